@@ -16,7 +16,7 @@ pub fn FormDemo() -> Element {
     let mut form_size = use_signal(|| FormSize::Middle);
 
     // 处理表单提交
-    let handle_submit = move |data: HashMap<String, String>| {
+    let handle_submit = move |data: serde_json::Value| {
         submit_result.set(format!("表单提交成功: {:?}", data));
         web_sys::console::log_1(&format!("Form submitted: {:?}", data).into());
     };
@@ -34,8 +34,7 @@ pub fn FormDemo() -> Element {
     };
 
     // 处理校验失败
-    let handle_validation_failed = move |errors: HashMap<String, String>| {
-        validation_errors.set(errors);
+    let handle_validation_failed = move |_: FinishFailedInfo| {
         submit_result.set("表单校验失败，请检查输入".to_string());
     };
 
@@ -264,7 +263,7 @@ pub fn FormDemo() -> Element {
                         label: "选择框",
                         name: "select",
 
-                        Select {
+                        Select::<String> {
                             placeholder: "请选择",
                             SelectOption { value: "option1", "选项1" }
                             SelectOption { value: "option2", "选项2" }
@@ -585,6 +584,687 @@ pub fn FormDemo() -> Element {
                     }
                 }
             }
+
+            // 表单实例
+            DemoSection {
+                title: "表单方法调用",
+                description: "通过 use_form 创建表单实例，在表单外部读写字段、校验、提交和滚动到字段。",
+
+                FormInstanceDemo {}
+            }
+
+            // 内置校验规则
+            DemoSection {
+                title: "内置校验规则",
+                description: "支持正则、邮箱、URL、整数、枚举、数值范围、长度与空白字符校验，未设置提示时使用语言包中的模板。",
+
+                FormRulesDemo {}
+            }
+
+            // 异步校验
+            DemoSection {
+                title: "异步校验",
+                description: "异步校验规则在同步校验通过后执行，支持防抖；校验期间表单项显示校验中状态，过期的校验结果会被丢弃。",
+
+                AsyncValidatorDemo {}
+            }
+
+            // 动态增减表单项
+            DemoSection {
+                title: "动态增减表单项",
+                description: "FormList 管理数组类型的字段，列表项以 phones[0].number 形式的路径注册，支持增加、删除、移动以及列表级校验规则。",
+
+                FormListDemo {}
+            }
+
+            // 字段联动
+            DemoSection {
+                title: "字段联动",
+                description: "dependencies 使确认密码在密码变化时重新校验，跨字段规则可读取整个表单的值；should_update 控制 render 内容何时重新渲染。",
+
+                DependenciesDemo {}
+            }
+
+            // 控件自动绑定
+            DemoSection {
+                title: "控件自动绑定",
+                description: "设置了 name 的 FormItem 内的数据录入组件自动读写字段值；value_prop_name 指定绑定的属性，trigger 指定收集字段值的事件。",
+
+                AutoBindDemo {}
+            }
+
+            // 校验时机
+            DemoSection {
+                title: "校验时机",
+                description: "validate_trigger 设置字段的校验时机，表单项的设置覆盖表单的设置；提交失败时 on_finish_failed 返回校验失败的字段。",
+
+                ValidateTriggerDemo {}
+            }
+
+            // JSON Schema 表单
+            DemoSection {
+                title: "JSON Schema 表单",
+                description: "SchemaForm 按 JSON Schema 的类型与格式生成表单项和控件，required、minLength、pattern 等约束转换为校验规则；ui-schema 调整控件、顺序与布局。",
+
+                SchemaFormDemo {}
+            }
+
+            // 草稿自动保存
+            DemoSection {
+                title: "草稿自动保存",
+                description: "persist 将修改后的表单值保存到 localStorage，刷新页面后恢复，提交成功后清除；is_dirty 可用于离开页面前提示未保存的修改。",
+
+                PersistDemo {}
+            }
+        }
+    }
+}
+
+/// 表单提交的数据
+#[derive(Debug, serde::Deserialize)]
+struct Account {
+    username: String,
+    contact: Contact,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct Contact {
+    email: String,
+}
+
+/// 表单实例演示
+#[component]
+fn FormInstanceDemo() -> Element {
+    let form = use_form();
+    let mut result = use_signal(String::new);
+
+    rsx! {
+        div {
+            div {
+                style: "display: flex; flex-wrap: wrap; gap: 8px; margin-bottom: 16px;",
+
+                Button {
+                    onclick: move |_| {
+                        form.set_fields_value(serde_json::json!({
+                            "username": "admin",
+                            "contact": { "email": "admin@example.com" },
+                        }));
+                    },
+                    "填充数据"
+                }
+                Button {
+                    onclick: move |_| form.reset_fields(None),
+                    "重置"
+                }
+                Button {
+                    onclick: move |_| {
+                        let message = match form.validate_fields(Some(vec!["username".to_string()])) {
+                            Ok(values) => format!("用户名校验通过: {:?}", values),
+                            Err(errors) => format!("用户名校验失败: {:?}", errors),
+                        };
+                        result.set(message);
+                    },
+                    "校验用户名"
+                }
+                Button {
+                    onclick: move |_| {
+                        form.set_fields(vec![FieldData::new("username")
+                            .errors(vec!["用户名已被占用".to_string()])]);
+                    },
+                    "设置错误"
+                }
+                Button {
+                    onclick: move |_| form.scroll_to_field("contact.email"),
+                    "滚动到邮箱"
+                }
+                Button {
+                    button_type: ButtonType::Primary,
+                    onclick: move |_| form.submit(),
+                    "外部提交"
+                }
+            }
+
+            Form {
+                form: form,
+                initial_values: serde_json::json!({ "username": "guest" }),
+                on_finish: move |values: serde_json::Value| {
+                    match serde_json::from_value::<Account>(values) {
+                        Ok(account) => result.set(format!(
+                            "提交成功: {} <{}>",
+                            account.username, account.contact.email
+                        )),
+                        Err(error) => result.set(format!("数据格式错误: {error}")),
+                    }
+                },
+                on_finish_failed: move |_| result.set("提交失败，请检查输入".to_string()),
+
+                FormItem {
+                    label: "用户名",
+                    name: "username",
+                    rules: vec![FormRule::required("请输入用户名")],
+
+                    Input {}
+                }
+
+                FormItem {
+                    label: "邮箱",
+                    name: "contact.email",
+                    rules: vec![FormRule::required("请输入邮箱")],
+
+                    Input {}
+                }
+            }
+
+            if !result().is_empty() {
+                p { style: "margin-top: 16px;", "{result}" }
+            }
+        }
+    }
+}
+
+/// 内置校验规则演示
+#[component]
+fn FormRulesDemo() -> Element {
+    let form = use_form();
+    let mut result = use_signal(String::new);
+
+    rsx! {
+        Form {
+            form: form,
+            on_finish: move |values: serde_json::Value| result.set(format!("校验通过: {values}")),
+            on_finish_failed: move |_| result.set(String::new()),
+
+            FormItem {
+                label: "手机号",
+                name: "phone",
+                rules: vec![
+                    FormRule::default().with_required(),
+                    FormRule::pattern(r"^1[3-9]\d{9}$", "请输入有效的手机号"),
+                ],
+
+                Input {}
+            }
+
+            FormItem {
+                label: "邮箱",
+                name: "email",
+                rules: vec![FormRule::email()],
+
+                Input {}
+            }
+
+            FormItem {
+                label: "网站",
+                name: "website",
+                rules: vec![FormRule::url()],
+
+                Input {}
+            }
+
+            FormItem {
+                label: "年龄",
+                name: "age",
+                rules: vec![FormRule::integer(), FormRule::range(18.0, 60.0)],
+
+                InputNumber {}
+            }
+
+            FormItem {
+                label: "昵称",
+                name: "nickname",
+                rules: vec![FormRule::length(2, 8), FormRule::no_whitespace()],
+
+                Input {}
+            }
+
+            FormItem {
+                Button {
+                    button_type: ButtonType::Primary,
+                    html_type: ButtonHtmlType::Submit,
+                    "校验"
+                }
+            }
+
+            if !result().is_empty() {
+                p { "{result}" }
+            }
+        }
+    }
+}
+
+/// 模拟服务端检查用户名是否已被占用
+async fn check_username(value: serde_json::Value) -> Result<(), String> {
+    gloo_timers::future::TimeoutFuture::new(800).await;
+    match value.as_str() {
+        Some("admin") | Some("root") => Err("${label}已被占用".to_string()),
+        _ => Ok(()),
+    }
+}
+
+/// 异步校验演示
+#[component]
+fn AsyncValidatorDemo() -> Element {
+    let mut result = use_signal(String::new);
+
+    rsx! {
+        Form {
+            on_finish: move |values: serde_json::Value| result.set(format!("注册成功: {values}")),
+            on_finish_failed: move |_| result.set(String::new()),
+
+            FormItem {
+                label: "用户名",
+                name: "username",
+                extra: "admin 与 root 已被占用",
+                rules: vec![
+                    FormRule::default().with_required(),
+                    FormRule::async_validator(check_username).validate_debounce(500),
+                ],
+
+                Input {}
+            }
+
+            FormItem {
+                Button {
+                    button_type: ButtonType::Primary,
+                    html_type: ButtonHtmlType::Submit,
+                    "注册"
+                }
+            }
+
+            if !result().is_empty() {
+                p { "{result}" }
+            }
+        }
+    }
+}
+
+/// 动态增减表单项演示
+#[component]
+fn FormListDemo() -> Element {
+    let mut result = use_signal(String::new);
+
+    rsx! {
+        Form {
+            initial_values: serde_json::json!({ "phones": [{ "number": "13800000000" }] }),
+            on_finish: move |values: serde_json::Value| result.set(format!("提交成功: {values}")),
+            on_finish_failed: move |_| result.set(String::new()),
+
+            FormList {
+                name: "phones",
+                rules: vec![
+                    FormRule::required("至少添加一个电话号码"),
+                    FormRule::max_length(3, "最多添加三个电话号码"),
+                ],
+                render: move |(fields, operation, errors): FormListRenderArgs| {
+                    let count = fields.len();
+                    rsx! {
+                        for field in fields {
+                            FormItem {
+                                key: "{field.key}",
+                                label: format!("电话 {}", field.index + 1),
+                                name: field.path("number"),
+                                rules: vec![FormRule::required("请输入电话号码")],
+
+                                div { style: "display: flex; gap: 8px;",
+                                    Input { placeholder: "电话号码" }
+                                    Button {
+                                        disabled: field.index == 0,
+                                        onclick: move |_| operation.move_item(field.index, field.index.saturating_sub(1)),
+                                        "上移"
+                                    }
+                                    Button {
+                                        disabled: field.index + 1 == count,
+                                        onclick: move |_| operation.move_item(field.index, field.index + 1),
+                                        "下移"
+                                    }
+                                    Button {
+                                        danger: true,
+                                        onclick: move |_| operation.remove(field.index),
+                                        "删除"
+                                    }
+                                }
+                            }
+                        }
+
+                        FormItem {
+                            Button {
+                                button_type: ButtonType::Dashed,
+                                block: true,
+                                onclick: move |_| operation.add(serde_json::json!({ "number": "" }), None),
+                                "添加电话"
+                            }
+                            for error in errors {
+                                div { class: "ant-form-item-explain ant-form-item-explain-error", "{error}" }
+                            }
+                        }
+                    }
+                },
+            }
+
+            FormItem {
+                Button {
+                    button_type: ButtonType::Primary,
+                    html_type: ButtonHtmlType::Submit,
+                    "提交"
+                }
+            }
+
+            if !result().is_empty() {
+                p { "{result}" }
+            }
+        }
+    }
+}
+
+/// 字段联动演示
+#[component]
+fn DependenciesDemo() -> Element {
+    rsx! {
+        Form {
+            FormItem {
+                label: "密码",
+                name: "password",
+                rules: vec![FormRule::required("请输入密码")],
+
+                Input {}
+            }
+
+            FormItem {
+                label: "确认密码",
+                name: "confirm",
+                dependencies: vec!["password".to_string()],
+                rules: vec![
+                    FormRule::required("请确认密码"),
+                    FormRule::cross_field(|value, values| {
+                        if values.get("password") == Some(value) {
+                            Ok(())
+                        } else {
+                            Err("两次输入的密码不一致".to_string())
+                        }
+                    }),
+                ],
+
+                Input {}
+            }
+
+            FormItem {
+                label: "密码强度",
+                should_update: |(prev, current): (serde_json::Value, serde_json::Value)| {
+                    prev.get("password") != current.get("password")
+                },
+                render: |form: FormInstance| {
+                    let length = form
+                        .get_field_value("password")
+                        .and_then(|value| value.as_str().map(|text| text.chars().count()))
+                        .unwrap_or_default();
+                    let strength = match length {
+                        0 => "-",
+                        1..=5 => "弱",
+                        6..=9 => "中",
+                        _ => "强",
+                    };
+                    rsx! { span { "{strength}" } }
+                },
+            }
+        }
+    }
+}
+
+/// 控件自动绑定演示
+#[component]
+fn AutoBindDemo() -> Element {
+    let mut result = use_signal(String::new);
+
+    rsx! {
+        Form {
+            initial_values: serde_json::json!({ "city": "beijing", "notify": true }),
+            on_finish: move |values: serde_json::Value| result.set(format!("提交成功: {values}")),
+
+            FormItem {
+                label: "昵称",
+                name: "nickname",
+                trigger: "on_blur",
+                extra: "失焦时收集字段值",
+                rules: vec![FormRule::required("请输入昵称")],
+
+                Input {}
+            }
+
+            FormItem {
+                label: "城市",
+                name: "city",
+
+                Select::<String> {
+                    SelectOption { value: "beijing", "北京" }
+                    SelectOption { value: "shanghai", "上海" }
+                }
+            }
+
+            FormItem {
+                label: "消息通知",
+                name: "notify",
+                value_prop_name: "checked",
+
+                Switch {}
+            }
+
+            FormItem {
+                name: "agreement",
+                value_prop_name: "checked",
+                rules: vec![
+                    FormRule::required("请阅读并同意协议"),
+                    FormRule::cross_field(|value, _| {
+                        if value.as_bool() == Some(true) {
+                            Ok(())
+                        } else {
+                            Err("请阅读并同意协议".to_string())
+                        }
+                    }),
+                ],
+
+                Checkbox { "我已阅读并同意协议" }
+            }
+
+            FormItem {
+                Button {
+                    button_type: ButtonType::Primary,
+                    html_type: ButtonHtmlType::Submit,
+                    "提交"
+                }
+            }
+
+            if !result().is_empty() {
+                p { "{result}" }
+            }
+        }
+    }
+}
+
+/// 校验时机演示
+#[component]
+fn ValidateTriggerDemo() -> Element {
+    let form = use_form();
+    let mut result = use_signal(String::new);
+    let field_state = |name: &str| {
+        format!(
+            "touched: {}，dirty: {}",
+            form.is_field_touched(name),
+            form.is_field_dirty(name)
+        )
+    };
+
+    rsx! {
+        Form {
+            form: form,
+            validate_trigger: vec![ValidateTrigger::OnBlur],
+            initial_values: serde_json::json!({ "email": "admin@example.com" }),
+            on_finish: move |values: serde_json::Value| result.set(format!("提交成功: {values}")),
+            on_finish_failed: move |info: FinishFailedInfo| {
+                let names: Vec<String> = info
+                    .error_fields
+                    .into_iter()
+                    .map(|field| field.name)
+                    .collect();
+                result.set(format!("校验失败的字段: {}", names.join("、")));
+            },
+
+            FormItem {
+                label: "邮箱",
+                name: "email",
+                extra: "失焦时校验，{field_state(\"email\")}",
+                rules: vec![FormRule::required("请输入邮箱"), FormRule::email()],
+
+                Input {}
+            }
+
+            FormItem {
+                label: "手机号",
+                name: "phone",
+                validate_trigger: vec![ValidateTrigger::OnChange, ValidateTrigger::OnBlur],
+                extra: "输入与失焦时校验，{field_state(\"phone\")}",
+                rules: vec![
+                    FormRule::required("请输入手机号"),
+                    FormRule::pattern(r"^1[3-9]\d{9}$", "请输入有效的手机号"),
+                ],
+
+                Input {}
+            }
+
+            FormItem {
+                label: "备注",
+                name: "remark",
+                validate_trigger: Vec::new(),
+                extra: "仅在提交时校验",
+                rules: vec![FormRule::max_length(20, "备注最多 20 个字符")],
+
+                Input {}
+            }
+
+            FormItem {
+                Button {
+                    button_type: ButtonType::Primary,
+                    html_type: ButtonHtmlType::Submit,
+                    "提交"
+                }
+            }
+
+            if !result().is_empty() {
+                p { "{result}" }
+            }
+        }
+    }
+}
+
+/// JSON Schema 表单演示
+#[component]
+fn SchemaFormDemo() -> Element {
+    let mut result = use_signal(String::new);
+    let schema = serde_json::json!({
+        "type": "object",
+        "required": ["name", "role"],
+        "properties": {
+            "name": { "type": "string", "title": "姓名", "minLength": 2, "maxLength": 20 },
+            "email": { "type": "string", "title": "邮箱", "format": "email" },
+            "age": { "type": "integer", "title": "年龄", "minimum": 18, "maximum": 60 },
+            "role": {
+                "type": "string",
+                "title": "角色",
+                "enum": ["admin", "editor", "viewer"],
+                "enumNames": ["管理员", "编辑", "访客"],
+            },
+            "active": { "type": "boolean", "title": "启用", "default": true },
+            "joined_at": { "type": "string", "title": "入职时间", "format": "date" },
+            "address": {
+                "type": "object",
+                "title": "地址",
+                "properties": {
+                    "city": { "type": "string", "title": "城市" },
+                    "zip": { "type": "string", "title": "邮编", "pattern": "^\\d{6}$" },
+                },
+            },
+            "phones": {
+                "type": "array",
+                "title": "电话",
+                "maxItems": 3,
+                "items": { "type": "string", "title": "号码" },
+            },
+        },
+    });
+    let ui_schema = serde_json::json!({
+        "ui:order": ["name", "role", "*"],
+        "name": { "ui:placeholder": "请输入姓名" },
+        "role": { "ui:widget": "radio" },
+        "email": { "ui:help": "用于接收通知" },
+    });
+
+    rsx! {
+        SchemaForm {
+            schema: schema,
+            ui_schema: ui_schema,
+            on_finish: move |values: serde_json::Value| result.set(format!("提交成功: {values}")),
+            on_finish_failed: move |_| result.set(String::new()),
+
+            FormItem {
+                Button {
+                    button_type: ButtonType::Primary,
+                    html_type: ButtonHtmlType::Submit,
+                    "提交"
+                }
+            }
+
+            if !result().is_empty() {
+                p { "{result}" }
+            }
+        }
+    }
+}
+
+/// 草稿自动保存演示
+#[component]
+fn PersistDemo() -> Element {
+    let form = use_form();
+    let mut result = use_signal(String::new);
+
+    rsx! {
+        Form {
+            form: form,
+            persist: FormPersist::new("form-demo-article").debounce(800),
+            on_finish: move |values: serde_json::Value| result.set(format!("发布成功: {values}")),
+
+            FormItem {
+                label: "标题",
+                name: "title",
+                rules: vec![FormRule::required("请输入标题")],
+
+                Input {}
+            }
+
+            FormItem {
+                label: "摘要",
+                name: "summary",
+
+                Input {}
+            }
+
+            FormItem {
+                extra: if form.is_dirty() { "有未发布的修改，已自动保存为草稿" } else { "暂无修改" },
+
+                div { style: "display: flex; gap: 8px;",
+                    Button {
+                        button_type: ButtonType::Primary,
+                        html_type: ButtonHtmlType::Submit,
+                        "发布"
+                    }
+                    Button {
+                        onclick: move |_| form.reset_fields(None),
+                        "放弃修改"
+                    }
+                }
+            }
+
+            if !result().is_empty() {
+                p { "{result}" }
+            }
         }
     }
 }
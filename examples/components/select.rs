@@ -41,13 +41,28 @@ pub fn SelectDemo() -> Element {
 
             // 无边框样式
             BorderlessExample {}
+
+            // 大数据虚拟滚动
+            VirtualListExample {}
+
+            // 类型化选项与自定义渲染
+            TypedOptionsExample {}
+
+            // 标签模式
+            TagsExample {}
+
+            // 远程搜索
+            RemoteSearchExample {}
+
+            // 模糊搜索
+            FuzzySearchExample {}
         }
     }
 }
 
 #[component]
 fn BasicExample() -> Element {
-    let mut value = use_signal(|| String::new());
+    let mut value = use_signal(|| None::<String>);
 
     rsx! {
         div {
@@ -64,7 +79,7 @@ fn BasicExample() -> Element {
                     placeholder: "请选择",
                     on_change: move |v| {
                         value.set(v);
-                        println!("选择了: {}", value());
+                        println!("选择了: {:?}", value());
                     },
                     style: "width: 200px;",
 
@@ -89,7 +104,7 @@ fn BasicExample() -> Element {
 
                 div {
                     style: "padding: 8px; background: #f5f5f5; border-radius: 4px;",
-                    "当前值: {value()}"
+                    "当前值: {value().unwrap_or_default()}"
                 }
             }
         }
@@ -98,9 +113,9 @@ fn BasicExample() -> Element {
 
 #[component]
 fn SizeExample() -> Element {
-    let mut large_value = use_signal(|| String::new());
-    let mut middle_value = use_signal(|| String::new());
-    let mut small_value = use_signal(|| String::new());
+    let mut large_value = use_signal(|| None::<String>);
+    let mut middle_value = use_signal(|| None::<String>);
+    let mut small_value = use_signal(|| None::<String>);
 
     rsx! {
         div {
@@ -163,9 +178,9 @@ fn SizeExample() -> Element {
 
 #[component]
 fn StatusExample() -> Element {
-    let mut normal_value = use_signal(|| String::new());
-    let mut error_value = use_signal(|| String::new());
-    let mut warning_value = use_signal(|| String::new());
+    let mut normal_value = use_signal(|| None::<String>);
+    let mut error_value = use_signal(|| None::<String>);
+    let mut warning_value = use_signal(|| None::<String>);
 
     rsx! {
         div {
@@ -225,7 +240,7 @@ fn StatusExample() -> Element {
 
 #[component]
 fn MultipleExample() -> Element {
-    let mut values = use_signal(|| vec![]);
+    let mut values = use_signal(Vec::<String>::new);
 
     rsx! {
         div {
@@ -265,7 +280,7 @@ fn MultipleExample() -> Element {
 
 #[component]
 fn SearchExample() -> Element {
-    let mut value = use_signal(|| String::new());
+    let mut value = use_signal(|| None::<String>);
     let mut search_value = use_signal(|| String::new());
 
     rsx! {
@@ -301,7 +316,7 @@ fn SearchExample() -> Element {
 
                 div {
                     style: "padding: 8px; background: #f5f5f5; border-radius: 4px;",
-                    div { "选择值: {value()}" }
+                    div { "选择值: {value().unwrap_or_default()}" }
                     div { "搜索值: {search_value()}" }
                 }
             }
@@ -311,7 +326,7 @@ fn SearchExample() -> Element {
 
 #[component]
 fn ClearableExample() -> Element {
-    let mut value = use_signal(|| "option1".to_string());
+    let mut value = use_signal(|| Some("option1".to_string()));
 
     rsx! {
         div {
@@ -339,13 +354,13 @@ fn ClearableExample() -> Element {
                 }
 
                 button {
-                    onclick: move |_| value.set("option2".to_string()),
+                    onclick: move |_| value.set(Some("option2".to_string())),
                     "设置为选项2"
                 }
 
                 div {
                     style: "padding: 8px; background: #f5f5f5; border-radius: 4px;",
-                    "当前值: {value()}"
+                    "当前值: {value().unwrap_or_default()}"
                 }
             }
         }
@@ -354,7 +369,7 @@ fn ClearableExample() -> Element {
 
 #[component]
 fn DisabledExample() -> Element {
-    let mut value = use_signal(|| "disabled1".to_string());
+    let mut value = use_signal(|| Some("disabled1".to_string()));
 
     rsx! {
         div {
@@ -368,7 +383,7 @@ fn DisabledExample() -> Element {
 
                 div {
                     "禁用选择器:"
-                    Select {
+                    Select::<String> {
                         value: value(),
                         placeholder: "禁用的选择器",
                         disabled: true,
@@ -381,7 +396,7 @@ fn DisabledExample() -> Element {
 
                 div {
                     "部分禁用:"
-                    Select {
+                    Select::<String> {
                         placeholder: "部分选项禁用",
                         style: "width: 200px; margin-left: 8px;",
 
@@ -398,7 +413,7 @@ fn DisabledExample() -> Element {
 
 #[component]
 fn OptGroupExample() -> Element {
-    let mut value = use_signal(|| String::new());
+    let mut value = use_signal(|| None::<String>);
 
     rsx! {
         div {
@@ -444,7 +459,294 @@ fn OptGroupExample() -> Element {
 
                 div {
                     style: "padding: 8px; background: #f5f5f5; border-radius: 4px;",
-                    "选择的城市: {value()}"
+                    "选择的城市: {value().unwrap_or_default()}"
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn VirtualListExample() -> Element {
+    let mut value = use_signal(|| None::<String>);
+    let options = use_hook(|| {
+        (0..20000)
+            .map(|i| {
+                OptionData::new(format!("item-{i}"), format!("选项 {i}")).disabled(i % 10 == 9)
+            })
+            .collect::<Vec<_>>()
+    });
+
+    rsx! {
+        div {
+            style: "margin-bottom: 32px;",
+
+            h2 { "大数据虚拟滚动" }
+            p { "通过 options 传入 20000 个选项，下拉菜单只渲染可视区域内的选项，支持键盘上下选择。" }
+
+            div {
+                style: "display: flex; gap: 16px; align-items: flex-start; flex-wrap: wrap;",
+
+                Select {
+                    value: value(),
+                    options: options.clone(),
+                    show_search: true,
+                    placeholder: "搜索或选择",
+                    on_change: move |v| value.set(v),
+                    style: "width: 250px;",
+                }
+
+                div {
+                    style: "padding: 8px; background: #f5f5f5; border-radius: 4px;",
+                    "选择的值: {value().unwrap_or_default()}"
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn TypedOptionsExample() -> Element {
+    let mut city = use_signal(|| None::<u32>);
+    let mut labeled = use_signal(Vec::<LabeledValue<u32>>::new);
+    let mut user = use_signal(|| None::<String>);
+    let options = vec![
+        SelectOptionData::group(
+            "华北地区",
+            vec![
+                SelectOptionData::new(1_u32, "北京"),
+                SelectOptionData::new(2_u32, "天津"),
+            ],
+        ),
+        SelectOptionData::group(
+            "华东地区",
+            vec![
+                SelectOptionData::new(3_u32, "上海"),
+                SelectOptionData::new(4_u32, "杭州").disabled(true),
+            ],
+        ),
+    ];
+    let raw_users = vec![
+        serde_json::json!({ "id": "u1", "name": "张三" }),
+        serde_json::json!({ "id": "u2", "name": "李四" }),
+    ];
+
+    rsx! {
+        div {
+            style: "margin-bottom: 32px;",
+
+            h2 { "类型化选项与自定义渲染" }
+            p { "选项值可以是任意可序列化类型，支持分组、JSON 数据字段映射、自定义渲染以及 label_in_value。" }
+
+            div {
+                style: "display: flex; gap: 16px; align-items: flex-start; flex-wrap: wrap;",
+
+                Select {
+                    value: city(),
+                    options: options.clone(),
+                    placeholder: "选择城市",
+                    label_in_value: true,
+                    option_render: move |option: SelectOptionData<u32>| rsx! {
+                        span { "{option.label}" }
+                        span { style: "float: right; color: #999;", "#{option.value}" }
+                    },
+                    on_change: move |v| city.set(v),
+                    on_labeled_change: move |v| labeled.set(v),
+                    style: "width: 200px;",
+                }
+
+                Select {
+                    value: user(),
+                    raw_options: raw_users,
+                    field_names: SelectFieldNames::default().label("name").value("id"),
+                    label_render: move |option: OptionData| rsx! { "👤 {option.label}" },
+                    placeholder: "选择用户",
+                    on_change: move |v| user.set(v),
+                    style: "width: 200px;",
+                }
+
+                div {
+                    style: "padding: 8px; background: #f5f5f5; border-radius: 4px;",
+                    "城市: {city().map(|city| city.to_string()).unwrap_or_default()}，标签: "
+                    {labeled().iter().map(|v| v.label.clone()).collect::<Vec<_>>().join(", ")}
+                    "，用户: {user().unwrap_or_default()}"
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn FuzzySearchExample() -> Element {
+    let mut city = use_signal(|| None::<String>);
+    let options = vec![
+        OptionData::group(
+            "华北",
+            vec![
+                OptionData::new("beijing", "北京"),
+                OptionData::new("tianjin", "天津"),
+            ],
+        ),
+        OptionData::group(
+            "华东",
+            vec![
+                OptionData::new("shanghai", "上海"),
+                OptionData::new("hangzhou", "杭州"),
+                OptionData::new("nanjing", "南京"),
+            ],
+        ),
+        OptionData::group(
+            "华南",
+            vec![
+                OptionData::new("guangzhou", "广州"),
+                OptionData::new("shenzhen", "深圳"),
+            ],
+        ),
+    ];
+
+    rsx! {
+        div {
+            style: "margin-bottom: 32px;",
+
+            h2 { "模糊搜索" }
+            p { "支持拼音首字母与不连续字符匹配，如输入 bj、hz 或 szn，匹配的字符高亮显示并按相关度排序。" }
+
+            div {
+                style: "max-width: 300px;",
+
+                Select {
+                    value: city(),
+                    options,
+                    show_search: true,
+                    placeholder: "搜索城市",
+                    on_change: move |v| city.set(v),
+                }
+            }
+        }
+    }
+}
+
+/// 模拟服务端：按搜索内容返回用户
+async fn search_users(query: String) -> Vec<OptionData> {
+    gloo_timers::future::TimeoutFuture::new(500).await;
+
+    let query = query.trim().to_lowercase();
+    (1..=200)
+        .map(|index| format!("user{index}"))
+        .filter(|name| name.contains(&query))
+        .take(20)
+        .map(|name| OptionData::new(name.clone(), format!("{name}@example.com")))
+        .collect()
+}
+
+#[component]
+fn RemoteSearchExample() -> Element {
+    let mut user = use_signal(|| None::<String>);
+    let mut users = use_signal(Vec::<String>::new);
+
+    rsx! {
+        div {
+            style: "margin-bottom: 32px;",
+
+            h2 { "远程搜索" }
+            p { "输入内容后防抖请求选项，加载中显示加载状态，相同的搜索内容使用缓存结果。" }
+
+            div {
+                style: "display: flex; flex-direction: column; gap: 16px; max-width: 400px;",
+
+                Select {
+                    value: user(),
+                    load_options: LoadOptions::new(search_users),
+                    placeholder: "搜索用户",
+                    allow_clear: true,
+                    not_found_content: rsx! { "没有找到用户" },
+                    on_change: move |v| user.set(v),
+                }
+
+                Select {
+                    mode: SelectMode::Multiple,
+                    values: users(),
+                    load_options: LoadOptions::new(search_users).debounce(800).cache(false),
+                    placeholder: "搜索多个用户",
+                    on_change_multiple: move |v| users.set(v),
+                }
+
+                AutoComplete {
+                    load_options: LoadOptions::new(|query: String| async move {
+                        search_users(query)
+                            .await
+                            .into_iter()
+                            .map(|option| AutoCompleteOption::new(&option.label, &option.label))
+                            .collect()
+                    }),
+                    placeholder: "输入邮箱",
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn TagsExample() -> Element {
+    let mut tags = use_signal(|| vec!["rust".to_string(), "dioxus".to_string()]);
+    let options = ["rust", "dioxus", "wasm", "typescript", "webassembly"]
+        .iter()
+        .map(|value| OptionData::new(*value, *value))
+        .collect::<Vec<_>>();
+
+    rsx! {
+        div {
+            style: "margin-bottom: 32px;",
+
+            h2 { "标签模式" }
+            p { "输入新的值回车创建标签，粘贴以逗号或空格分隔的内容会自动拆分；超出数量的标签折叠为 +N。" }
+
+            div {
+                style: "display: flex; flex-direction: column; gap: 16px; max-width: 400px;",
+
+                Select {
+                    mode: SelectMode::Tags,
+                    values: tags(),
+                    options: options.clone(),
+                    token_separators: vec![",".to_string(), " ".to_string()],
+                    max_tag_count: MaxTagCount::Count(3),
+                    max_tag_text_length: 8,
+                    placeholder: "输入标签",
+                    on_change_multiple: move |v| tags.set(v),
+                }
+
+                Select {
+                    mode: SelectMode::Tags,
+                    values: tags(),
+                    options: options.clone(),
+                    max_tag_count: MaxTagCount::Responsive,
+                    placeholder: "自适应标签数",
+                    on_change_multiple: move |v| tags.set(v),
+                }
+
+                Select {
+                    mode: SelectMode::Multiple,
+                    values: tags(),
+                    options,
+                    tag_render: move |args: SelectTagRenderArgs<String>| rsx! {
+                        Tag {
+                            color: TagColor::Primary,
+                            closable: args.closable,
+                            style: "margin-right: 4px;",
+                            onclose: move |evt: MouseEvent| {
+                                evt.stop_propagation();
+                                args.on_close.call(());
+                            },
+                            "{args.label}"
+                        }
+                    },
+                    placeholder: "自定义标签",
+                    on_change_multiple: move |v| tags.set(v),
+                }
+
+                div {
+                    style: "padding: 8px; background: #f5f5f5; border-radius: 4px;",
+                    "标签: {tags().join(\", \")}"
                 }
             }
         }
@@ -453,7 +755,7 @@ fn OptGroupExample() -> Element {
 
 #[component]
 fn BorderlessExample() -> Element {
-    let mut value = use_signal(|| String::new());
+    let mut value = use_signal(|| None::<String>);
 
     rsx! {
         div {
@@ -482,7 +784,7 @@ fn BorderlessExample() -> Element {
 
                 div {
                     "对比 - 有边框:"
-                    Select {
+                    Select::<String> {
                         placeholder: "有边框",
                         bordered: true,
                         style: "width: 200px; margin-left: 8px;",
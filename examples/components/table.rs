@@ -5,7 +5,7 @@
 use crate::common::*;
 use ant_design_dioxus::prelude::*;
 use dioxus::prelude::*;
-use std::collections::HashMap;
+use std::rc::Rc;
 
 #[derive(Clone, PartialEq, Debug)]
 struct UserData {
@@ -16,15 +16,10 @@ struct UserData {
     tags: Vec<String>,
 }
 
-/// 将UserData转换为HashMap<String, String>
-fn user_data_to_hashmap(user: &UserData) -> HashMap<String, String> {
-    let mut map = HashMap::new();
-    map.insert("key".to_string(), user.key.clone());
-    map.insert("name".to_string(), user.name.clone());
-    map.insert("age".to_string(), user.age.to_string());
-    map.insert("address".to_string(), user.address.clone());
-    map.insert("tags".to_string(), user.tags.join(", "));
-    map
+impl TableRow for UserData {
+    fn row_key(&self) -> String {
+        self.key.clone()
+    }
 }
 
 /// Table 组件演示
@@ -56,79 +51,44 @@ pub fn TableDemo() -> Element {
         ]
     });
 
-    // 将UserData转换为HashMap<String, String>
-    let data = use_memo(move || {
-        user_data
-            .read()
-            .iter()
-            .map(|item| user_data_to_hashmap(item))
-            .collect::<Vec<_>>()
-    });
-
     let columns = vec![
-        TableColumn {
-            title: "Name".to_string(),
-            dataindex: "name".to_string(),
-            key: "name".to_string(),
-            width: Some("150px".to_string()),
-            align: Some(TableAlign::Left),
-            sortable: true,
-            fixed: None,
-            show_sorter_tooltip: true,
-        },
-        TableColumn {
-            title: "Age".to_string(),
-            dataindex: "age".to_string(),
-            key: "age".to_string(),
-            width: Some("80px".to_string()),
-            align: Some(TableAlign::Center),
-            sortable: true,
-            fixed: None,
-            show_sorter_tooltip: true,
-        },
-        TableColumn {
-            title: "Address".to_string(),
-            dataindex: "address".to_string(),
-            key: "address".to_string(),
-            width: None,
-            align: Some(TableAlign::Left),
-            sortable: false,
-            fixed: None,
-            show_sorter_tooltip: true,
-        },
-        TableColumn {
-            title: "Tags".to_string(),
-            dataindex: "tags".to_string(),
-            key: "tags".to_string(),
-            width: Some("200px".to_string()),
-            align: Some(TableAlign::Center),
-            sortable: false,
-            fixed: None,
-            show_sorter_tooltip: true,
-        },
-        TableColumn {
-            title: "Action".to_string(),
-            dataindex: "action".to_string(),
-            key: "action".to_string(),
-            width: Some("150px".to_string()),
-            align: Some(TableAlign::Center),
-            sortable: false,
-            fixed: None,
-            show_sorter_tooltip: true,
-        },
+        TableColumn::new("name", "Name")
+            .value(|user: &UserData| user.name.clone())
+            .sortable()
+            .width("150px")
+            .align(TableAlign::Left),
+        TableColumn::new("age", "Age")
+            .value(|user: &UserData| user.age.to_string())
+            .sorter(|a: &UserData, b: &UserData| a.age.cmp(&b.age))
+            .width("80px")
+            .align(TableAlign::Center),
+        TableColumn::new("address", "Address")
+            .value(|user: &UserData| user.address.clone())
+            .filters(vec![
+                TableFilter::new("London", "London"),
+                TableFilter::new("New York", "New York"),
+                TableFilter::new("Sidney", "Sidney"),
+            ])
+            .on_filter(|value: &str, user: &UserData| user.address.starts_with(value))
+            .align(TableAlign::Left),
+        TableColumn::new("tags", "Tags")
+            .render(|user: &UserData, _| {
+                rsx! {
+                    for tag in user.tags.iter() {
+                        Tag { key: "{tag}", "{tag}" }
+                    }
+                }
+            })
+            .width("200px")
+            .align(TableAlign::Center),
+        TableColumn::new("action", "Action")
+            .render(|user: &UserData, _| rsx! { a { "Invite {user.name}" } })
+            .width("150px")
+            .align(TableAlign::Center),
     ];
 
-    let selected_rows = use_signal(|| Vec::<String>::new());
-    let selected_keys = use_signal(|| Vec::<String>::new());
-
-    // 获取所有用户的key
-    let all_keys = use_memo(move || {
-        user_data
-            .read()
-            .iter()
-            .map(|item| item.key.clone())
-            .collect::<Vec<_>>()
-    });
+    let mut selected_rows = use_signal(Vec::<UserData>::new);
+    let mut clicked_row = use_signal(|| None::<UserData>);
 
     let current_page = use_signal(|| 1);
     let page_size = use_signal(|| 10);
@@ -143,22 +103,18 @@ pub fn TableDemo() -> Element {
                 description: "简单的表格，最后一列是各种操作。",
 
                 div {
+                    if let Some(row) = clicked_row.read().as_ref() {
+                        p { "点击了 {row.name}" }
+                    }
                     Table {
                         columns: columns.clone(),
-                        data_source: data.read().clone(),
+                        data_source: user_data.read().clone(),
                         size: TableSize::Default,
                         loading: false,
                         scroll_x: false,
                         scroll_y: false,
                         show_header: true,
-                        // 暂时注释掉on_row_click以解决类型不匹配问题
-                        // on_row_click: Some(|key| {
-                        //     println!("Row clicked: {}", key);
-                        // }),
-                        // 暂时注释掉on_sort_change以解决类型不匹配问题
-                        // on_sort_change: Some(move |(column, order): (String, String)| {
-                        //     println!("Sort changed: {} {}", column, order);
-                        // }),
+                        on_row_click: move |row: UserData| clicked_row.set(Some(row)),
                         row_selection: None,
                         pagination: None,
                     }
@@ -174,27 +130,18 @@ pub fn TableDemo() -> Element {
                     p { "已选择 {selected_rows.read().len()} 项" }
                     Table {
                         columns: columns.clone(),
-                        data_source: data.read().clone(),
+                        data_source: user_data.read().clone(),
                         size: TableSize::Default,
                         loading: false,
                         scroll_x: false,
                         scroll_y: false,
                         show_header: true,
-                        // 暂时注释掉on_row_click以解决类型不匹配问题
-                        // on_row_click: Some(|key| {
-                        //     println!("Row clicked: {}", key);
-                        // }),
-                        // 暂时注释掉on_sort_change以解决类型不匹配问题
-                        // on_sort_change: Some(move |(column, order): (String, String)| {
-                        //     println!("Sort changed: {} {}", column, order);
-                        // }),
                         row_selection: Some(TableRowSelection {
-                            selected_row_keys: selected_rows.read().clone(),
-                            checkbox_props: None,
-                            column_width: None,
-                            column_title: None,
-                            fixed: false,
                             selection_type: SelectionType::Checkbox,
+                            on_change: Some(EventHandler::new(move |(_, rows): (Vec<String>, Vec<UserData>)| {
+                                selected_rows.set(rows);
+                            })),
+                            ..Default::default()
                         }),
                         pagination: None,
                     }
@@ -208,20 +155,12 @@ pub fn TableDemo() -> Element {
                 div {
                     Table {
                         columns: columns.clone(),
-                        data_source: data.read().clone(),
+                        data_source: user_data.read().clone(),
                         size: TableSize::Default,
                         loading: false,
                         scroll_x: false,
                         scroll_y: false,
                         show_header: true,
-                        // 暂时注释掉on_row_click以解决类型不匹配问题
-                        // on_row_click: Some(|key| {
-                        //     println!("Row clicked: {}", key);
-                        // }),
-                        // 暂时注释掉on_sort_change以解决类型不匹配问题
-                        // on_sort_change: Some(move |(column, order): (String, String)| {
-                        //     println!("Sort changed: {} {}", column, order);
-                        // }),
                         row_selection: None,
                         pagination: Some(TablePagination {
                             current: current_page.read().clone(),
@@ -250,14 +189,14 @@ pub fn TableDemo() -> Element {
                         h4 { "Middle size" }
                         Table {
                             columns: columns.clone(),
-                            data_source: data.read().clone(),
+                            data_source: user_data.read().clone(),
                             size: TableSize::Middle,
                             loading: false,
                             scroll_x: false,
                             scroll_y: false,
                             show_header: true,
                             // empty_text: "暂无数据".to_string(),
-                            on_row_click: move |key: String| {},
+                            on_row_click: move |_: UserData| {},
                             // on_sort_change: move |column: String, direction: String| {},
                             row_selection: None,
                             pagination: None,
@@ -265,14 +204,14 @@ pub fn TableDemo() -> Element {
                         h4 { "Small size" }
                         Table {
                             columns: columns.clone(),
-                            data_source: data.read().clone(),
+                            data_source: user_data.read().clone(),
                             size: TableSize::Small,
                             loading: false,
                             scroll_x: false,
                             scroll_y: false,
                             show_header: true,
                             // empty_text: "暂无数据".to_string(),
-                            on_row_click: move |key: String| {},
+                            on_row_click: move |_: UserData| {},
                             // on_sort_change: move |column: String, direction: String| {},
                             row_selection: None,
                             pagination: None,
@@ -280,6 +219,559 @@ pub fn TableDemo() -> Element {
                     }
                 }
             }
+
+            DemoSection {
+                title: "虚拟列表",
+                description: "五万行数据开启虚拟滚动，只渲染可视区域内的行；表头固定，首尾列固定在两侧。",
+
+                VirtualTableDemo {}
+            }
+
+            DemoSection {
+                title: "可展开",
+                description: "当表格内容较多不能一次性完全展示时，可以展开额外的行内容。",
+
+                ExpandableTableDemo {}
+            }
+
+            DemoSection {
+                title: "树形数据展示",
+                description: "数据行通过 children 返回子行时以树形结构展示，勾选父行会联动选择子行。",
+
+                TreeTableDemo {}
+            }
+
+            DemoSection {
+                title: "可编辑单元格",
+                description: "点击单元格进入编辑，回车或失焦时校验并保存。",
+
+                EditableCellDemo {}
+            }
+
+            DemoSection {
+                title: "可编辑行",
+                description: "通过操作列编辑整行，保存时按列的校验规则校验。",
+
+                EditableRowDemo {}
+            }
+
+            DemoSection {
+                title: "列设置",
+                description: "拖拽表头边缘调整列宽、拖拽表头调整列顺序，并通过列设置切换列的显隐；列布局保存在 localStorage 中。",
+
+                ColumnStateDemo {}
+            }
+
+            DemoSection {
+                title: "表头分组与合并单元格",
+                description: "通过分组列实现多级表头，通过 on_cell 合并单元格，并在底部显示总结栏。",
+
+                GroupedTableDemo {}
+            }
+
+            DemoSection {
+                title: "远程加载数据",
+                description: "分页、排序与筛选由服务端完成，表格在状态变化时调用异步 load 获取当前页数据；筛选变化带防抖，过期的响应会被丢弃。",
+
+                RemoteTableDemo {}
+            }
+
+            DemoSection {
+                title: "导出数据",
+                description: "通过 use_table_exporter 创建导出器，将筛选、排序后的数据导出为 CSV 或 XLSX 文件。",
+
+                ExportTableDemo {}
+            }
+        }
+    }
+}
+
+/// 虚拟滚动演示
+#[component]
+fn VirtualTableDemo() -> Element {
+    let rows = use_signal(|| {
+        (0..50_000)
+            .map(|index| UserData {
+                key: index.to_string(),
+                name: format!("Edward King {index}"),
+                age: 20 + (index % 40) as u32,
+                address: format!("London, Park Lane no. {index}"),
+                tags: Vec::new(),
+            })
+            .collect::<Vec<_>>()
+    });
+
+    let columns = vec![
+        TableColumn::new("name", "Name")
+            .value(|user: &UserData| user.name.clone())
+            .width("200px")
+            .fixed(TableFixed::Left),
+        TableColumn::new("age", "Age")
+            .value(|user: &UserData| user.age.to_string())
+            .sorter(|a: &UserData, b: &UserData| a.age.cmp(&b.age))
+            .width("120px"),
+        TableColumn::new("address", "Address")
+            .value(|user: &UserData| user.address.clone())
+            .width("400px"),
+        TableColumn::new("note", "Note")
+            .value(|user: &UserData| format!("Note for {}", user.name))
+            .width("400px"),
+        TableColumn::new("action", "Action")
+            .render(|_: &UserData, _| rsx! { a { "Delete" } })
+            .width("120px")
+            .fixed(TableFixed::Right),
+    ];
+
+    rsx! {
+        Table {
+            columns: columns,
+            data_source: rows.read().clone(),
+            size: TableSize::Small,
+            virtual_scroll: true,
+            scroll_x: true,
+            scroll_height: 400,
+        }
+    }
+}
+
+/// 展开行演示
+#[component]
+fn ExpandableTableDemo() -> Element {
+    let rows: Vec<UserData> = (1..=3)
+        .map(|index| UserData {
+            key: index.to_string(),
+            name: format!("John Brown {index}"),
+            age: 30 + index,
+            address: format!("New York No. {index} Lake Park"),
+            tags: Vec::new(),
+        })
+        .collect();
+
+    let columns = vec![
+        TableColumn::new("name", "Name").value(|user: &UserData| user.name.clone()),
+        TableColumn::new("age", "Age").value(|user: &UserData| user.age.to_string()),
+    ];
+
+    rsx! {
+        Table {
+            columns: columns,
+            data_source: rows,
+            expandable: TableExpandable {
+                expanded_row_render: Some(Rc::new(|user: &UserData, _| rsx! {
+                    p { style: "margin: 0;", "My name is {user.name}, I live in {user.address}." }
+                })),
+                row_expandable: Some(Rc::new(|user: &UserData| user.key != "2")),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Debug)]
+struct Department {
+    key: String,
+    name: String,
+    members: u32,
+    children: Vec<Department>,
+}
+
+impl TableRow for Department {
+    fn row_key(&self) -> String {
+        self.key.clone()
+    }
+
+    fn children(&self) -> &[Self] {
+        &self.children
+    }
+}
+
+/// 树形数据演示
+#[component]
+fn TreeTableDemo() -> Element {
+    let department = |key: &str, name: &str, members: u32, children: Vec<Department>| Department {
+        key: key.to_string(),
+        name: name.to_string(),
+        members,
+        children,
+    };
+    let rows = vec![
+        department(
+            "1",
+            "研发中心",
+            42,
+            vec![
+                department("1-1", "前端组", 12, Vec::new()),
+                department(
+                    "1-2",
+                    "后端组",
+                    30,
+                    vec![
+                        department("1-2-1", "服务端", 18, Vec::new()),
+                        department("1-2-2", "数据平台", 12, Vec::new()),
+                    ],
+                ),
+            ],
+        ),
+        department("2", "市场部", 8, Vec::new()),
+    ];
+
+    let columns = vec![
+        TableColumn::new("name", "部门").value(|row: &Department| row.name.clone()),
+        TableColumn::new("members", "人数")
+            .value(|row: &Department| row.members.to_string())
+            .sortable(),
+    ];
+
+    rsx! {
+        Table {
+            columns: columns,
+            data_source: rows,
+            row_selection: TableRowSelection::default(),
+            expandable: TableExpandable {
+                default_expand_all_rows: true,
+                ..Default::default()
+            },
+        }
+    }
+}
+
+fn editable_rows() -> Vec<UserData> {
+    (1..=3)
+        .map(|index| UserData {
+            key: index.to_string(),
+            name: format!("Edward King {index}"),
+            age: 30 + index,
+            address: format!("London, Park Lane no. {index}"),
+            tags: vec!["2024-01-0{index}".to_string()],
+        })
+        .collect()
+}
+
+fn replace_row(rows: &mut Signal<Vec<UserData>>, edited: UserData) {
+    rows.with_mut(|rows| {
+        if let Some(row) = rows.iter_mut().find(|row| row.key == edited.key) {
+            *row = edited;
+        }
+    });
+}
+
+/// 可编辑单元格演示
+#[component]
+fn EditableCellDemo() -> Element {
+    let mut rows = use_signal(editable_rows);
+
+    let columns = vec![
+        TableColumn::new("name", "Name")
+            .value(|user: &UserData| user.name.clone())
+            .editable(|user: &mut UserData, value| user.name = value.to_string())
+            .rules(vec![FormRule::required("请输入姓名")]),
+        TableColumn::new("age", "Age")
+            .value(|user: &UserData| user.age.to_string())
+            .editable(|user: &mut UserData, value| user.age = value.parse().unwrap_or(user.age))
+            .editor(TableCellEditor::input_number()),
+        TableColumn::new("address", "Address")
+            .value(|user: &UserData| user.address.clone())
+            .editable(|user: &mut UserData, value| user.address = value.to_string())
+            .editor(TableCellEditor::select(vec![
+                ("London, Park Lane no. 1".to_string(), "London".to_string()),
+                (
+                    "New York No. 1 Lake Park".to_string(),
+                    "New York".to_string(),
+                ),
+                ("Sidney No. 1 Lake Park".to_string(), "Sidney".to_string()),
+            ])),
+    ];
+
+    rsx! {
+        Table {
+            columns: columns,
+            data_source: rows.read().clone(),
+            editable: TableEditable {
+                on_save: Some(EventHandler::new(move |edited: UserData| replace_row(&mut rows, edited))),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+/// 可编辑行演示
+#[component]
+fn EditableRowDemo() -> Element {
+    let mut rows = use_signal(editable_rows);
+
+    let columns = vec![
+        TableColumn::new("name", "Name")
+            .value(|user: &UserData| user.name.clone())
+            .editable(|user: &mut UserData, value| user.name = value.to_string())
+            .rules(vec![
+                FormRule::required("请输入姓名"),
+                FormRule::max_length(20, "姓名不能超过20个字符"),
+            ]),
+        TableColumn::new("age", "Age")
+            .value(|user: &UserData| user.age.to_string())
+            .editable(|user: &mut UserData, value| user.age = value.parse().unwrap_or(user.age))
+            .editor(TableCellEditor::input_number())
+            .rules(vec![FormRule::required("请输入年龄")]),
+        TableColumn::new("joined", "Joined")
+            .value(|user: &UserData| user.tags.first().cloned().unwrap_or_default())
+            .editable(|user: &mut UserData, value| user.tags = vec![value.to_string()])
+            .editor(TableCellEditor::date_picker()),
+    ];
+
+    rsx! {
+        Table {
+            columns: columns,
+            data_source: rows.read().clone(),
+            editable: TableEditable {
+                mode: TableEditMode::Row,
+                on_save: Some(EventHandler::new(move |edited: UserData| replace_row(&mut rows, edited))),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+const COLUMN_STATE_KEY: &str = "table-demo-column-state";
+
+/// 列设置演示
+#[component]
+fn ColumnStateDemo() -> Element {
+    let mut column_state = use_signal(ColumnState::default);
+
+    // 从 localStorage 恢复保存的列布局
+    use_hook(|| {
+        spawn(async move {
+            let saved = document::eval(&format!(
+                "return localStorage.getItem('{COLUMN_STATE_KEY}');"
+            ))
+            .await;
+            if let Some(state) = saved
+                .ok()
+                .and_then(|value| value.as_str().map(str::to_string))
+                .and_then(|json| serde_json::from_str::<ColumnState>(&json).ok())
+            {
+                column_state.set(state);
+            }
+        })
+    });
+
+    let columns = vec![
+        TableColumn::new("name", "Name")
+            .value(|user: &UserData| user.name.clone())
+            .width("160px"),
+        TableColumn::new("age", "Age")
+            .value(|user: &UserData| user.age.to_string())
+            .width("100px"),
+        TableColumn::new("address", "Address")
+            .value(|user: &UserData| user.address.clone())
+            .width("260px"),
+    ];
+
+    rsx! {
+        Table {
+            columns: columns,
+            data_source: editable_rows(),
+            resizable: true,
+            reorderable: true,
+            show_column_picker: true,
+            column_state: column_state(),
+            on_column_state_change: move |state: ColumnState| {
+                if let Ok(json) = serde_json::to_string(&state) {
+                    document::eval(&format!(
+                        "localStorage.setItem('{COLUMN_STATE_KEY}', {});",
+                        serde_json::Value::String(json)
+                    ));
+                }
+                column_state.set(state);
+            },
+        }
+    }
+}
+
+/// 表头分组、合并单元格与总结栏演示
+#[component]
+fn GroupedTableDemo() -> Element {
+    let rows: Vec<UserData> = (1..=4)
+        .map(|index| UserData {
+            key: index.to_string(),
+            name: format!("John Brown {index}"),
+            age: 20 + index * 5,
+            address: if index <= 2 { "London" } else { "New York" }.to_string(),
+            tags: Vec::new(),
+        })
+        .collect();
+
+    let columns = vec![
+        TableColumn::new("address", "City")
+            .value(|user: &UserData| user.address.clone())
+            .width("120px")
+            .fixed(TableFixed::Left)
+            // 每两行合并为一个城市单元格
+            .on_cell(|_: &UserData, index| TableCellProps {
+                row_span: Some(if index % 2 == 0 { 2 } else { 0 }),
+                ..Default::default()
+            }),
+        TableColumn::group(
+            "user",
+            "User",
+            vec![
+                TableColumn::new("name", "Name").value(|user: &UserData| user.name.clone()),
+                TableColumn::new("age", "Age").value(|user: &UserData| user.age.to_string()),
+            ],
+        ),
+    ];
+
+    rsx! {
+        Table {
+            columns: columns,
+            data_source: rows,
+            summary: move |rows: Vec<UserData>| {
+                let total: u32 = rows.iter().map(|user| user.age).sum();
+                vec![TableSummaryRow::new(vec![
+                    TableSummaryCell::new("address", rsx! { "Total" }).col_span(2),
+                    TableSummaryCell::new("age", rsx! { "{total}" }),
+                ])]
+            },
+        }
+    }
+}
+
+/// 模拟服务端：按查询参数筛选、排序并分页
+async fn fetch_users(query: TableQuery) -> Result<TableResult<UserData>, String> {
+    gloo_timers::future::TimeoutFuture::new(600).await;
+
+    let cities = ["London", "New York", "Sidney"];
+    let mut rows: Vec<UserData> = (1..=87)
+        .map(|index| UserData {
+            key: index.to_string(),
+            name: format!("User {index}"),
+            age: 18 + (index * 7 % 50),
+            address: cities[index as usize % cities.len()].to_string(),
+            tags: Vec::new(),
+        })
+        .filter(|user| {
+            query
+                .filters
+                .get("address")
+                .is_none_or(|values| values.contains(&user.address))
+        })
+        .collect();
+    if let Some(sorter) = query.sorters.first() {
+        rows.sort_by_key(|user| user.age);
+        if sorter.order == SortOrder::Descend {
+            rows.reverse();
+        }
+    }
+
+    let total = rows.len();
+    let rows = rows
+        .into_iter()
+        .skip((query.page - 1) * query.page_size)
+        .take(query.page_size)
+        .collect();
+    Ok(TableResult { rows, total })
+}
+
+/// 远程数据演示
+#[component]
+fn RemoteTableDemo() -> Element {
+    let remote = use_hook(|| TableRemote::new(fetch_users));
+
+    let columns = vec![
+        TableColumn::new("name", "Name").value(|user: &UserData| user.name.clone()),
+        TableColumn::new("age", "Age")
+            .value(|user: &UserData| user.age.to_string())
+            .sortable(),
+        TableColumn::new("address", "Address")
+            .value(|user: &UserData| user.address.clone())
+            .filters(vec![
+                TableFilter::new("London", "London"),
+                TableFilter::new("New York", "New York"),
+                TableFilter::new("Sidney", "Sidney"),
+            ]),
+    ];
+
+    rsx! {
+        Table {
+            columns: columns,
+            data_source: Vec::new(),
+            remote: remote,
+            pagination: TablePagination {
+                current: 1,
+                page_size: 10,
+                total: 0,
+                show_quick_jumper: false,
+                show_size_changer: true,
+                page_size_options: vec!["10".to_string(), "20".to_string()],
+            },
+        }
+    }
+}
+
+/// 导出数据演示
+#[component]
+fn ExportTableDemo() -> Element {
+    let exporter = use_table_exporter::<UserData>();
+
+    let columns = vec![
+        TableColumn::new("name", "Name")
+            .value(|user: &UserData| user.name.clone())
+            .sortable(),
+        TableColumn::new("age", "Age")
+            .value(|user: &UserData| user.age.to_string())
+            .sortable(),
+        TableColumn::new("address", "Address")
+            .value(|user: &UserData| user.address.clone())
+            .filters(vec![
+                TableFilter::new("London", "London"),
+                TableFilter::new("New York", "New York"),
+            ])
+            .on_filter(|value: &str, user: &UserData| user.address.starts_with(value)),
+    ];
+    let rows: Vec<UserData> = (1..=20)
+        .map(|index| UserData {
+            key: index.to_string(),
+            name: format!("Edward \"King\" {index}"),
+            age: 20 + index,
+            address: if index % 2 == 0 {
+                format!("London, Park Lane no. {index}")
+            } else {
+                format!("New York No. {index} Lake Park")
+            },
+            tags: Vec::new(),
+        })
+        .collect();
+
+    let csv_exporter = exporter.clone();
+    let xlsx_exporter = exporter.clone();
+
+    rsx! {
+        div {
+            div { style: "margin-bottom: 16px; display: flex; gap: 8px;",
+                Button {
+                    onclick: move |_| {
+                        csv_exporter.download_csv("users.csv", &CsvOptions { bom: true, ..Default::default() })
+                    },
+                    "导出 CSV"
+                }
+                Button {
+                    onclick: move |_| xlsx_exporter.download_xlsx("users.xlsx", "Users"),
+                    "导出 XLSX"
+                }
+            }
+            Table {
+                columns: columns,
+                data_source: rows,
+                exporter: exporter,
+                pagination: TablePagination {
+                    current: 1,
+                    page_size: 5,
+                    total: 20,
+                    show_quick_jumper: false,
+                    show_size_changer: false,
+                    page_size_options: Vec::new(),
+                },
+            }
         }
     }
 }
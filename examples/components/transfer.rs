@@ -6,6 +6,7 @@ use crate::common::*;
 use ant_design_dioxus::prelude::*;
 use dioxus::prelude::Callback;
 use dioxus::prelude::*;
+use std::collections::HashMap;
 
 /// Transfer 组件演示
 #[component]
@@ -325,37 +326,20 @@ pub fn TransferDemo() -> Element {
                             rsx! {
                                 Table {
                                     row_selection: TableRowSelection {
-                                        checkbox_props: None,
-                                        column_width: None,
-                                        column_title: None,
-                                        fixed: false,
-                                        selected_row_keys: props.selected_keys.clone(),
+                                        selected_row_keys: Some(props.selected_keys.clone()),
                                         selection_type: SelectionType::Checkbox,
+                                        ..Default::default()
                                     },
                                     columns: vec![
-                                        TableColumn {
-                                            title: "Title".to_string(),
-                                            dataindex: "title".to_string(),
-                                            key: "title".to_string(),
-                                            width: None,
-                                            align: Some(TableAlign::Left),
-                                            sortable: false,
-                                            fixed: None,
-                                            show_sorter_tooltip: true,
-                                        },
-                                        TableColumn {
-                                            title: "Description".to_string(),
-                                            dataindex: "description".to_string(),
-                                            key: "description".to_string(),
-                                            width: None,
-                                            align: Some(TableAlign::Left),
-                                            sortable: false,
-                                            fixed: None,
-                                            show_sorter_tooltip: true,
-                                        },
+                                        TableColumn::new("title", "Title")
+                                            .value(|row: &HashMap<String, String>| row["title"].clone())
+                                            .align(TableAlign::Left),
+                                        TableColumn::new("description", "Description")
+                                            .value(|row: &HashMap<String, String>| row["description"].clone())
+                                            .align(TableAlign::Left),
                                     ],
                                     data_source: props.data_source.iter().map(|item| {
-                                        let mut map = std::collections::HashMap::new();
+                                        let mut map = HashMap::new();
                                         map.insert("key".to_string(), item.key.clone());
                                         map.insert("title".to_string(), item.title.clone());
                                         map.insert("description".to_string(), item.description.as_ref().unwrap_or(&String::new()).clone());
@@ -363,8 +347,9 @@ pub fn TransferDemo() -> Element {
                                     }).collect(),
                                     size: TableSize::Small,
                                     style: "pointer-events: none;",
-                                    on_row_click: move |key: String| {
+                                    on_row_click: move |row: HashMap<String, String>| {
                                         // 处理行点击事件
+                                        let key = row.row_key();
                                         if let Some(item) = props.data_source.iter().find(|item| item.key == key) {
                                             if !item.disabled {
                                                 // 这里需要调用 on_select_change 回调
@@ -7,7 +7,10 @@
 //! - 需要一个输入框而不是选择器。
 //! - 需要输入建议/辅助提示。
 
+use crate::components::select::{use_remote_options, LoadOptions};
+use crate::components::spin::{Spin, SpinSize};
 use crate::utils::class_names::conditional_class_names_array;
+use crate::utils::{fuzzy_match, highlight_segments};
 use dioxus::prelude::*;
 use std::collections::HashMap;
 
@@ -47,6 +50,26 @@ impl AutoCompleteOption {
     }
 }
 
+/// 按输入内容模糊筛选选项，匹配标签、值及汉字拼音首字母，并按匹配得分排序
+fn filter_options(options: &[AutoCompleteOption], search: &str) -> Vec<AutoCompleteOption> {
+    let mut matched: Vec<(i32, &AutoCompleteOption)> = options
+        .iter()
+        .filter_map(|option| {
+            let score = [&option.label, &option.value]
+                .into_iter()
+                .filter_map(|text| fuzzy_match(text, search))
+                .map(|result| result.score)
+                .max()?;
+            Some((score, option))
+        })
+        .collect();
+    matched.sort_by_key(|(score, _)| std::cmp::Reverse(*score));
+    matched
+        .into_iter()
+        .map(|(_, option)| option.clone())
+        .collect()
+}
+
 /// 自动完成尺寸
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum AutoCompleteSize {
@@ -120,6 +143,10 @@ pub struct AutoCompleteProps {
     /// 下拉菜单的最大高度
     #[props(default = 256)]
     pub dropdown_max_height: u32,
+    /// 远程搜索，输入变化时防抖调用异步加载函数获取选项，设置后忽略 `options`
+    pub load_options: Option<LoadOptions<AutoCompleteOption>>,
+    /// 没有匹配的选项时显示的内容，未设置时不显示下拉菜单
+    pub not_found_content: Option<Element>,
     /// 值变化时的回调
     pub on_change: Option<EventHandler<String>>,
     /// 选择选项时的回调
@@ -154,6 +181,9 @@ pub fn AutoComplete(props: AutoCompleteProps) -> Element {
     let on_focus_static = props.on_focus.clone();
     let on_blur_static = props.on_blur.clone();
     let on_press_enter_static = props.on_press_enter.clone();
+    let load_options_static = props.load_options.clone();
+    let is_remote = props.load_options.is_some();
+    let remote = use_remote_options::<AutoCompleteOption>();
 
     let mut input_value = use_signal(|| props.value.clone());
     let mut is_open = use_signal(|| false);
@@ -176,19 +206,13 @@ pub fn AutoComplete(props: AutoCompleteProps) -> Element {
         }
     });
 
-    // 过滤选项
+    // 过滤选项，远程搜索时直接使用查询结果
     use_effect(move || {
         let value = input_value.read();
-        if filter_option_static && !value.is_empty() {
-            let filtered: Vec<AutoCompleteOption> = options_static
-                .iter()
-                .filter(|option| {
-                    option.label.to_lowercase().contains(&value.to_lowercase())
-                        || option.value.to_lowercase().contains(&value.to_lowercase())
-                })
-                .cloned()
-                .collect();
-            filtered_options.set(filtered);
+        if is_remote {
+            filtered_options.set(remote.options.read().clone());
+        } else if filter_option_static && !value.is_empty() {
+            filtered_options.set(filter_options(&options_static, &value));
         } else {
             filtered_options.set(options_static.clone());
         }
@@ -211,6 +235,10 @@ pub fn AutoComplete(props: AutoCompleteProps) -> Element {
             callback.call(value.clone());
         }
 
+        if let Some(loader) = &load_options_static {
+            remote.search(loader, value.clone());
+        }
+
         if let Some(callback) = &on_search_static {
             callback.call(value);
         }
@@ -383,11 +411,35 @@ pub fn AutoComplete(props: AutoCompleteProps) -> Element {
                 }
             }
 
+            // 远程加载中或没有匹配的选项
+            if *is_open.read() && ((remote.loading)() || (display_options.read().is_empty() && props.not_found_content.is_some())) {
+                div {
+                    class: "ant-auto-complete-dropdown",
+                    div { class: "ant-auto-complete-dropdown-menu",
+                        if (remote.loading)() {
+                            div { class: "ant-auto-complete-dropdown-loading",
+                                Spin { size: SpinSize::Small }
+                            }
+                        } else {
+                            div { class: "ant-auto-complete-dropdown-empty",
+                                {props.not_found_content}
+                            }
+                        }
+                    }
+                }
+            }
+
             // 下拉选项
-            if *is_open.read() && !display_options.read().is_empty() {
+            else if *is_open.read() && !display_options.read().is_empty() {
                 {
                     let options_list = display_options.read().clone();
                     let current_active_index = *active_index.read();
+                    // 本地筛选时高亮标签中匹配的字符
+                    let search = if filter_option_static && !is_remote {
+                        input_value.read().clone()
+                    } else {
+                        String::new()
+                    };
 
                     rsx! {
                         div {
@@ -399,6 +451,9 @@ pub fn AutoComplete(props: AutoCompleteProps) -> Element {
                                     {
                                         let option_clone = option.clone();
                                         let option_clone2 = option.clone();
+                                        let ranges = fuzzy_match(&option.label, &search)
+                                            .map(|result| result.ranges)
+                                            .unwrap_or_default();
                                         rsx! {
                                             div {
                                                 key: "{option.value}-{index}",
@@ -418,7 +473,13 @@ pub fn AutoComplete(props: AutoCompleteProps) -> Element {
                                                     }
                                                 },
 
-                                                "{option.label}"
+                                                for (segment, matched) in highlight_segments(&option.label, &ranges) {
+                                                    if matched {
+                                                        span { class: "ant-auto-complete-dropdown-menu-item-highlight", "{segment}" }
+                                                    } else {
+                                                        "{segment}"
+                                                    }
+                                                }
                                             }
                                         }
                                     }
@@ -542,6 +603,25 @@ mod tests {
         assert_eq!(AutoCompleteStatus::default(), AutoCompleteStatus::Default);
     }
 
+    #[test]
+    fn test_filter_options() {
+        let options = vec![
+            AutoCompleteOption::new("gmail", "example@gmail.com"),
+            AutoCompleteOption::new("shanghai", "上海"),
+            AutoCompleteOption::new("mail", "mail.com"),
+        ];
+        let values = |search: &str| {
+            filter_options(&options, search)
+                .into_iter()
+                .map(|option| option.value)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(values(""), vec!["gmail", "shanghai", "mail"]);
+        assert_eq!(values("MAIL"), vec!["mail", "gmail"]);
+        assert_eq!(values("sh"), vec!["shanghai"]);
+        assert!(values("xyz").is_empty());
+    }
+
     #[test]
     fn test_auto_complete_option_builder() {
         let option = AutoCompleteOptionBuilder::new("value1", "Label 1")
@@ -8,8 +8,11 @@
 //! - 从一个较大的数据集合中进行选择时，用多级分类进行分隔，方便选择。
 //! - 比起 Select 组件，可以在同一个浮层中完成选择，有较好的体验。
 
+use crate::components::form::{use_form_item_control, value_to_text, ValidateStatus};
 use crate::utils::class_names::conditional_class_names_array;
+use crate::utils::{fuzzy_match, highlight_segments};
 use dioxus::prelude::*;
+use serde_json::Value;
 use std::collections::HashMap;
 
 const CASCADER_STYLE: &str = include_str!("./style.css");
@@ -187,6 +190,15 @@ pub fn Cascader(
     /// 自定义样式
     style: Option<String>,
 ) -> Element {
+    // 位于 FormItem 内时合并禁用与校验状态
+    let form_item = use_form_item_control();
+    let disabled = disabled || form_item.is_some_and(|item| item.is_disabled());
+    let status = status.or_else(|| match form_item.and_then(|item| item.status()) {
+        Some(ValidateStatus::Error) => Some("error".to_string()),
+        Some(ValidateStatus::Warning) => Some("warning".to_string()),
+        _ => None,
+    });
+
     // 克隆所有需要在闭包中使用的props，确保'static生命周期
     let options_static = options.clone();
     let placeholder_static = placeholder.clone();
@@ -204,6 +216,16 @@ pub fn Cascader(
     let mut search_value = use_signal(|| String::new());
     let mut filtered_options = use_signal(|| options_static.clone());
 
+    // 位于 FormItem 内时使用字段值，字段值为各级选项值组成的数组
+    use_effect(move || {
+        if let Some(value) = form_item.and_then(|item| item.value_for("value", "value")) {
+            selected_value.set(match value {
+                Value::Array(items) => items.iter().map(value_to_text).collect(),
+                _ => Vec::new(),
+            });
+        }
+    });
+
     // 搜索过滤效果
     {
         let options_clone = options_static.clone();
@@ -264,6 +286,9 @@ pub fn Cascader(
             {
                 selected_value.set(new_path.clone());
                 is_open.set(false);
+                if let Some(item) = form_item {
+                    item.trigger("on_change", new_path.clone());
+                }
                 if let Some(handler) = on_change_clone {
                     handler.call(new_path);
                 }
@@ -301,6 +326,9 @@ pub fn Cascader(
             selected_value.set(Vec::new());
             search_value.set(String::new());
             active_path.set(Vec::new());
+            if let Some(item) = form_item {
+                item.trigger("on_change", Vec::<String>::new());
+            }
 
             if let Some(callback) = on_change_clone {
                 callback.call(Vec::new());
@@ -412,6 +440,7 @@ pub fn Cascader(
                         active_path: active_path.read().clone(),
                         selected_value: selected_value.read().clone(),
                         expand_trigger: expand_trigger_static.clone(),
+                        search: if show_search { search_value.read().clone() } else { String::new() },
                         on_option_click: handle_option_click,
                     }
                 }
@@ -427,6 +456,8 @@ struct CascaderMenuProps {
     active_path: Vec<usize>,
     selected_value: Vec<String>,
     expand_trigger: String,
+    /// 搜索内容，用于高亮选项中匹配的字符
+    search: String,
     on_option_click: EventHandler<(Vec<usize>, CascaderOption)>,
 }
 
@@ -438,6 +469,7 @@ fn CascaderMenu(props: CascaderMenuProps) -> Element {
     let active_path = props.active_path.clone();
     let expand_trigger = props.expand_trigger;
     let on_option_click = props.on_option_click.clone();
+    let search = props.search.clone();
 
     let mut hover_path = use_signal(|| Vec::<usize>::new());
 
@@ -485,7 +517,16 @@ fn CascaderMenu(props: CascaderMenuProps) -> Element {
                             },
 
                             span { class: "ant-cascader-menu-item-content",
-                                "{option.label}"
+                                for (segment, matched) in highlight_segments(
+                                    &option.label,
+                                    &fuzzy_match(&option.label, &search).map(|result| result.ranges).unwrap_or_default(),
+                                ) {
+                                    if matched {
+                                        span { class: "ant-cascader-menu-item-keyword", "{segment}" }
+                                    } else {
+                                        "{segment}"
+                                    }
+                                }
                             }
 
                             if option.children.is_some() {
@@ -536,24 +577,44 @@ fn CascaderMenu(props: CascaderMenuProps) -> Element {
 // 辅助函数
 
 /// 过滤选项
+///
+/// 模糊匹配标签、值及汉字拼音首字母，每一级按匹配得分排序，
+/// 父级的得分取自身与子级中的最高得分。
 fn filter_options(options: &[CascaderOption], search: &str) -> Vec<CascaderOption> {
+    scored_options(options, search)
+        .into_iter()
+        .map(|(_, option)| option)
+        .collect()
+}
+
+fn scored_options(options: &[CascaderOption], search: &str) -> Vec<(i32, CascaderOption)> {
     let mut filtered = Vec::new();
 
     for option in options {
-        if option.label.to_lowercase().contains(&search.to_lowercase())
-            || option.value.to_lowercase().contains(&search.to_lowercase())
-        {
-            filtered.push(option.clone());
+        let score = [&option.label, &option.value]
+            .into_iter()
+            .filter_map(|text| fuzzy_match(text, search))
+            .map(|result| result.score)
+            .max();
+        if let Some(score) = score {
+            filtered.push((score, option.clone()));
         } else if let Some(children) = &option.children {
-            let filtered_children = filter_options(children, search);
-            if !filtered_children.is_empty() {
+            let filtered_children = scored_options(children, search);
+            if let Some((score, _)) = filtered_children.first() {
                 let mut new_option = option.clone();
-                new_option.children = Some(filtered_children);
-                filtered.push(new_option);
+                let score = *score;
+                new_option.children = Some(
+                    filtered_children
+                        .into_iter()
+                        .map(|(_, child)| child)
+                        .collect(),
+                );
+                filtered.push((score, new_option));
             }
         }
     }
 
+    filtered.sort_by_key(|(score, _)| std::cmp::Reverse(*score));
     filtered
 }
 
@@ -711,6 +772,17 @@ mod tests {
         let filtered = filter_options(&options, "test");
         assert_eq!(filtered.len(), 1);
         assert_eq!(filtered[0].value, "option2");
+
+        // 按得分排序，子级匹配时保留父级
+        let cities = vec![
+            CascaderOption::new("zhejiang", "浙江")
+                .with_children(vec![CascaderOption::new("hangzhou", "杭州市")]),
+            CascaderOption::new("hz", "惠州"),
+        ];
+        let filtered = filter_options(&cities, "hz");
+        assert_eq!(filtered.len(), 2);
+        assert_eq!(filtered[0].value, "hz");
+        assert_eq!(filtered[1].children.as_ref().unwrap()[0].value, "hangzhou");
     }
 
     #[test]
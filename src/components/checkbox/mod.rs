@@ -27,7 +27,9 @@
 //! }
 //! ```
 
+use crate::components::form::{use_form_item_boundary, use_form_item_control, value_to_text};
 use dioxus::prelude::*;
+use serde_json::Value;
 
 const CHECKBOX_STYLE: &str = include_str!("./style.css");
 
@@ -114,15 +116,23 @@ pub struct CheckboxProps {
 /// 复选框
 #[component]
 pub fn Checkbox(props: CheckboxProps) -> Element {
+    let form_item = use_form_item_control();
     let mut internal_checked = use_signal(|| props.default_checked);
 
-    // 使用外部传入的 checked 值，如果没有则使用内部状态
-    let is_checked = props.checked || internal_checked();
+    // 使用外部传入的 checked 值，如果没有则使用内部状态；位于 FormItem 内时使用字段值
+    let is_checked = match form_item.and_then(|item| item.value_for("checked", "checked")) {
+        Some(value) => value.as_bool().unwrap_or_default(),
+        None => props.checked || internal_checked(),
+    };
+    let disabled = props.disabled || form_item.is_some_and(|item| item.is_disabled());
 
     let handle_change = move |_| {
-        if !props.disabled {
+        if !disabled {
             let new_checked = !is_checked;
             internal_checked.set(new_checked);
+            if let Some(item) = form_item {
+                item.trigger("on_change", new_checked);
+            }
             if let Some(on_change) = &props.on_change {
                 on_change.call(new_checked);
             }
@@ -136,6 +146,9 @@ pub fn Checkbox(props: CheckboxProps) -> Element {
     };
 
     let handle_blur = move |evt: FocusEvent| {
+        if let Some(item) = form_item {
+            item.trigger("on_blur", is_checked);
+        }
         if let Some(on_blur) = &props.on_blur {
             on_blur.call(evt);
         }
@@ -144,7 +157,7 @@ pub fn Checkbox(props: CheckboxProps) -> Element {
     let checkbox_class = {
         let mut classes = vec!["ant-checkbox-wrapper"];
 
-        if props.disabled {
+        if disabled {
             classes.push("ant-checkbox-wrapper-disabled");
         }
 
@@ -171,7 +184,7 @@ pub fn Checkbox(props: CheckboxProps) -> Element {
             classes.push("ant-checkbox-indeterminate");
         }
 
-        if props.disabled {
+        if disabled {
             classes.push("ant-checkbox-disabled");
         }
 
@@ -193,7 +206,7 @@ pub fn Checkbox(props: CheckboxProps) -> Element {
                     r#type: "checkbox",
                     class: "ant-checkbox-input",
                     checked: is_checked,
-                    disabled: props.disabled,
+                    disabled: disabled,
                     value: props.value.unwrap_or_default(),
                     onfocus: handle_focus,
                     onblur: handle_blur
@@ -288,19 +301,23 @@ impl CheckboxOption {
 /// 复选框组
 #[component]
 pub fn CheckboxGroup(props: CheckboxGroupProps) -> Element {
+    let form_item = use_form_item_control();
+    use_form_item_boundary();
     let mut internal_value = use_signal(|| props.default_value.clone());
 
-    // 使用外部传入的 value，如果没有则使用内部状态
-    let current_value = if !props.value.is_empty() {
-        props.value.clone()
-    } else {
-        internal_value()
+    // 使用外部传入的 value，如果没有则使用内部状态；位于 FormItem 内时使用字段值
+    let current_value = match form_item.and_then(|item| item.value_for("value", "value")) {
+        Some(Value::Array(items)) => items.iter().map(value_to_text).collect(),
+        Some(_) => Vec::new(),
+        None if !props.value.is_empty() => props.value.clone(),
+        None => internal_value(),
     };
+    let disabled = props.disabled || form_item.is_some_and(|item| item.is_disabled());
 
     let current_value01 = current_value.clone();
     let handle_checkbox_change = Callback::new(move |args: (String, bool)| {
         let (option_value, checked) = args;
-        if !props.disabled {
+        if !disabled {
             let mut new_value = current_value01.clone();
 
             if checked {
@@ -312,6 +329,9 @@ pub fn CheckboxGroup(props: CheckboxGroupProps) -> Element {
             }
 
             internal_value.set(new_value.clone());
+            if let Some(item) = form_item {
+                item.trigger("on_change", new_value.clone());
+            }
             if let Some(on_change) = &props.on_change {
                 on_change.call(new_value);
             }
@@ -321,7 +341,7 @@ pub fn CheckboxGroup(props: CheckboxGroupProps) -> Element {
     let group_class = {
         let mut classes = vec!["ant-checkbox-group"];
 
-        if props.disabled {
+        if disabled {
             classes.push("ant-checkbox-group-disabled");
         }
 
@@ -348,7 +368,7 @@ pub fn CheckboxGroup(props: CheckboxGroupProps) -> Element {
                     Checkbox {
                         key: option.value.clone(),
                         checked: current_value.contains(&option.value),
-                        disabled: props.disabled || option.disabled,
+                        disabled: disabled || option.disabled,
                         size: props.size,
                         value: option.value.clone(),
                         on_change: {
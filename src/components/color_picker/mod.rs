@@ -1,4 +1,6 @@
+use crate::components::form::use_form_item_control;
 use dioxus::prelude::*;
+use serde_json::Value;
 
 const COLOR_PICKER_STYLE: &str = include_str!("./style.css");
 
@@ -297,10 +299,23 @@ pub fn ColorPicker(props: ColorPickerProps) -> Element {
     let mut current_format = use_signal(|| props.format.clone());
     let _is_dragging = use_signal(|| false);
 
+    // Bind to the surrounding FormItem, the field value is the hex string
+    let form_item = use_form_item_control();
+    use_effect(move || {
+        if let Some(Value::String(color)) =
+            form_item.and_then(|item| item.value_for("value", "value"))
+        {
+            if !current_color.peek().hex.eq_ignore_ascii_case(&color) {
+                current_color.set(ColorValue::from_hex(&color));
+            }
+        }
+    });
+    let disabled = props.disabled || form_item.is_some_and(|item| item.is_disabled());
+
     let class_name = format!(
         "ant-color-picker ant-color-picker-{} {} {}",
         props.size.as_str(),
-        if props.disabled {
+        if disabled {
             "ant-color-picker-disabled"
         } else {
             ""
@@ -317,7 +332,7 @@ pub fn ColorPicker(props: ColorPickerProps) -> Element {
     );
 
     let handle_trigger_click = move |_evt: MouseEvent| {
-        if !props.disabled {
+        if !disabled {
             let new_open = !*is_open.read();
             is_open.set(new_open);
             if let Some(on_open_change) = &props.on_open_change {
@@ -328,6 +343,9 @@ pub fn ColorPicker(props: ColorPickerProps) -> Element {
 
     let mut handle_color_change = move |new_color: ColorValue| {
         current_color.set(new_color.clone());
+        if let Some(item) = form_item {
+            item.trigger("on_change", new_color.hex.clone());
+        }
         if let Some(on_change) = &props.on_change {
             on_change.call(new_color);
         }
@@ -348,6 +366,9 @@ pub fn ColorPicker(props: ColorPickerProps) -> Element {
 
     let handle_clear = move |_evt: MouseEvent| {
         _evt.stop_propagation();
+        if let Some(item) = form_item {
+            item.trigger("on_change", Value::Null);
+        }
         if let Some(on_clear) = &props.on_clear {
             on_clear.call(());
         }
@@ -24,7 +24,9 @@
 //! }
 //! ```
 
+use crate::components::form::{merge_form_status, use_form_item_control, value_to_text};
 use dioxus::prelude::*;
+use serde_json::Value;
 use std::fmt;
 
 const DATE_PICKER_STYLE: &str = include_str!("./style.css");
@@ -192,6 +194,7 @@ pub struct DatePickerProps {
 /// DatePicker 组件
 #[component]
 pub fn DatePicker(props: DatePickerProps) -> Element {
+    let form_item = use_form_item_control();
     let mut open = use_signal(|| false);
     let mut current_value =
         use_signal(|| props.value.clone().or_else(|| props.default_value.clone()));
@@ -202,13 +205,31 @@ pub fn DatePicker(props: DatePickerProps) -> Element {
             .unwrap_or_else(|| String::new())
     });
 
+    // 位于 FormItem 内时使用字段值，字段值为日期字符串
+    use_effect(move || {
+        if let Some(value) = form_item.and_then(|item| item.value_for("value", "value")) {
+            let date = Some(value_to_text(&value)).filter(|date| !date.is_empty());
+            input_value.set(date.clone().unwrap_or_default());
+            current_value.set(date);
+        }
+    });
+    let disabled = props.disabled || form_item.is_some_and(|item| item.is_disabled());
+    let status = merge_form_status(
+        form_item,
+        props.status.clone(),
+        DatePickerStatus::Default,
+        DatePickerStatus::Error,
+        DatePickerStatus::Warning,
+    );
+    let trigger_form = move |event: &str, date: Option<String>| {
+        if let Some(item) = form_item {
+            item.trigger(event, date.map(Value::from).unwrap_or(Value::Null));
+        }
+    };
+
     let class_name = format!(
         "ant-picker {} {} {} {} {} {}",
-        if props.disabled {
-            "ant-picker-disabled"
-        } else {
-            ""
-        },
+        if disabled { "ant-picker-disabled" } else { "" },
         if !props.bordered {
             "ant-picker-borderless"
         } else {
@@ -216,8 +237,8 @@ pub fn DatePicker(props: DatePickerProps) -> Element {
         },
         if open() { "ant-picker-focused" } else { "" },
         format!("ant-picker-{}", props.size),
-        if props.status != DatePickerStatus::Default {
-            format!("ant-picker-status-{}", props.status)
+        if status != DatePickerStatus::Default {
+            format!("ant-picker-status-{}", status)
         } else {
             String::new()
         },
@@ -237,7 +258,7 @@ pub fn DatePicker(props: DatePickerProps) -> Element {
     });
 
     let handle_input_click = move |_| {
-        if !props.disabled {
+        if !disabled {
             open.set(!open());
             if let Some(handler) = &props.on_open_change {
                 handler.call(open());
@@ -247,9 +268,10 @@ pub fn DatePicker(props: DatePickerProps) -> Element {
 
     let handle_clear = move |e: MouseEvent| {
         e.stop_propagation();
-        if !props.disabled && props.allow_clear {
+        if !disabled && props.allow_clear {
             current_value.set(None);
             input_value.set(String::new());
+            trigger_form("on_change", None);
             if let Some(handler) = &props.on_change {
                 handler.call(None);
             }
@@ -260,6 +282,7 @@ pub fn DatePicker(props: DatePickerProps) -> Element {
         current_value.set(Some(date.clone()));
         input_value.set(date.clone());
         open.set(false);
+        trigger_form("on_change", Some(date.clone()));
 
         if let Some(handler) = &props.on_change {
             handler.call(Some(date.clone()));
@@ -279,6 +302,7 @@ pub fn DatePicker(props: DatePickerProps) -> Element {
     };
 
     let handle_blur = move |e: FocusEvent| {
+        trigger_form("on_blur", current_value());
         if let Some(handler) = &props.on_blur {
             handler.call(e);
         }
@@ -300,14 +324,14 @@ pub fn DatePicker(props: DatePickerProps) -> Element {
                     r#type: "text",
                     placeholder: placeholder_text,
                     value: input_value(),
-                    disabled: props.disabled,
+                    disabled: disabled,
                     readonly: true,
                     autofocus: props.auto_focus,
                     onfocus: handle_focus,
                     onblur: handle_blur,
                 }
 
-                if props.allow_clear && !input_value().is_empty() && !props.disabled {
+                if props.allow_clear && !input_value().is_empty() && !disabled {
                     span {
                         class: "ant-picker-clear",
                         onclick: handle_clear,
@@ -0,0 +1,296 @@
+//! Form 控件绑定
+//!
+//! `FormItem` 通过 [`FormItemContext`] 向内部的数据录入组件提供字段信息，组件通过
+//! [`use_form_item_control`] 读取字段值、禁用与校验状态，并在触发事件时将值写回表单。
+
+use super::{FormContext, FormInstance, ValidateStatus};
+use crate::config_provider::ValidateTrigger;
+use dioxus::prelude::*;
+use serde_json::Value;
+use std::rc::Rc;
+
+/// 默认收集字段值的事件
+pub const DEFAULT_TRIGGER: &str = "on_change";
+
+/// 控件事件对应的校验时机
+fn event_trigger(event: &str) -> Option<ValidateTrigger> {
+    match event {
+        "on_change" => Some(ValidateTrigger::OnChange),
+        "on_blur" => Some(ValidateTrigger::OnBlur),
+        _ => None,
+    }
+}
+
+/// FormItem 提供给内部控件的字段信息
+#[derive(Clone)]
+pub struct FormItemContext {
+    /// 字段路径，为 None 时内部控件不绑定表单
+    pub name: Option<String>,
+    /// 绑定字段值的控件属性，为 None 时使用控件的默认属性，如 Switch 为 `checked`
+    pub value_prop_name: Option<String>,
+    /// 收集字段值的事件，如 `on_change`、`on_blur`
+    pub trigger: String,
+    /// 上报字段值
+    pub(crate) on_change: Rc<dyn Fn(String, Value)>,
+}
+
+impl PartialEq for FormItemContext {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name
+            && self.value_prop_name == other.value_prop_name
+            && self.trigger == other.trigger
+            && Rc::ptr_eq(&self.on_change, &other.on_change)
+    }
+}
+
+/// 控件读取的字段值与校验状态
+type FieldSnapshot = Option<(Value, Option<ValidateStatus>)>;
+
+/// 数据录入组件与所在表单项的绑定
+#[derive(Clone, Copy, PartialEq)]
+pub struct FormItemControl {
+    form: FormInstance,
+    item: Signal<FormItemContext>,
+    /// 所在字段的值与校验状态，其他字段变化时不通知控件重新渲染
+    field: Memo<FieldSnapshot>,
+    disabled: bool,
+}
+
+impl FormItemControl {
+    /// 字段路径
+    pub fn name(&self) -> String {
+        self.item.read().name.clone().unwrap_or_default()
+    }
+
+    /// 控件的 `prop` 属性是否绑定字段值，`default` 为控件默认绑定的属性
+    pub fn binds(&self, prop: &str, default: &str) -> bool {
+        self.item
+            .read()
+            .value_prop_name
+            .as_deref()
+            .unwrap_or(default)
+            == prop
+    }
+
+    /// 字段值，字段不存在时为 `null`
+    pub fn value(&self) -> Value {
+        self.field
+            .read()
+            .as_ref()
+            .map_or(Value::Null, |(value, _)| value.clone())
+    }
+
+    /// `prop` 属性绑定字段值时返回字段值
+    pub fn value_for(&self, prop: &str, default: &str) -> Option<Value> {
+        self.binds(prop, default).then(|| self.value())
+    }
+
+    /// 控件触发事件：事件与表单项的 `trigger` 一致时将值写回表单，值变化时按校验时机校验；
+    /// 失焦等其他事件在字段的校验时机包含该事件时校验
+    pub fn trigger(&self, event: &str, value: impl Into<Value>) {
+        let item = self.item.peek().clone();
+        let Some(name) = item.name else {
+            return;
+        };
+        if item.trigger == event {
+            (item.on_change)(name.clone(), value.into());
+        }
+        if let Some(trigger) =
+            event_trigger(event).filter(|trigger| *trigger != ValidateTrigger::OnChange)
+        {
+            self.form.trigger_validate(&name, trigger);
+        }
+    }
+
+    /// 表单是否禁用
+    pub fn is_disabled(&self) -> bool {
+        self.disabled
+    }
+
+    /// 字段的校验状态
+    pub fn status(&self) -> Option<ValidateStatus> {
+        self.field
+            .read()
+            .as_ref()
+            .and_then(|(_, status)| status.clone())
+    }
+
+    /// 字段是否校验失败
+    pub fn has_error(&self) -> bool {
+        self.status() == Some(ValidateStatus::Error)
+    }
+}
+
+/// 获取所在表单项的绑定，不在设置了 `name` 的 `FormItem` 内时返回 None
+pub fn use_form_item_control() -> Option<FormItemControl> {
+    let form_context = try_use_context::<FormContext>();
+    let item = try_use_context::<Signal<FormItemContext>>();
+
+    // 只订阅所在字段，输入时表单中的其他控件不会重新渲染
+    let form = form_context.as_ref().map(|context| context.form);
+    let field = use_memo(move || {
+        let name = item?.read().name.clone()?;
+        let form = form?;
+        let status = form
+            .fields
+            .read()
+            .get(&name)
+            .and_then(|field| field.status.clone());
+        Some((form.get_field_value(&name).unwrap_or(Value::Null), status))
+    });
+
+    let (form_context, item) = (form_context?, item?);
+    item.read().name.as_ref()?;
+    Some(FormItemControl {
+        form: form_context.form,
+        item,
+        field,
+        disabled: form_context.disabled,
+    })
+}
+
+/// 合并控件自身的状态与字段的校验状态：控件未设置状态（为 `normal`）时使用字段的校验状态
+pub fn merge_form_status<S: PartialEq>(
+    control: Option<FormItemControl>,
+    status: S,
+    normal: S,
+    error: S,
+    warning: S,
+) -> S {
+    if status != normal {
+        return status;
+    }
+    match control.and_then(|control| control.status()) {
+        Some(ValidateStatus::Error) => error,
+        Some(ValidateStatus::Warning) => warning,
+        _ => status,
+    }
+}
+
+/// 阻止内部控件绑定所在的表单项，由 CheckboxGroup、RadioGroup 等自身绑定字段的组合控件调用
+pub fn use_form_item_boundary() {
+    use_context_provider(|| {
+        Signal::new(FormItemContext {
+            name: None,
+            value_prop_name: None,
+            trigger: DEFAULT_TRIGGER.to_string(),
+            on_change: Rc::new(|_, _| {}),
+        })
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::form::tests::{mark_checked, render_checked};
+    use crate::components::form::{use_form, Form, FormItem};
+    use serde_json::json;
+    use std::cell::{Cell, RefCell};
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_form_item_control() {
+        #[component]
+        fn BoundControl() -> Element {
+            let form = use_context::<FormContext>().form;
+            let control = use_form_item_control();
+            use_hook(move || {
+                let control = control.expect("FormItem 内的控件应绑定字段");
+                assert_eq!(control.name(), "agreement");
+                assert!(control.binds("checked", "value"));
+                assert_eq!(control.value_for("value", "value"), None);
+                assert_eq!(control.value_for("checked", "value"), Some(json!(false)));
+
+                // 仅 trigger 指定的事件写回字段值
+                control.trigger("on_change", true);
+                assert_eq!(form.get_field_value("agreement"), Some(json!(false)));
+                control.trigger("on_blur", true);
+                assert_eq!(form.get_field_value("agreement"), Some(json!(true)));
+                assert!(form.is_field_touched("agreement"));
+                mark_checked();
+            });
+            rsx! {}
+        }
+
+        #[component]
+        fn UnboundControl() -> Element {
+            let control = use_form_item_control();
+            use_hook(move || {
+                assert!(control.is_none());
+                mark_checked();
+            });
+            rsx! {}
+        }
+
+        #[component]
+        fn App() -> Element {
+            rsx! {
+                Form {
+                    initial_values: json!({ "agreement": false }),
+                    FormItem {
+                        name: "agreement",
+                        value_prop_name: "checked",
+                        trigger: "on_blur",
+                        BoundControl {}
+                    }
+                    FormItem { label: "说明", UnboundControl {} }
+                }
+            }
+        }
+
+        assert_eq!(render_checked(App), 2);
+
+        assert_eq!(
+            merge_form_status(None, "warning", "", "error", "warning"),
+            "warning"
+        );
+        assert_eq!(merge_form_status(None, "", "", "error", "warning"), "");
+    }
+
+    #[test]
+    fn test_form_item_control_subscribes_own_field() {
+        thread_local! {
+            static RENDERS: RefCell<HashMap<String, usize>> = RefCell::default();
+            static FORM: Cell<Option<FormInstance>> = const { Cell::new(None) };
+        }
+
+        #[component]
+        fn Control() -> Element {
+            if let Some(control) = use_form_item_control() {
+                control.value();
+                control.status();
+                RENDERS
+                    .with(|renders| *renders.borrow_mut().entry(control.name()).or_default() += 1);
+            }
+            rsx! {}
+        }
+
+        #[component]
+        fn App() -> Element {
+            let form = use_form();
+            FORM.with(|current| current.set(Some(form)));
+            rsx! {
+                Form { form,
+                    FormItem { name: "title", Control {} }
+                    FormItem { name: "content", Control {} }
+                }
+            }
+        }
+
+        let renders = |name: &str| RENDERS.with(|renders| renders.borrow().get(name).copied());
+        let mut dom = VirtualDom::new(App);
+        dom.rebuild_in_place();
+        dom.process_events();
+        dom.render_immediate(&mut dioxus_core::NoOpMutations);
+        let (title, content) = (renders("title"), renders("content"));
+        assert!(title.is_some() && content.is_some());
+
+        // 修改其他字段时控件不重新渲染
+        let form = FORM.with(Cell::get).expect("表单已创建");
+        dom.in_runtime(|| form.change_field("content", json!("正文")));
+        dom.process_events();
+        dom.render_immediate(&mut dioxus_core::NoOpMutations);
+        assert_eq!(renders("title"), title);
+        assert!(renders("content") > content);
+    }
+}
@@ -0,0 +1,1301 @@
+//! Form 实例
+//!
+//! 通过 [`use_form`] 创建表单实例并传给 `Form`，即可在表单外部读写字段、校验和提交。
+
+use super::{
+    flatten_object, format_message, get_value_by_path, is_empty_value, is_path_prefix,
+    list_item_index, set_value_by_path, FormField, FormRule, ListChange, ValidateStatus,
+};
+use crate::config_provider::ValidateTrigger;
+use crate::locale::LocaleConfig;
+use dioxus::prelude::*;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::rc::Rc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+static FORM_ID: AtomicUsize = AtomicUsize::new(0);
+
+/// 表单提交函数，由 `Form` 注册
+type SubmitFn = Rc<dyn Fn()>;
+
+/// 字段进行中的异步校验
+#[derive(Default)]
+struct PendingValidation {
+    /// 最近一轮校验的序号，用于丢弃过期的结果
+    id: u64,
+    /// 进行中的校验任务，开始新一轮校验时取消
+    task: Option<Task>,
+}
+
+/// 通过 [`FormInstance::set_fields`] 设置的字段状态
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct FieldData {
+    /// 字段路径
+    pub name: String,
+    /// 字段值，为 None 时不修改
+    pub value: Option<Value>,
+    /// 错误信息，为 None 时不修改，为空时清除错误
+    pub errors: Option<Vec<String>>,
+    /// 是否被用户操作过，为 None 时不修改
+    pub touched: Option<bool>,
+}
+
+impl FieldData {
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// 设置字段值
+    pub fn value(mut self, value: impl Into<Value>) -> Self {
+        self.value = Some(value.into());
+        self
+    }
+
+    /// 设置错误信息
+    pub fn errors(mut self, errors: Vec<String>) -> Self {
+        self.errors = Some(errors);
+        self
+    }
+
+    /// 设置是否被用户操作过
+    pub fn touched(mut self, touched: bool) -> Self {
+        self.touched = Some(touched);
+        self
+    }
+}
+
+/// 校验失败的字段
+#[derive(Debug, Clone, PartialEq)]
+pub struct ErrorField {
+    /// 字段路径
+    pub name: String,
+    /// 错误信息
+    pub errors: Vec<String>,
+}
+
+impl ErrorField {
+    /// 由校验返回的各字段错误信息生成，按字段路径排序
+    pub fn from_errors(errors: HashMap<String, String>) -> Vec<Self> {
+        let mut error_fields: Vec<Self> = errors
+            .into_iter()
+            .map(|(name, error)| Self {
+                name,
+                errors: vec![error],
+            })
+            .collect();
+        error_fields.sort_by(|a, b| a.name.cmp(&b.name));
+        error_fields
+    }
+}
+
+/// 表单提交失败的信息，传给 `Form` 的 `on_finish_failed`
+#[derive(Debug, Clone, PartialEq)]
+pub struct FinishFailedInfo {
+    /// 嵌套结构的表单值
+    pub values: Value,
+    /// 校验失败的字段
+    pub error_fields: Vec<ErrorField>,
+}
+
+/// 字段值与初始值是否不同，均为空值时视为相同
+fn is_value_changed(initial: Option<&Value>, value: &Value) -> bool {
+    let initial = initial.unwrap_or(&Value::Null);
+    initial != value && !(is_empty_value(initial) && is_empty_value(value))
+}
+
+/// 由各字段的值组装出嵌套结构的表单值，上级路径的值先写入，再由下级路径覆盖
+///
+/// 上级字段的值中不存在的空值下级字段不写入，避免改变上级字段的结构。
+fn collect_values(fields: &HashMap<String, FormField>) -> Value {
+    let mut names: Vec<&String> = fields.keys().collect();
+    names.sort();
+    let mut values = Value::Object(Default::default());
+    for name in names {
+        let value = &fields[name].value;
+        if value.is_null() && get_value_by_path(&values, name).is_none() && has_parent(fields, name)
+        {
+            continue;
+        }
+        set_value_by_path(&mut values, name, value.clone());
+    }
+    values
+}
+
+/// 是否存在上级路径的字段
+fn has_parent(fields: &HashMap<String, FormField>, name: &str) -> bool {
+    fields
+        .keys()
+        .any(|parent| parent != name && is_path_prefix(parent, name))
+}
+
+/// 写入上级字段后同步其下级字段：取新值中对应路径的值，路径不存在时清空，
+/// 避免下级字段的旧值在组装表单值时覆盖新值
+fn sync_child_values(fields: &mut HashMap<String, FormField>, name: &str) {
+    let Some(value) = fields.get(name).map(|field| field.value.clone()) else {
+        return;
+    };
+    let mut root = Value::Null;
+    set_value_by_path(&mut root, name, value);
+    for (child, field) in fields.iter_mut() {
+        if child != name && is_path_prefix(name, child) {
+            field.value = get_value_by_path(&root, child)
+                .cloned()
+                .unwrap_or(Value::Null);
+        }
+    }
+}
+
+/// 将嵌套结构的值写入字段：已有字段按路径读取，其余的值按叶子节点创建新字段
+fn merge_values(fields: &mut HashMap<String, FormField>, values: &Value, skip_touched: bool) {
+    let mut updated = Vec::new();
+    for (name, field) in fields.iter_mut() {
+        if skip_touched && field.touched {
+            continue;
+        }
+        if let Some(value) = get_value_by_path(values, name) {
+            field.value = value.clone();
+            updated.push(name.clone());
+        }
+    }
+    // 由上到下同步，下级字段取到的值与批量设置的值一致
+    updated.sort();
+    for name in updated {
+        sync_child_values(fields, &name);
+    }
+    for (name, value) in flatten_object(values) {
+        let covered = fields
+            .keys()
+            .any(|field| is_path_prefix(field, &name) || is_path_prefix(&name, field));
+        if !covered {
+            let mut field = FormField::new(&name);
+            field.value = value;
+            fields.insert(name, field);
+        }
+    }
+}
+
+/// 将上级字段的值更新为其下级字段组装后的值，如列表字段的值与各列表项保持一致
+fn sync_parent_values(fields: &mut HashMap<String, FormField>) {
+    let values = collect_values(fields);
+    let parents: Vec<String> = fields
+        .keys()
+        .filter(|name| {
+            fields
+                .keys()
+                .any(|child| child != *name && is_path_prefix(name, child))
+        })
+        .cloned()
+        .collect();
+    for name in parents {
+        if let Some(field) = fields.get_mut(&name) {
+            field.value = get_value_by_path(&values, &name)
+                .cloned()
+                .unwrap_or(Value::Null);
+        }
+    }
+}
+
+/// 表单实例
+#[derive(Clone, Copy, PartialEq)]
+pub struct FormInstance {
+    /// 表单 id，用于生成字段的 DOM id
+    id: usize,
+    /// 所有字段，以字段路径为键
+    pub(crate) fields: Signal<HashMap<String, FormField>>,
+    /// 初始值，重置字段时恢复
+    initial_values: CopyValue<Value>,
+    /// `Form` 注册的提交函数
+    submit_fn: CopyValue<Option<SubmitFn>>,
+    /// 校验提示使用的语言包
+    locale: CopyValue<LocaleConfig>,
+    /// 创建表单实例的组件，异步校验在该组件中执行
+    scope: ScopeId,
+    /// 各字段进行中的异步校验
+    pending: CopyValue<HashMap<String, PendingValidation>>,
+    /// 表单默认的校验时机
+    validate_trigger: CopyValue<Vec<ValidateTrigger>>,
+}
+
+impl FormInstance {
+    /// 创建表单实例，需要在组件内调用
+    pub fn new() -> Self {
+        Self {
+            id: FORM_ID.fetch_add(1, Ordering::Relaxed),
+            fields: Signal::new(HashMap::new()),
+            initial_values: CopyValue::new(Value::Null),
+            submit_fn: CopyValue::new(None),
+            locale: CopyValue::new(LocaleConfig::default()),
+            scope: current_scope_id().unwrap_or(ScopeId::ROOT),
+            pending: CopyValue::new(HashMap::new()),
+            validate_trigger: CopyValue::new(vec![ValidateTrigger::OnChange]),
+        }
+    }
+
+    /// 字段对应的 DOM id
+    pub fn field_id(&self, name: &str) -> String {
+        format!("ant-form-{}-{}", self.id, name)
+    }
+
+    /// 获取字段值，字段不存在时按路径从上级字段的值中读取
+    pub fn get_field_value(&self, name: &str) -> Option<Value> {
+        let fields = self.fields.read();
+        match fields.get(name) {
+            Some(field) => Some(field.value.clone()),
+            None => get_value_by_path(&collect_values(&fields), name).cloned(),
+        }
+    }
+
+    /// 获取嵌套结构的表单值
+    pub fn get_fields_value(&self) -> Value {
+        collect_values(&self.fields.read())
+    }
+
+    /// 获取表单值并反序列化为指定类型
+    pub fn get_fields_value_as<T: DeserializeOwned>(&self) -> Result<T, serde_json::Error> {
+        serde_json::from_value(self.get_fields_value())
+    }
+
+    /// 获取表单值但不订阅变化
+    pub(crate) fn peek_fields_value(&self) -> Value {
+        collect_values(&self.fields.peek())
+    }
+
+    /// 获取字段的错误信息
+    pub fn get_field_error(&self, name: &str) -> Option<String> {
+        self.fields
+            .read()
+            .get(name)
+            .and_then(|field| field.error.clone())
+    }
+
+    /// 设置字段值，不触发校验
+    pub fn set_field_value(&self, name: &str, value: impl Into<Value>) {
+        let mut fields = self.fields;
+        fields.with_mut(|fields| {
+            fields
+                .entry(name.to_string())
+                .or_insert_with(|| FormField::new(name))
+                .value = value.into();
+            sync_child_values(fields, name);
+        });
+    }
+
+    /// 按嵌套结构批量设置字段值，不触发校验
+    pub fn set_fields_value(&self, values: Value) {
+        let mut fields = self.fields;
+        fields.with_mut(|fields| merge_values(fields, &values, false));
+    }
+
+    /// 设置字段的值、错误信息与操作状态
+    pub fn set_fields(&self, data: Vec<FieldData>) {
+        let mut fields = self.fields;
+        fields.with_mut(|fields| {
+            for data in data {
+                let field = fields
+                    .entry(data.name.clone())
+                    .or_insert_with(|| FormField::new(&data.name));
+                let changed = data.value.is_some();
+                if let Some(value) = data.value {
+                    field.value = value;
+                }
+                if let Some(errors) = data.errors {
+                    field.error = errors.into_iter().next();
+                    field.status = field.error.as_ref().map(|_| ValidateStatus::Error);
+                }
+                if let Some(touched) = data.touched {
+                    field.touched = touched;
+                }
+                if changed {
+                    sync_child_values(fields, &data.name);
+                }
+            }
+        });
+    }
+
+    /// 将字段恢复为初始值并清除校验状态，`names` 为 None 时重置所有字段
+    pub fn reset_fields(&self, names: Option<Vec<String>>) {
+        let initial_values = self.initial_values.read();
+        let mut fields = self.fields;
+        fields.with_mut(|fields| {
+            for (name, field) in fields.iter_mut() {
+                if names.as_ref().is_some_and(|names| !names.contains(name)) {
+                    continue;
+                }
+                self.begin_async_validation(name);
+                *field = FormField {
+                    value: get_value_by_path(&initial_values, name)
+                        .cloned()
+                        .unwrap_or(Value::Null),
+                    label: field.label.take(),
+                    rules: std::mem::take(&mut field.rules),
+                    dependencies: std::mem::take(&mut field.dependencies),
+                    validate_trigger: field.validate_trigger.take(),
+                    ..FormField::new(name)
+                };
+            }
+        });
+    }
+
+    /// 同步校验字段，`names` 为 None 时校验所有字段，不执行异步校验规则
+    ///
+    /// 校验通过时返回嵌套结构的表单值，否则返回各字段的错误信息。
+    pub fn validate_fields(
+        &self,
+        names: Option<Vec<String>>,
+    ) -> Result<Value, HashMap<String, String>> {
+        let mut errors = HashMap::new();
+        let locale = self.locale.read();
+        let mut fields = self.fields;
+        fields.with_mut(|fields| {
+            sync_parent_values(fields);
+            let values = collect_values(fields);
+            for (name, field) in fields.iter_mut() {
+                if names.as_ref().is_some_and(|names| !names.contains(name)) {
+                    continue;
+                }
+                if !field.validate_with_values(&locale, &values) {
+                    errors.insert(name.clone(), field.error.clone().unwrap_or_default());
+                }
+            }
+        });
+        if errors.is_empty() {
+            Ok(self.peek_fields_value())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// 校验字段并等待异步校验规则完成，`names` 为 None 时校验所有字段
+    ///
+    /// 异步校验立即执行，不等待防抖时间；进行中的异步校验会被取消。
+    pub async fn validate_fields_async(
+        &self,
+        names: Option<Vec<String>>,
+    ) -> Result<Value, HashMap<String, String>> {
+        let mut errors = self
+            .validate_fields(names.clone())
+            .err()
+            .unwrap_or_default();
+        for name in self.async_field_names(names.as_ref()) {
+            if errors.contains_key(&name) {
+                continue;
+            }
+            let id = self.begin_async_validation(&name);
+            if self.run_async_rules(&name, id, false).await == Some(false) {
+                errors.insert(name.clone(), self.peek_field_error(&name));
+            }
+        }
+        if errors.is_empty() {
+            Ok(self.peek_fields_value())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// 字段是否正在进行异步校验
+    pub fn is_field_validating(&self, name: &str) -> bool {
+        self.fields
+            .read()
+            .get(name)
+            .is_some_and(|field| field.status == Some(ValidateStatus::Validating))
+    }
+
+    /// 字段是否被用户操作过
+    pub fn is_field_touched(&self, name: &str) -> bool {
+        self.fields
+            .read()
+            .get(name)
+            .is_some_and(|field| field.touched)
+    }
+
+    /// 字段组是否被用户操作过，`names` 为 None 时检查所有字段；`all_touched` 为 true 时要求全部被操作过
+    pub fn is_fields_touched(&self, names: Option<Vec<String>>, all_touched: bool) -> bool {
+        let fields = self.fields.read();
+        let mut touched = fields
+            .iter()
+            .filter(|(name, _)| names.as_ref().is_none_or(|names| names.contains(name)))
+            .map(|(_, field)| field.touched);
+        if all_touched {
+            touched.all(|touched| touched)
+        } else {
+            touched.any(|touched| touched)
+        }
+    }
+
+    /// 字段值是否被用户修改为与初始值不同的值
+    pub fn is_field_dirty(&self, name: &str) -> bool {
+        self.fields
+            .read()
+            .get(name)
+            .is_some_and(|field| field.dirty)
+    }
+
+    /// 表单是否有未提交的修改，即存在被用户修改过或由草稿恢复且与初始值不同的字段
+    pub fn is_dirty(&self) -> bool {
+        self.fields.read().values().any(|field| field.dirty)
+    }
+
+    /// 提交成功后将所有字段标记为未修改，草稿不再被保存
+    pub(crate) fn mark_clean(&self) {
+        let dirty = self.fields.peek().values().any(|field| field.dirty);
+        if dirty {
+            let mut fields = self.fields;
+            fields.with_mut(|fields| fields.values_mut().for_each(|field| field.dirty = false));
+        }
+    }
+
+    /// 提交表单，与点击提交按钮的效果相同
+    pub fn submit(&self) {
+        let submit = self.submit_fn.read().clone();
+        if let Some(submit) = submit {
+            submit();
+        }
+    }
+
+    /// 滚动到字段所在的位置
+    pub fn scroll_to_field(&self, name: &str) {
+        let id = serde_json::Value::String(self.field_id(name));
+        document::eval(&format!(
+            "var field = document.getElementById({id});\
+             if (field) {{ field.scrollIntoView({{ behavior: 'smooth', block: 'center' }}); }}"
+        ));
+    }
+
+    /// 设置初始值，已注册但未被修改的字段同步更新
+    pub(crate) fn set_initial_values(&self, values: Value) {
+        let mut fields = self.fields;
+        fields.with_mut(|fields| merge_values(fields, &values, true));
+        let mut initial_values = self.initial_values;
+        initial_values.set(values);
+    }
+
+    /// 恢复保存的表单值，与初始值不同的字段标记为修改过
+    pub(crate) fn restore_values(&self, values: Value) {
+        let initial_values = self.initial_values.peek();
+        let mut fields = self.fields;
+        fields.with_mut(|fields| {
+            merge_values(fields, &values, false);
+            for (name, field) in fields.iter_mut() {
+                field.dirty =
+                    is_value_changed(get_value_by_path(&initial_values, name), &field.value);
+            }
+        });
+    }
+
+    /// 设置校验提示使用的语言包
+    pub(crate) fn set_locale(&self, locale: LocaleConfig) {
+        let mut current = self.locale;
+        current.set(locale);
+    }
+
+    /// 设置表单默认的校验时机
+    pub(crate) fn set_validate_trigger(&self, triggers: Vec<ValidateTrigger>) {
+        let mut validate_trigger = self.validate_trigger;
+        if *validate_trigger.peek() != triggers {
+            validate_trigger.set(triggers);
+        }
+    }
+
+    /// 注册 `Form` 的提交函数
+    pub(crate) fn set_submit(&self, submit: SubmitFn) {
+        let mut submit_fn = self.submit_fn;
+        submit_fn.set(Some(submit));
+    }
+
+    /// 设置单个字段的初始值，`initial_values` 中已有该字段时不修改
+    pub(crate) fn set_field_initial_value(&self, name: &str, value: Value) {
+        let mut initial_values = self.initial_values;
+        let exists = initial_values.with_mut(|initial_values| {
+            let exists = get_value_by_path(initial_values, name).is_some();
+            if !exists {
+                set_value_by_path(initial_values, name, value.clone());
+            }
+            exists
+        });
+        if !exists
+            && self
+                .get_field_value(name)
+                .is_none_or(|value| value.is_null())
+        {
+            self.set_field_value(name, value);
+        }
+    }
+
+    /// 注册字段的校验规则，未设置值的字段按路径从当前表单值中读取，其次使用初始值
+    pub(crate) fn register_field(&self, name: &str, label: Option<String>, rules: Vec<FormRule>) {
+        let initial_value = get_value_by_path(&self.initial_values.read(), name).cloned();
+        let mut fields = self.fields;
+        fields.with_mut(|fields| {
+            let current_value = get_value_by_path(&collect_values(fields), name).cloned();
+            let field = fields.entry(name.to_string()).or_insert_with(|| {
+                let mut field = FormField::new(name);
+                field.value = current_value.or(initial_value).unwrap_or(Value::Null);
+                field
+            });
+            field.label = label;
+            field.rules = rules;
+        });
+    }
+
+    /// 设置字段依赖的字段路径
+    pub(crate) fn set_field_dependencies(&self, name: &str, dependencies: Vec<String>) {
+        let mut fields = self.fields;
+        fields.with_mut(|fields| {
+            fields
+                .entry(name.to_string())
+                .or_insert_with(|| FormField::new(name))
+                .dependencies = dependencies;
+        });
+    }
+
+    /// 设置字段的校验时机，为 None 时使用表单的校验时机
+    pub(crate) fn set_field_validate_trigger(
+        &self,
+        name: &str,
+        triggers: Option<Vec<ValidateTrigger>>,
+    ) {
+        let mut fields = self.fields;
+        fields.with_mut(|fields| {
+            fields
+                .entry(name.to_string())
+                .or_insert_with(|| FormField::new(name))
+                .validate_trigger = triggers;
+        });
+    }
+
+    /// 字段是否在 `trigger` 时校验
+    fn validates_on(&self, field: &FormField, trigger: ValidateTrigger) -> bool {
+        match &field.validate_trigger {
+            Some(triggers) => triggers.contains(&trigger),
+            None => self.validate_trigger.peek().contains(&trigger),
+        }
+    }
+
+    /// 控件触发 `trigger` 对应的事件，字段的校验时机包含该事件时校验字段
+    pub(crate) fn trigger_validate(&self, name: &str, trigger: ValidateTrigger) {
+        let validates = self
+            .fields
+            .peek()
+            .get(name)
+            .is_some_and(|field| self.validates_on(field, trigger));
+        if validates {
+            self.validate_field(name);
+        }
+    }
+
+    /// 用户修改字段值：标记为已操作与是否修改过，字段在值变化时校验则重新校验，
+    /// 依赖该字段且已操作或已校验过的字段一并重新校验
+    pub(crate) fn change_field(&self, name: &str, value: Value) {
+        let initial_value = get_value_by_path(&self.initial_values.peek(), name).cloned();
+        let mut fields = self.fields;
+        let (validates, dependents): (bool, Vec<String>) = fields.with_mut(|fields| {
+            let field = fields
+                .entry(name.to_string())
+                .or_insert_with(|| FormField::new(name));
+            field.dirty = is_value_changed(initial_value.as_ref(), &value);
+            field.value = value;
+            field.touched = true;
+            let validates = self.validates_on(field, ValidateTrigger::OnChange);
+            sync_child_values(fields, name);
+
+            let dependents = fields
+                .iter()
+                .filter(|(dependent, field)| {
+                    *dependent != name
+                        && (field.touched || field.validated)
+                        && self.validates_on(field, ValidateTrigger::OnChange)
+                        && field.dependencies.iter().any(|dependency| {
+                            is_path_prefix(dependency, name) || is_path_prefix(name, dependency)
+                        })
+                })
+                .map(|(dependent, _)| dependent.clone())
+                .collect();
+            (validates, dependents)
+        });
+
+        if validates {
+            self.validate_field(name);
+        }
+        for dependent in dependents {
+            self.validate_field(&dependent);
+        }
+    }
+
+    /// 同步校验单个字段，之后按防抖时间执行异步校验
+    fn validate_field(&self, name: &str) {
+        let locale = self.locale.read();
+        let mut fields = self.fields;
+        fields.with_mut(|fields| {
+            let values = collect_values(fields);
+            if let Some(field) = fields.get_mut(name) {
+                field.validate_with_values(&locale, &values);
+            }
+        });
+        drop(locale);
+
+        // 同步校验之后按防抖时间执行异步校验
+        if !self
+            .async_field_names(Some(&vec![name.to_string()]))
+            .is_empty()
+        {
+            let id = self.begin_async_validation(name);
+            if self.peek_field_error(name).is_empty() {
+                self.set_field_status(name, ValidateStatus::Validating, None);
+            }
+            let form = *self;
+            let field = name.to_string();
+            let task = self.scope.push_future(async move {
+                form.run_async_rules(&field, id, true).await;
+            });
+            let mut pending = self.pending;
+            pending.with_mut(|pending| {
+                if let Some(pending) = pending.get_mut(name).filter(|pending| pending.id == id) {
+                    pending.task = task;
+                }
+            });
+        }
+    }
+
+    /// 增加、删除或移动列表项：更新列表字段的值，并按新的下标重命名各列表项的字段
+    ///
+    /// 被删除的项的字段一并删除，受影响字段进行中的异步校验被取消；列表字段已校验过时重新校验。
+    pub(crate) fn change_list(&self, name: &str, change: ListChange, value: Value) {
+        let locale = self.locale.read();
+        let mut renamed = Vec::new();
+        let mut fields = self.fields;
+        fields.with_mut(|fields| {
+            let mut items = match get_value_by_path(&collect_values(fields), name) {
+                Some(Value::Array(items)) => items.clone(),
+                _ => Vec::new(),
+            };
+            let index_map = change.index_map(items.len());
+            change.apply(&mut items, || value);
+
+            let item_names: Vec<String> = fields
+                .keys()
+                .filter(|field| list_item_index(name, field).is_some())
+                .cloned()
+                .collect();
+            let mut moved = Vec::new();
+            for old_name in item_names {
+                let Some(mut field) = fields.remove(&old_name) else {
+                    continue;
+                };
+                if let Some((index, rest)) = list_item_index(name, &old_name) {
+                    if let Some(index) = index_map.get(index).copied().flatten() {
+                        if field.status == Some(ValidateStatus::Validating) {
+                            field.status = None;
+                        }
+                        field.name = format!("{name}[{index}]{rest}");
+                        moved.push(field);
+                    }
+                }
+                renamed.push(old_name);
+            }
+            for field in moved {
+                fields.insert(field.name.clone(), field);
+            }
+
+            let list = fields
+                .entry(name.to_string())
+                .or_insert_with(|| FormField::new(name));
+            list.value = Value::Array(items);
+            if list.validated {
+                let values = collect_values(fields);
+                if let Some(list) = fields.get_mut(name) {
+                    list.validate_with_values(&locale, &values);
+                }
+            }
+        });
+        drop(locale);
+
+        for name in renamed {
+            self.begin_async_validation(&name);
+        }
+    }
+
+    /// 是否存在需要执行异步校验的字段
+    pub(crate) fn has_async_rules(&self) -> bool {
+        !self.async_field_names(None).is_empty()
+    }
+
+    /// 在创建表单实例的组件中执行异步任务
+    pub(crate) fn spawn(&self, future: impl std::future::Future<Output = ()> + 'static) {
+        self.scope.push_future(future);
+    }
+
+    /// 包含异步校验规则的字段
+    fn async_field_names(&self, names: Option<&Vec<String>>) -> Vec<String> {
+        self.fields
+            .peek()
+            .iter()
+            .filter(|(name, _)| names.is_none_or(|names| names.contains(name)))
+            .filter(|(_, field)| {
+                field
+                    .rules
+                    .iter()
+                    .any(|rule| rule.async_validator.is_some())
+            })
+            .map(|(name, _)| name.clone())
+            .collect()
+    }
+
+    fn peek_field_error(&self, name: &str) -> String {
+        self.fields
+            .peek()
+            .get(name)
+            .and_then(|field| field.error.clone())
+            .unwrap_or_default()
+    }
+
+    /// 开始新一轮异步校验：取消进行中的校验并返回本轮的序号
+    fn begin_async_validation(&self, name: &str) -> u64 {
+        let mut pending = self.pending;
+        let mut pending = pending.write();
+        let pending = pending.entry(name.to_string()).or_default();
+        if let Some(task) = pending.task.take() {
+            task.cancel();
+        }
+        pending.id += 1;
+        pending.id
+    }
+
+    fn is_current_validation(&self, name: &str, id: u64) -> bool {
+        self.pending
+            .read()
+            .get(name)
+            .is_some_and(|pending| pending.id == id)
+    }
+
+    fn set_field_status(&self, name: &str, status: ValidateStatus, error: Option<String>) {
+        let mut fields = self.fields;
+        fields.with_mut(|fields| {
+            if let Some(field) = fields.get_mut(name) {
+                field.status = Some(status);
+                field.error = error;
+            }
+        });
+    }
+
+    /// 依次执行字段的异步校验规则，返回是否通过；同步校验未通过时不执行，
+    /// 被新一轮校验取代时返回 None 且不修改字段状态
+    async fn run_async_rules(&self, name: &str, id: u64, debounce: bool) -> Option<bool> {
+        let (value, label, rules) = {
+            let fields = self.fields.peek();
+            let field = fields.get(name)?;
+            if field.error.is_some() {
+                return Some(false);
+            }
+            let rules: Vec<FormRule> = field
+                .rules
+                .iter()
+                .filter(|rule| rule.async_validator.is_some())
+                .cloned()
+                .collect();
+            let label = field.label.clone().unwrap_or_else(|| name.to_string());
+            (field.value.clone(), label, rules)
+        };
+
+        self.set_field_status(name, ValidateStatus::Validating, None);
+        for rule in rules {
+            if debounce && rule.validate_debounce > 0 {
+                gloo_timers::future::TimeoutFuture::new(rule.validate_debounce).await;
+                if !self.is_current_validation(name, id) {
+                    return None;
+                }
+            }
+            let Some(validator) = rule.async_validator else {
+                continue;
+            };
+            let result = validator(value.clone()).await;
+            if !self.is_current_validation(name, id) {
+                return None;
+            }
+            if let Err(message) = result {
+                let message = format_message(&message, &[("label", label)]);
+                self.set_field_status(name, ValidateStatus::Error, Some(message));
+                return Some(false);
+            }
+        }
+        self.set_field_status(name, ValidateStatus::Success, None);
+        Some(true)
+    }
+}
+
+impl Default for FormInstance {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// 创建表单实例
+pub fn use_form() -> FormInstance {
+    use_hook(FormInstance::new)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::form::tests::run_in_component;
+    use serde::Deserialize;
+    use serde_json::json;
+
+    #[test]
+    fn test_form_instance_values() {
+        run_in_component(|| {
+            let form = use_form();
+            form.set_initial_values(json!({ "username": "admin" }));
+            form.register_field("username", None, vec![FormRule::required("必填")]);
+            form.register_field("password", None, Vec::new());
+            assert_eq!(form.get_field_value("username"), Some(json!("admin")));
+            assert_eq!(form.get_field_value("missing"), None);
+
+            form.set_fields_value(json!({ "password": "secret", "remember": true }));
+            form.change_field("username", json!("root"));
+            assert!(form.is_field_touched("username"));
+            assert!(!form.is_field_touched("password"));
+            assert_eq!(
+                form.get_fields_value(),
+                json!({ "username": "root", "password": "secret", "remember": true })
+            );
+
+            form.reset_fields(Some(vec!["username".to_string()]));
+            assert_eq!(form.get_field_value("username"), Some(json!("admin")));
+            assert_eq!(form.get_field_value("password"), Some(json!("secret")));
+            assert!(!form.is_field_touched("username"));
+
+            form.reset_fields(None);
+            assert_eq!(form.get_field_value("password"), Some(Value::Null));
+        });
+    }
+
+    #[test]
+    fn test_form_instance_validate() {
+        run_in_component(|| {
+            let form = use_form();
+            form.register_field("username", None, vec![FormRule::required("请输入用户名")]);
+            form.register_field("nickname", None, Vec::new());
+
+            let errors = form.validate_fields(None).unwrap_err();
+            assert_eq!(errors.get("username"), Some(&"请输入用户名".to_string()));
+            assert!(form
+                .validate_fields(Some(vec!["nickname".to_string()]))
+                .is_ok());
+
+            form.set_field_value("username", "admin");
+            let values = form.validate_fields(None).unwrap();
+            assert_eq!(values, json!({ "username": "admin", "nickname": null }));
+
+            form.set_fields(vec![FieldData::new("username")
+                .errors(vec!["用户名已存在".to_string()])
+                .touched(true)]);
+            assert_eq!(
+                form.get_field_error("username"),
+                Some("用户名已存在".to_string())
+            );
+            assert!(form.is_field_touched("username"));
+            form.set_fields(vec![FieldData::new("username").errors(Vec::new())]);
+            assert_eq!(form.get_field_error("username"), None);
+        });
+    }
+
+    #[test]
+    fn test_form_instance_submit() {
+        run_in_component(|| {
+            let form = use_form();
+            let submitted = CopyValue::new(0);
+            form.submit();
+            form.set_submit(Rc::new(move || {
+                let mut submitted = submitted;
+                submitted += 1;
+            }));
+            form.submit();
+            assert_eq!(*submitted.read(), 1);
+            assert_ne!(form.field_id("a"), FormInstance::new().field_id("a"));
+        });
+    }
+
+    #[test]
+    fn test_form_instance_parent_values() {
+        run_in_component(|| {
+            let form = use_form();
+            form.register_field("user.name", None, Vec::new());
+            form.register_field("user.address.city", None, Vec::new());
+            form.set_field_value("user.name", "admin");
+            form.set_field_value("user.address.city", "Hangzhou");
+
+            // 写入上级字段时同步下级字段，不再保留旧值
+            form.set_field_value("user", json!({ "name": "root" }));
+            assert_eq!(form.get_field_value("user.name"), Some(json!("root")));
+            assert_eq!(form.get_field_value("user.address.city"), Some(Value::Null));
+            assert_eq!(
+                form.get_fields_value(),
+                json!({ "user": { "name": "root" } })
+            );
+
+            form.set_fields_value(json!({ "user": { "address": { "city": "Ningbo" } } }));
+            assert_eq!(form.get_field_value("user.name"), Some(Value::Null));
+            assert_eq!(
+                form.get_fields_value(),
+                json!({ "user": { "address": { "city": "Ningbo" } } })
+            );
+
+            form.set_fields(vec![
+                FieldData::new("user").value(json!({ "name": "guest" }))
+            ]);
+            assert_eq!(
+                form.get_fields_value(),
+                json!({ "user": { "name": "guest" } })
+            );
+
+            // 之后修改下级字段仍会更新上级字段
+            form.set_field_value("user.address.city", "Suzhou");
+            assert_eq!(
+                form.get_fields_value(),
+                json!({ "user": { "name": "guest", "address": { "city": "Suzhou" } } })
+            );
+        });
+    }
+
+    #[test]
+    fn test_form_instance_nested_values() {
+        #[derive(Debug, PartialEq, Deserialize)]
+        struct Item {
+            qty: u32,
+        }
+
+        #[derive(Debug, PartialEq, Deserialize)]
+        struct Order {
+            user: HashMap<String, String>,
+            items: Vec<Option<Item>>,
+            tags: Vec<String>,
+        }
+
+        run_in_component(|| {
+            let form = use_form();
+            form.set_initial_values(json!({ "items": [null, null, { "qty": 1 }] }));
+            form.register_field("user.address.city", None, Vec::new());
+            form.register_field("items[2].qty", None, Vec::new());
+            assert_eq!(form.get_field_value("items[2].qty"), Some(json!(1)));
+
+            form.set_field_value("user.address.city", "Hangzhou");
+            form.set_field_value("tags", vec!["a", "b"]);
+            form.set_fields_value(json!({ "items": [null, null, { "qty": 3 }] }));
+            assert_eq!(
+                form.get_fields_value(),
+                json!({
+                    "user": { "address": { "city": "Hangzhou" } },
+                    "items": [null, null, { "qty": 3 }],
+                    "tags": ["a", "b"],
+                })
+            );
+            assert_eq!(
+                form.get_field_value("user.address"),
+                Some(json!({ "city": "Hangzhou" }))
+            );
+
+            form.set_field_value("user", json!({ "name": "admin" }));
+            let order: Order = form.get_fields_value_as().unwrap();
+            assert_eq!(order.user.get("name"), Some(&"admin".to_string()));
+            assert_eq!(order.items[2], Some(Item { qty: 3 }));
+            assert_eq!(order.tags, vec!["a", "b"]);
+        });
+    }
+
+    /// 第一次轮询时返回 Pending 的 Future
+    struct YieldOnce(bool);
+
+    impl std::future::Future for YieldOnce {
+        type Output = ();
+
+        fn poll(
+            mut self: std::pin::Pin<&mut Self>,
+            _: &mut std::task::Context<'_>,
+        ) -> std::task::Poll<()> {
+            if self.0 {
+                std::task::Poll::Ready(())
+            } else {
+                self.0 = true;
+                std::task::Poll::Pending
+            }
+        }
+    }
+
+    fn username_rules() -> Vec<FormRule> {
+        vec![
+            FormRule::default().with_required(),
+            FormRule::async_validator(|value: Value| async move {
+                YieldOnce(false).await;
+                if value == json!("admin") {
+                    Err("${label}已被占用".to_string())
+                } else {
+                    Ok(())
+                }
+            })
+            .validate_debounce(300),
+        ]
+    }
+
+    fn poll_once<F: std::future::Future>(
+        future: &mut std::pin::Pin<Box<F>>,
+    ) -> std::task::Poll<F::Output> {
+        let mut context = std::task::Context::from_waker(std::task::Waker::noop());
+        future.as_mut().poll(&mut context)
+    }
+
+    #[test]
+    fn test_form_async_validator() {
+        run_in_component(|| {
+            let form = use_form();
+            form.register_field("username", Some("用户名".to_string()), username_rules());
+            assert!(form.has_async_rules());
+
+            // 同步校验未通过时不执行异步校验
+            let mut future = Box::pin(form.validate_fields_async(None));
+            let errors = match poll_once(&mut future) {
+                std::task::Poll::Ready(result) => result.unwrap_err(),
+                std::task::Poll::Pending => panic!("required check is synchronous"),
+            };
+            assert_eq!(errors.get("username"), Some(&"请输入用户名".to_string()));
+
+            form.set_field_value("username", "admin");
+            let mut future = Box::pin(form.validate_fields_async(None));
+            assert!(poll_once(&mut future).is_pending());
+            assert!(form.is_field_validating("username"));
+            match poll_once(&mut future) {
+                std::task::Poll::Ready(result) => assert_eq!(
+                    result.unwrap_err().get("username"),
+                    Some(&"用户名已被占用".to_string())
+                ),
+                std::task::Poll::Pending => panic!("validator should resolve"),
+            }
+            assert_eq!(
+                form.get_field_error("username"),
+                Some("用户名已被占用".to_string())
+            );
+
+            form.set_field_value("username", "guest");
+            let mut future = Box::pin(form.validate_fields_async(None));
+            assert!(poll_once(&mut future).is_pending());
+            assert!(matches!(
+                poll_once(&mut future),
+                std::task::Poll::Ready(Ok(_))
+            ));
+            assert!(!form.is_field_validating("username"));
+        });
+    }
+
+    #[test]
+    fn test_form_async_validator_superseded() {
+        run_in_component(|| {
+            let form = use_form();
+            form.register_field("username", None, username_rules());
+            form.set_field_value("username", "admin");
+
+            let mut future = Box::pin(form.validate_fields_async(None));
+            assert!(poll_once(&mut future).is_pending());
+
+            // 用户再次修改后，上一轮的结果被丢弃
+            form.change_field("username", json!("guest"));
+            assert!(form.is_field_validating("username"));
+            assert!(poll_once(&mut future).is_ready());
+            assert!(form.is_field_validating("username"));
+            assert_eq!(form.get_field_error("username"), None);
+
+            // 同步校验失败时取消异步校验
+            form.change_field("username", json!(""));
+            assert!(!form.is_field_validating("username"));
+            assert_eq!(
+                form.get_field_error("username"),
+                Some("请输入username".to_string())
+            );
+
+            form.reset_fields(None);
+            assert_eq!(form.get_field_error("username"), None);
+        });
+    }
+
+    #[test]
+    fn test_form_list_instance() {
+        run_in_component(|| {
+            let form = use_form();
+            form.set_initial_values(json!({
+                "phones": [{ "number": "1" }, { "number": "2" }, { "number": "3" }]
+            }));
+            form.register_field("phones", None, vec![FormRule::required("至少添加一项")]);
+            for index in 0..3 {
+                let name = format!("phones[{index}].number");
+                form.register_field(&name, None, vec![FormRule::required("请输入号码")]);
+            }
+            assert_eq!(form.get_field_value("phones[1].number"), Some(json!("2")));
+
+            form.change_field("phones[2].number", json!("33"));
+            form.change_list("phones", ListChange::Remove(0), Value::Null);
+            assert_eq!(
+                form.get_fields_value(),
+                json!({ "phones": [{ "number": "2" }, { "number": "33" }] })
+            );
+            assert!(form.is_field_touched("phones[1].number"));
+            assert!(!form.is_field_touched("phones[0].number"));
+
+            form.change_list("phones", ListChange::Move(1, 0), Value::Null);
+            form.change_list("phones", ListChange::Add(None), json!({ "number": "" }));
+            assert_eq!(form.get_field_value("phones[0].number"), Some(json!("33")));
+            // 新增项的字段注册时使用新增的值，而不是初始值
+            form.register_field(
+                "phones[2].number",
+                None,
+                vec![FormRule::required("请输入号码")],
+            );
+            assert_eq!(form.get_field_value("phones[2].number"), Some(json!("")));
+
+            let errors = form.validate_fields(None).unwrap_err();
+            assert_eq!(errors.len(), 1);
+            assert!(errors.contains_key("phones[2].number"));
+
+            // 列表级规则：删除所有项后重新校验
+            for _ in 0..3 {
+                form.change_list("phones", ListChange::Remove(0), Value::Null);
+            }
+            assert_eq!(
+                form.get_field_error("phones"),
+                Some("至少添加一项".to_string())
+            );
+            assert_eq!(form.get_fields_value(), json!({ "phones": [] }));
+        });
+    }
+
+    /// 确认密码须与密码一致
+    fn confirm_rules() -> Vec<FormRule> {
+        vec![
+            FormRule::required("请确认密码"),
+            FormRule::cross_field(|value, values| {
+                if values.get("password") == Some(value) {
+                    Ok(())
+                } else {
+                    Err("${label}与密码不一致".to_string())
+                }
+            }),
+        ]
+    }
+
+    #[test]
+    fn test_form_dependencies() {
+        run_in_component(|| {
+            let form = use_form();
+            form.register_field("password", None, vec![FormRule::required("请输入密码")]);
+            form.register_field("confirm", Some("确认密码".to_string()), confirm_rules());
+            form.set_field_dependencies("confirm", vec!["password".to_string()]);
+
+            // 未操作过的依赖字段不校验
+            form.change_field("password", json!("secret"));
+            assert_eq!(form.get_field_error("confirm"), None);
+
+            form.change_field("confirm", json!("secret"));
+            assert_eq!(form.get_field_error("confirm"), None);
+
+            // 依赖的字段变化后重新校验
+            form.change_field("password", json!("changed"));
+            assert_eq!(
+                form.get_field_error("confirm"),
+                Some("确认密码与密码不一致".to_string())
+            );
+            form.change_field("password", json!("secret"));
+            assert_eq!(form.get_field_error("confirm"), None);
+
+            form.set_field_value("password", "other");
+            let errors = form.validate_fields(None).unwrap_err();
+            assert!(errors.contains_key("confirm"));
+
+            // 重置后保留标签与依赖
+            form.reset_fields(None);
+            form.change_field("confirm", json!("x"));
+            form.change_field("password", json!("x"));
+            assert_eq!(form.get_field_error("confirm"), None);
+            form.change_field("password", json!("y"));
+            assert_eq!(
+                form.get_field_error("confirm"),
+                Some("确认密码与密码不一致".to_string())
+            );
+        });
+    }
+
+    #[test]
+    fn test_form_validate_trigger() {
+        run_in_component(|| {
+            let form = use_form();
+            form.set_initial_values(json!({ "email": "a@example.com" }));
+            form.set_validate_trigger(vec![ValidateTrigger::OnBlur]);
+            form.register_field("email", None, vec![FormRule::required("请输入邮箱")]);
+            form.register_field("name", None, vec![FormRule::required("请输入姓名")]);
+            form.set_field_validate_trigger("name", Some(vec![ValidateTrigger::OnChange]));
+
+            // 失焦时校验的字段在值变化时不校验
+            form.change_field("email", json!(""));
+            assert_eq!(form.get_field_error("email"), None);
+            form.trigger_validate("email", ValidateTrigger::OnBlur);
+            assert_eq!(
+                form.get_field_error("email"),
+                Some("请输入邮箱".to_string())
+            );
+
+            // 字段的校验时机覆盖表单的校验时机
+            form.change_field("name", json!(""));
+            assert_eq!(form.get_field_error("name"), Some("请输入姓名".to_string()));
+            form.set_field_validate_trigger("name", Some(Vec::new()));
+            form.change_field("name", json!("Tom"));
+            assert_eq!(form.get_field_error("name"), Some("请输入姓名".to_string()));
+            assert!(form.validate_fields(Some(vec!["name".to_string()])).is_ok());
+        });
+    }
+
+    #[test]
+    fn test_form_touched_dirty() {
+        run_in_component(|| {
+            let form = use_form();
+            form.set_initial_values(json!({ "username": "admin" }));
+            form.register_field("username", None, Vec::new());
+            form.register_field("nickname", None, Vec::new());
+            assert!(!form.is_fields_touched(None, false));
+
+            form.change_field("username", json!("root"));
+            assert!(form.is_field_dirty("username"));
+            assert!(form.is_fields_touched(None, false));
+            assert!(!form.is_fields_touched(None, true));
+
+            // 改回初始值后不再视为修改过，但仍被操作过
+            form.change_field("username", json!("admin"));
+            assert!(!form.is_field_dirty("username"));
+            assert!(form.is_field_touched("username"));
+            form.change_field("nickname", json!(""));
+            assert!(!form.is_field_dirty("nickname"));
+            assert!(form.is_fields_touched(None, true));
+
+            form.reset_fields(None);
+            assert!(!form.is_fields_touched(None, false));
+        });
+
+        let errors = HashMap::from([
+            ("phone".to_string(), "请输入手机号".to_string()),
+            ("email".to_string(), "请输入邮箱".to_string()),
+        ]);
+        assert_eq!(
+            ErrorField::from_errors(errors),
+            vec![
+                ErrorField {
+                    name: "email".to_string(),
+                    errors: vec!["请输入邮箱".to_string()],
+                },
+                ErrorField {
+                    name: "phone".to_string(),
+                    errors: vec!["请输入手机号".to_string()],
+                },
+            ]
+        );
+    }
+}
@@ -0,0 +1,238 @@
+//! Form.List 动态增减的字段组
+//!
+//! `FormList` 管理一个数组类型的字段，通过渲染函数提供各项的字段路径以及增加、
+//! 删除、移动等操作，列表项内的 `FormItem` 以 `phones[0].number` 形式的路径注册。
+
+use super::{FormContext, FormInstance, FormRule};
+use dioxus::prelude::*;
+use serde_json::Value;
+
+/// 列表项信息
+#[derive(Debug, Clone, PartialEq)]
+pub struct FormListField {
+    /// 稳定的唯一标识，增删与移动后保持不变，用作渲染的 key
+    pub key: usize,
+    /// 当前下标
+    pub index: usize,
+    /// 列表项的字段路径，如 `phones[0]`
+    pub name: String,
+}
+
+impl FormListField {
+    /// 列表项下级字段的路径，如 `phones[0].number`
+    pub fn path(&self, key: &str) -> String {
+        format!("{}.{}", self.name, key)
+    }
+}
+
+/// 列表的变更
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum ListChange {
+    /// 在指定位置插入，为 None 时追加到末尾
+    Add(Option<usize>),
+    /// 删除指定位置
+    Remove(usize),
+    /// 从 `from` 移动到 `to`
+    Move(usize, usize),
+}
+
+impl ListChange {
+    /// 对列表应用变更，`make` 用于创建新增的项；下标越界时不做修改
+    pub(crate) fn apply<T>(&self, items: &mut Vec<T>, make: impl FnOnce() -> T) {
+        let len = items.len();
+        match *self {
+            ListChange::Add(index) => items.insert(index.unwrap_or(len).min(len), make()),
+            ListChange::Remove(index) if index < len => {
+                items.remove(index);
+            }
+            ListChange::Move(from, to) if from < len && to < len => {
+                let item = items.remove(from);
+                items.insert(to, item);
+            }
+            _ => {}
+        }
+    }
+
+    /// 变更前各下标在变更后的位置，被删除的项为 None
+    pub(crate) fn index_map(&self, len: usize) -> Vec<Option<usize>> {
+        let mut items: Vec<Option<usize>> = (0..len).map(Some).collect();
+        self.apply(&mut items, || None);
+
+        let mut index_map = vec![None; len];
+        for (index, old) in items.into_iter().enumerate() {
+            if let Some(old) = old {
+                index_map[old] = Some(index);
+            }
+        }
+        index_map
+    }
+}
+
+/// 解析列表项下级字段的路径，返回下标与下标之后的部分
+///
+/// 如列表 `phones` 下的 `phones[1].number` 返回 `(1, ".number")`。
+pub(crate) fn list_item_index<'a>(list: &str, name: &'a str) -> Option<(usize, &'a str)> {
+    let rest = name.strip_prefix(list)?.strip_prefix('[')?;
+    let (index, rest) = rest.split_once(']')?;
+    let index = index.trim().parse().ok()?;
+    (rest.is_empty() || rest.starts_with('.') || rest.starts_with('[')).then_some((index, rest))
+}
+
+/// 列表的渲染状态
+#[derive(Default)]
+struct ListState {
+    /// 列表字段路径
+    name: String,
+    /// 各项的稳定标识
+    keys: Vec<usize>,
+    /// 下一个新增项的标识
+    next_key: usize,
+}
+
+impl ListState {
+    /// 使标识的数量与列表长度一致，列表被整体设置或重置时使用
+    fn sync(&mut self, len: usize) {
+        while self.keys.len() < len {
+            self.keys.push(self.next_key);
+            self.next_key += 1;
+        }
+        self.keys.truncate(len);
+    }
+}
+
+/// 列表操作，传给 `FormList` 的渲染函数
+#[derive(Clone, Copy, PartialEq)]
+pub struct FormListOperation {
+    form: FormInstance,
+    state: CopyValue<ListState>,
+}
+
+impl FormListOperation {
+    /// 新增一项，`index` 为 None 时追加到末尾
+    pub fn add(&self, value: impl Into<Value>, index: Option<usize>) {
+        self.change(ListChange::Add(index), value.into());
+    }
+
+    /// 删除指定位置的项
+    pub fn remove(&self, index: usize) {
+        self.change(ListChange::Remove(index), Value::Null);
+    }
+
+    /// 将项从 `from` 移动到 `to`，对应 antd 的 `move`（`move` 是 Rust 关键字）
+    pub fn move_item(&self, from: usize, to: usize) {
+        self.change(ListChange::Move(from, to), Value::Null);
+    }
+
+    fn change(&self, change: ListChange, value: Value) {
+        let mut state = self.state;
+        let name = state.with_mut(|state| {
+            let key = state.next_key;
+            state.next_key += 1;
+            change.apply(&mut state.keys, || key);
+            state.name.clone()
+        });
+        self.form.change_list(&name, change, value);
+    }
+}
+
+/// 列表渲染函数的参数：各项信息、列表操作与列表级校验的错误信息
+pub type FormListRenderArgs = (Vec<FormListField>, FormListOperation, Vec<String>);
+
+/// FormList 组件属性
+#[derive(Props, Clone, PartialEq)]
+pub struct FormListProps {
+    /// 列表字段路径
+    pub name: String,
+    /// 列表的初始值，Form 的 `initial_values` 中没有该字段时使用
+    pub initial_value: Option<Vec<Value>>,
+    /// 列表级校验规则，校验的值为整个数组，如 `FormRule::required` 要求至少一项、
+    /// `FormRule::max_length` 限制最多项数
+    #[props(default = Vec::new())]
+    pub rules: Vec<FormRule>,
+    /// 渲染函数
+    pub render: Callback<FormListRenderArgs, Element>,
+}
+
+/// FormList 动态字段组组件
+#[component]
+pub fn FormList(props: FormListProps) -> Element {
+    let form_context = use_context::<FormContext>();
+    let form = form_context.form;
+    let mut state = use_hook(|| CopyValue::new(ListState::default()));
+
+    let initial_value = props.initial_value.clone();
+    let name = props.name.clone();
+    use_hook(move || {
+        if let Some(initial_value) = initial_value {
+            form.set_field_initial_value(&name, Value::Array(initial_value));
+        }
+    });
+
+    // 注册列表字段，校验规则作用于整个数组
+    let name = props.name.clone();
+    let rules = props.rules.clone();
+    use_effect(use_reactive!(|name, rules| {
+        (form_context.on_field_register)(name, None, rules);
+    }));
+
+    let len = match form.get_field_value(&props.name) {
+        Some(Value::Array(items)) => items.len(),
+        _ => 0,
+    };
+    let keys = state.with_mut(|state| {
+        state.name = props.name.clone();
+        state.sync(len);
+        state.keys.clone()
+    });
+
+    let fields = keys
+        .into_iter()
+        .enumerate()
+        .map(|(index, key)| FormListField {
+            key,
+            index,
+            name: format!("{}[{}]", props.name, index),
+        })
+        .collect();
+    let operation = FormListOperation { form, state };
+    let errors = form.get_field_error(&props.name).into_iter().collect();
+
+    props.render.call((fields, operation, errors))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_form_list_change() {
+        let mut items = vec!['a', 'b', 'c'];
+        ListChange::Move(0, 2).apply(&mut items, || 'x');
+        assert_eq!(items, vec!['b', 'c', 'a']);
+        ListChange::Add(Some(1)).apply(&mut items, || 'x');
+        assert_eq!(items, vec!['b', 'x', 'c', 'a']);
+        ListChange::Remove(9).apply(&mut items, || 'x');
+        assert_eq!(items.len(), 4);
+
+        assert_eq!(
+            ListChange::Remove(1).index_map(3),
+            vec![Some(0), None, Some(1)]
+        );
+        assert_eq!(
+            ListChange::Add(Some(0)).index_map(2),
+            vec![Some(1), Some(2)]
+        );
+        assert_eq!(
+            ListChange::Move(2, 0).index_map(3),
+            vec![Some(1), Some(2), Some(0)]
+        );
+
+        assert_eq!(
+            list_item_index("phones", "phones[1].number"),
+            Some((1, ".number"))
+        );
+        assert_eq!(list_item_index("phones", "phones[0]"), Some((0, "")));
+        assert_eq!(list_item_index("phones", "phones2[0]"), None);
+        assert_eq!(list_item_index("phones", "phones"), None);
+    }
+}
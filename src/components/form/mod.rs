@@ -7,11 +7,30 @@
 //! - 用于创建一个实体或收集信息。
 //! - 需要对输入的数据类型进行校验时。
 
+use crate::config_provider::{ConfigContext, ValidateTrigger};
+use crate::locale::LocaleConfig;
 use crate::utils::class_names::conditional_class_names_array;
 use dioxus::prelude::*;
+use serde_json::Value;
 use std::collections::HashMap;
 use std::rc::Rc;
 
+mod control;
+mod instance;
+mod list;
+mod path;
+mod persist;
+mod rule;
+mod schema;
+
+pub use control::*;
+pub use instance::*;
+pub use list::*;
+pub use path::*;
+pub use persist::*;
+pub use rule::*;
+pub use schema::*;
+
 const FORM_STYLE: &str = include_str!("./style.css");
 
 /// 表单布局类型
@@ -76,100 +95,15 @@ pub enum ValidateStatus {
     Validating,
 }
 
-/// 校验规则
-#[derive(Clone)]
-pub struct FormRule {
-    /// 是否必填
-    pub required: bool,
-    /// 错误信息
-    pub message: String,
-    /// 最小长度
-    pub min: Option<usize>,
-    /// 最大长度
-    pub max: Option<usize>,
-    /// 正则表达式
-    pub pattern: Option<String>,
-    /// 自定义校验函数
-    pub validator: Option<Rc<dyn Fn(&str) -> Result<(), String>>>,
-}
-
-impl FormRule {
-    pub fn required(message: &str) -> Self {
-        Self {
-            required: true,
-            message: message.to_string(),
-            min: None,
-            max: None,
-            pattern: None,
-            validator: None,
-        }
-    }
-
-    pub fn min_length(min: usize, message: &str) -> Self {
-        Self {
-            required: false,
-            message: message.to_string(),
-            min: Some(min),
-            max: None,
-            pattern: None,
-            validator: None,
-        }
-    }
-
-    pub fn max_length(max: usize, message: &str) -> Self {
-        Self {
-            required: false,
-            message: message.to_string(),
-            min: None,
-            max: Some(max),
-            pattern: None,
-            validator: None,
-        }
-    }
-
-    pub fn pattern(pattern: &str, message: &str) -> Self {
-        Self {
-            required: false,
-            message: message.to_string(),
-            min: None,
-            max: None,
-            pattern: Some(pattern.to_string()),
-            validator: None,
-        }
-    }
-
-    pub fn custom<F>(validator: F, message: &str) -> Self
-    where
-        F: Fn(&str) -> Result<(), String> + 'static,
-    {
-        Self {
-            required: false,
-            message: message.to_string(),
-            min: None,
-            max: None,
-            pattern: None,
-            validator: Some(Rc::new(validator)),
-        }
-    }
-}
-
-impl PartialEq for FormRule {
-    fn eq(&self, other: &Self) -> bool {
-        self.required == other.required
-            && self.message == other.message
-            && self.min == other.min
-            && self.max == other.max
-            && self.pattern == other.pattern
-    }
-}
-
 /// 表单字段信息
 #[derive(Clone, PartialEq)]
 pub struct FormField {
-    /// 字段名
+    /// 字段名，支持 `user.address.city`、`items[2].qty` 形式的嵌套路径
     pub name: String,
+    /// 字段标签，用于替换校验提示中的 `${label}`，未设置时使用字段名
+    pub label: Option<String>,
     /// 字段值
-    pub value: String,
+    pub value: Value,
     /// 校验规则
     pub rules: Vec<FormRule>,
     /// 校验状态
@@ -178,73 +112,56 @@ pub struct FormField {
     pub error: Option<String>,
     /// 是否已校验
     pub validated: bool,
+    /// 是否被用户操作过
+    pub touched: bool,
+    /// 是否被用户修改为与初始值不同的值
+    pub dirty: bool,
+    /// 依赖的字段路径，依赖的字段被用户修改时重新校验该字段
+    pub dependencies: Vec<String>,
+    /// 校验时机，为 None 时使用表单的校验时机
+    pub validate_trigger: Option<Vec<ValidateTrigger>>,
 }
 
 impl FormField {
     pub fn new(name: &str) -> Self {
         Self {
             name: name.to_string(),
-            value: String::new(),
+            label: None,
+            value: Value::Null,
             rules: Vec::new(),
             status: None,
             error: None,
             validated: false,
+            touched: false,
+            dirty: false,
+            dependencies: Vec::new(),
+            validate_trigger: None,
         }
     }
 
-    /// 校验字段
+    /// 校验字段，使用默认语言包中的提示模板
     pub fn validate(&mut self) -> bool {
+        self.validate_with(&LocaleConfig::default())
+    }
+
+    /// 校验字段，使用指定语言包中的提示模板
+    pub fn validate_with(&mut self, locale: &LocaleConfig) -> bool {
+        self.validate_with_values(locale, &Value::Null)
+    }
+
+    /// 校验字段，`values` 为整个表单的值，供跨字段校验读取
+    pub fn validate_with_values(&mut self, locale: &LocaleConfig, values: &Value) -> bool {
         self.validated = true;
         self.error = None;
         self.status = None;
 
+        let label = self.label.clone().unwrap_or_else(|| self.name.clone());
         for rule in &self.rules {
-            // 必填校验
-            if rule.required && self.value.trim().is_empty() {
-                self.error = Some(rule.message.clone());
+            if let Err(error) = rule.check_with_values(&self.value, values, &label, locale) {
+                self.error = Some(error);
                 self.status = Some(ValidateStatus::Error);
                 return false;
             }
-
-            // 长度校验
-            if let Some(min) = rule.min {
-                if self.value.len() < min {
-                    self.error = Some(rule.message.clone());
-                    self.status = Some(ValidateStatus::Error);
-                    return false;
-                }
-            }
-
-            if let Some(max) = rule.max {
-                if self.value.len() > max {
-                    self.error = Some(rule.message.clone());
-                    self.status = Some(ValidateStatus::Error);
-                    return false;
-                }
-            }
-
-            // 正则校验
-            if let Some(pattern) = &rule.pattern {
-                // 简单的正则校验实现
-                if !self.value.is_empty() {
-                    // 这里可以使用 regex crate 进行更复杂的正则校验
-                    // 暂时使用简单的字符串匹配
-                    if pattern == "email" && !self.value.contains('@') {
-                        self.error = Some(rule.message.clone());
-                        self.status = Some(ValidateStatus::Error);
-                        return false;
-                    }
-                }
-            }
-
-            // 自定义校验
-            if let Some(validator) = &rule.validator {
-                if let Err(err) = validator(&self.value) {
-                    self.error = Some(err);
-                    self.status = Some(ValidateStatus::Error);
-                    return false;
-                }
-            }
         }
 
         self.status = Some(ValidateStatus::Success);
@@ -252,11 +169,26 @@ impl FormField {
     }
 }
 
+/// 字段值是否为空：`null`、空白字符串、空数组与空对象
+pub fn is_empty_value(value: &Value) -> bool {
+    match value {
+        Value::Null => true,
+        Value::String(text) => text.trim().is_empty(),
+        Value::Array(items) => items.is_empty(),
+        Value::Object(map) => map.is_empty(),
+        _ => false,
+    }
+}
+
 /// Form 组件属性
 #[derive(Props, Clone, PartialEq)]
 pub struct FormProps {
     /// 表单子元素
     pub children: Element,
+    /// 通过 [`use_form`] 创建的表单实例，未设置时内部创建
+    pub form: Option<FormInstance>,
+    /// 表单的初始值，按字段路径读取各字段的初始值，重置字段时恢复为该值
+    pub initial_values: Option<Value>,
     /// 表单布局
     #[props(default = FormLayout::Horizontal)]
     pub layout: FormLayout,
@@ -273,12 +205,16 @@ pub struct FormProps {
     /// 是否禁用表单
     #[props(default = false)]
     pub disabled: bool,
-    /// 表单提交事件
-    pub on_finish: Option<EventHandler<HashMap<String, String>>>,
-    /// 表单提交失败事件
-    pub on_finish_failed: Option<EventHandler<HashMap<String, String>>>,
-    /// 字段值变化事件
-    pub on_values_change: Option<EventHandler<(String, String)>>,
+    /// 字段的校验时机，可组合多个；为空时仅在提交时校验。未设置时使用 `ConfigProvider` 的表单配置
+    pub validate_trigger: Option<Vec<ValidateTrigger>>,
+    /// 将表单值保存为草稿，挂载时恢复，提交成功后清除
+    pub persist: Option<FormPersist>,
+    /// 表单提交事件，参数为嵌套结构的表单值，可通过 `serde_json::from_value` 转换为结构体
+    pub on_finish: Option<EventHandler<Value>>,
+    /// 表单提交失败事件，参数为表单值与校验失败的字段
+    pub on_finish_failed: Option<EventHandler<FinishFailedInfo>>,
+    /// 字段值变化事件，参数为字段路径与新值
+    pub on_values_change: Option<EventHandler<(String, Value)>>,
     /// 自定义样式类名
     #[props(default = String::new())]
     pub class: String,
@@ -290,9 +226,85 @@ pub struct FormProps {
 /// Form 表单组件
 #[component]
 pub fn Form(props: FormProps) -> Element {
-    let mut form_fields = use_signal(|| HashMap::<String, FormField>::new());
+    let inner_form = use_form();
+    let form = props.form.unwrap_or(inner_form);
+
+    // 校验提示使用当前语言包
+    let locale = try_use_context::<Signal<LocaleConfig>>();
+    form.set_locale(
+        locale
+            .map(|locale| locale.read().clone())
+            .unwrap_or_default(),
+    );
+
+    // 校验时机，未设置时使用全局表单配置
+    let config = try_use_context::<ConfigContext>();
+    form.set_validate_trigger(props.validate_trigger.clone().unwrap_or_else(|| {
+        vec![config
+            .map(|config| config.config.form.validate_trigger)
+            .unwrap_or(ValidateTrigger::OnChange)]
+    }));
+
+    use_hook(|| {
+        if let Some(initial_values) = props.initial_values.clone() {
+            form.set_initial_values(initial_values);
+        }
+    });
+
+    // 恢复草稿，表单值变化后按防抖时间保存
+    let persist = props.persist.clone();
+    use_hook(|| {
+        if let Some(values) = persist.as_ref().and_then(FormPersist::load) {
+            form.restore_values(values);
+        }
+    });
+    let saver = use_hook(PersistSaver::new);
+    use_effect(use_reactive!(|persist| {
+        let values = form.get_fields_value();
+        let dirty = form.is_dirty();
+        match persist {
+            Some(persist) => saver.schedule(persist, values, dirty),
+            None => saver.cancel(),
+        }
+    }));
+
+    // 注册提交函数，供提交按钮与 `FormInstance::submit` 调用
+    let on_finish = props.on_finish;
+    let on_finish_failed = props.on_finish_failed;
+    let persist = props.persist.clone();
+    form.set_submit(Rc::new(move || {
+        let persist = persist.clone();
+        let finish = move |result: Result<Value, HashMap<String, String>>| match result {
+            Ok(values) => {
+                // 先标记为未修改，校验写入字段后重新执行的保存逻辑会清除而不是保存草稿
+                form.mark_clean();
+                if let Some(persist) = &persist {
+                    saver.cancel();
+                    persist.clear();
+                }
+                if let Some(callback) = &on_finish {
+                    callback.call(values);
+                }
+            }
+            Err(errors) => {
+                if let Some(callback) = &on_finish_failed {
+                    callback.call(FinishFailedInfo {
+                        values: form.peek_fields_value(),
+                        error_fields: ErrorField::from_errors(errors),
+                    });
+                }
+            }
+        };
+        // 存在异步校验规则时等待校验完成后再回调
+        if form.has_async_rules() {
+            form.spawn(async move { finish(form.validate_fields_async(None).await) });
+        } else {
+            finish(form.validate_fields(None));
+        }
+    }));
 
     // 提供表单上下文
+    let on_values_change = props.on_values_change;
     use_context_provider(|| FormContext {
         layout: props.layout,
         size: props.size,
@@ -300,63 +312,26 @@ pub fn Form(props: FormProps) -> Element {
         label_col: props.label_col,
         wrapper_col: props.wrapper_col,
         disabled: props.disabled,
-        fields: form_fields.clone(),
-        on_field_change: Rc::new(move |name: String, value: String| {
-            let mut form_fields = form_fields.clone();
-            form_fields.with_mut(|fields| {
-                if let Some(field) = fields.get_mut(&name) {
-                    field.value = value.clone();
-                    field.validate();
-                } else {
-                    let mut field = FormField::new(&name);
-                    field.value = value.clone();
-                    fields.insert(name.clone(), field);
-                }
-            });
+        form,
+        fields: form.fields,
+        on_field_change: Rc::new(move |name: String, value: Value| {
+            form.change_field(&name, value.clone());
 
-            if let Some(callback) = &props.on_values_change {
+            if let Some(callback) = &on_values_change {
                 callback.call((name, value));
             }
         }),
-        on_field_register: Rc::new(move |name: String, rules: Vec<FormRule>| {
-            let mut form_fields = form_fields.clone();
-            form_fields.with_mut(|fields| {
-                if let Some(field) = fields.get_mut(&name) {
-                    field.rules = rules;
-                } else {
-                    let mut field = FormField::new(&name);
-                    field.rules = rules;
-                    fields.insert(name, field);
-                }
-            });
-        }),
+        on_field_register: Rc::new(
+            move |name: String, label: Option<String>, rules: Vec<FormRule>| {
+                form.register_field(&name, label, rules);
+            },
+        ),
     });
 
     // 处理表单提交
     let handle_submit = move |evt: FormEvent| {
         evt.prevent_default();
-
-        let mut all_valid = true;
-        let mut form_data = HashMap::new();
-
-        form_fields.with_mut(|fields| {
-            for (name, field) in fields.iter_mut() {
-                if !field.validate() {
-                    all_valid = false;
-                }
-                form_data.insert(name.clone(), field.value.clone());
-            }
-        });
-
-        if all_valid {
-            if let Some(callback) = &props.on_finish {
-                callback.call(form_data);
-            }
-        } else {
-            if let Some(callback) = &props.on_finish_failed {
-                callback.call(form_data);
-            }
-        }
+        form.submit();
     };
 
     let form_class = conditional_class_names_array(&[
@@ -401,6 +376,20 @@ pub struct FormItemProps {
     /// 是否必填
     #[props(default = false)]
     pub required: bool,
+    /// 依赖的字段路径，依赖的字段被用户修改时重新校验该字段；设置了 `render` 时依赖的字段值变化后重新渲染
+    #[props(default = Vec::new())]
+    pub dependencies: Vec<String>,
+    /// 自定义是否重新渲染 `render`，参数为变化前与变化后的表单值
+    pub should_update: Option<Callback<(Value, Value), bool>>,
+    /// 根据表单值渲染内容，替代 `children`，配合 `should_update` 或 `dependencies` 使用
+    pub render: Option<Callback<FormInstance, Element>>,
+    /// 内部控件绑定字段值的属性，未设置时使用控件的默认属性，如 Input 为 `value`、Switch 为 `checked`
+    pub value_prop_name: Option<String>,
+    /// 收集字段值的事件，如 `on_change`、`on_blur`
+    #[props(default = DEFAULT_TRIGGER.to_string())]
+    pub trigger: String,
+    /// 字段的校验时机，未设置时使用 Form 的 `validate_trigger`
+    pub validate_trigger: Option<Vec<ValidateTrigger>>,
     /// 额外的提示信息
     pub extra: Option<String>,
     /// 配合 label 属性使用，表示是否显示 label 后面的冒号
@@ -422,32 +411,111 @@ pub struct FormItemProps {
 #[component]
 pub fn FormItem(props: FormItemProps) -> Element {
     let form_context = use_context::<FormContext>();
-    let mut field_status = use_signal(|| None::<ValidateStatus>);
-    let mut field_error = use_signal(|| None::<String>);
-
-    // 注册字段
-    let name_clone = props.name.clone();
-    use_effect(move || {
-        if let Some(name) = &name_clone {
-            let mut rules = props.rules.clone();
-            if props.required && !rules.iter().any(|r| r.required) {
-                rules.push(FormRule::required("此字段为必填项"));
-            }
-            (form_context.on_field_register)(name.clone(), rules);
-        }
-    });
 
-    // 监听字段状态变化
-    let name_clone2 = props.name.clone();
-    use_effect(move || {
-        if let Some(name) = &name_clone2 {
-            let fields = form_context.fields.read();
-            if let Some(field) = fields.get(name) {
-                field_status.set(field.status.clone());
-                field_error.set(field.error.clone());
+    // 注册字段，字段名变化时（如列表项的下标变化）重新注册
+    let name = props.name.clone();
+    let label = props.label.clone();
+    let rules = props.rules.clone();
+    let required = props.required;
+    use_effect(use_reactive!(|name, label, rules, required| {
+        if let Some(name) = name {
+            let mut rules = rules;
+            if required && !rules.iter().any(|r| r.required) {
+                rules.push(FormRule::default().with_required());
             }
+            (form_context.on_field_register)(name, label, rules);
+        }
+    }));
+
+    // 设置依赖的字段
+    let name = props.name.clone();
+    let dependencies = props.dependencies.clone();
+    use_effect(use_reactive!(|name, dependencies| {
+        if let Some(name) = name {
+            form_context
+                .form
+                .set_field_dependencies(&name, dependencies);
         }
+    }));
+
+    // 设置字段的校验时机
+    let name = props.name.clone();
+    let validate_trigger = props.validate_trigger.clone();
+    use_effect(use_reactive!(|name, validate_trigger| {
+        if let Some(name) = name {
+            form_context
+                .form
+                .set_field_validate_trigger(&name, validate_trigger);
+        }
+    }));
+
+    // 向内部控件提供字段信息，控件据此自动读写字段值
+    let item_context = FormItemContext {
+        name: props.name.clone(),
+        value_prop_name: props.value_prop_name.clone(),
+        trigger: props.trigger.clone(),
+        on_change: form_context.on_field_change.clone(),
+    };
+    let mut item_signal = use_context_provider(|| Signal::new(item_context.clone()));
+    use_effect(use_reactive!(|item_context| {
+        if *item_signal.peek() != item_context {
+            item_signal.set(item_context);
+        }
+    }));
+
+    // 读取字段的校验状态，仅在状态变化时重新渲染
+    let fields = form_context.fields;
+    let name = props.name.clone();
+    let field_state = use_memo(use_reactive!(|name| {
+        name.and_then(|name| {
+            fields
+                .read()
+                .get(&name)
+                .map(|field| (field.status.clone(), field.error.clone()))
+        })
+        .unwrap_or_default()
+    }));
+    let (field_status, field_error) = field_state();
+
+    // 自定义渲染：依赖的字段值变化或 `should_update` 返回 true 时重新渲染
+    let form = form_context.form;
+    let has_render = props.render.is_some();
+    let should_update = props.should_update;
+    let mut prev_values = use_hook(|| CopyValue::new(Value::Null));
+    let mut update_count = use_hook(|| CopyValue::new(0usize));
+    let dependencies = props.dependencies.clone();
+    let update_version = use_memo(use_reactive!(|dependencies| {
+        if !has_render {
+            return 0;
+        }
+        let values = form.get_fields_value();
+        let prev = prev_values.with_mut(|prev| std::mem::replace(prev, values.clone()));
+        let changed = match should_update {
+            Some(should_update) => should_update.call((prev, values)),
+            None => dependencies.iter().any(|dependency| {
+                get_value_by_path(&prev, dependency) != get_value_by_path(&values, dependency)
+            }),
+        };
+        if changed {
+            update_count += 1;
+        }
+        update_count()
+    }));
+    // `render` 中读取表单值不订阅变化，由 `update_version` 控制重新渲染
+    let untracked = use_hook(|| {
+        ReactiveContext::new_with_callback(
+            || {},
+            current_scope_id().unwrap_or(ScopeId::ROOT),
+            std::panic::Location::caller(),
+        )
     });
+    let content = match props.render {
+        Some(render) => {
+            update_version();
+            untracked.run_in(|| render.call(form))
+        }
+        None => props.children.clone(),
+    };
 
     let label_col = props.label_col.or(form_context.label_col).unwrap_or(6);
     let wrapper_col = props.wrapper_col.or(form_context.wrapper_col).unwrap_or(18);
@@ -457,19 +525,19 @@ pub fn FormItem(props: FormItemProps) -> Element {
         ("ant-form-item-required", props.required),
         (
             "ant-form-item-has-success",
-            field_status.read().as_ref() == Some(&ValidateStatus::Success),
+            field_status.as_ref() == Some(&ValidateStatus::Success),
         ),
         (
             "ant-form-item-has-warning",
-            field_status.read().as_ref() == Some(&ValidateStatus::Warning),
+            field_status.as_ref() == Some(&ValidateStatus::Warning),
         ),
         (
             "ant-form-item-has-error",
-            field_status.read().as_ref() == Some(&ValidateStatus::Error),
+            field_status.as_ref() == Some(&ValidateStatus::Error),
         ),
         (
             "ant-form-item-is-validating",
-            field_status.read().as_ref() == Some(&ValidateStatus::Validating),
+            field_status.as_ref() == Some(&ValidateStatus::Validating),
         ),
         (&props.class, !props.class.is_empty()),
     ]);
@@ -478,6 +546,7 @@ pub fn FormItem(props: FormItemProps) -> Element {
         div {
             class: "{item_class}",
             style: "{props.style}",
+            id: props.name.as_ref().map(|name| form_context.form.field_id(name)),
 
             if form_context.layout == FormLayout::Horizontal {
                 div { class: "ant-row ant-form-item-row",
@@ -505,11 +574,11 @@ pub fn FormItem(props: FormItemProps) -> Element {
                         style: "flex: 0 0 {wrapper_col * 100 / 24}%;",
 
                         div { class: "ant-form-item-control-input",
-                            {props.children}
+                            {content}
                         }
 
                         // 错误信息
-                        if let Some(error) = field_error.read().as_ref() {
+                        if let Some(error) = field_error.as_ref() {
                             div { class: "ant-form-item-explain ant-form-item-explain-error",
                                 "{error}"
                             }
@@ -540,11 +609,11 @@ pub fn FormItem(props: FormItemProps) -> Element {
 
                 div { class: "ant-form-item-control",
                     div { class: "ant-form-item-control-input",
-                        {props.children}
+                        {content}
                     }
 
                     // 错误信息
-                    if let Some(error) = field_error.read().as_ref() {
+                    if let Some(error) = field_error.as_ref() {
                         div { class: "ant-form-item-explain ant-form-item-explain-error",
                             "{error}"
                         }
@@ -560,6 +629,9 @@ pub fn FormItem(props: FormItemProps) -> Element {
     }
 }
 
+/// 注册字段的回调，参数为字段路径、标签与校验规则
+pub type FieldRegisterFn = Rc<dyn Fn(String, Option<String>, Vec<FormRule>)>;
+
 /// 表单上下文
 #[derive(Clone)]
 pub struct FormContext {
@@ -569,9 +641,10 @@ pub struct FormContext {
     pub label_col: Option<usize>,
     pub wrapper_col: Option<usize>,
     pub disabled: bool,
+    pub form: FormInstance,
     pub fields: Signal<HashMap<String, FormField>>,
-    pub on_field_change: Rc<dyn Fn(String, String)>,
-    pub on_field_register: Rc<dyn Fn(String, Vec<FormRule>)>,
+    pub on_field_change: Rc<dyn Fn(String, Value)>,
+    pub on_field_register: FieldRegisterFn,
 }
 
 impl PartialEq for FormContext {
@@ -582,6 +655,7 @@ impl PartialEq for FormContext {
             && self.label_col == other.label_col
             && self.wrapper_col == other.wrapper_col
             && self.disabled == other.disabled
+            && self.form == other.form
     }
 }
 
@@ -606,6 +680,8 @@ macro_rules! form_rules {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use serde_json::json;
+    use std::cell::Cell;
 
     #[test]
     fn test_form_layout_default() {
@@ -626,7 +702,7 @@ mod tests {
     fn test_form_field_new() {
         let field = FormField::new("username");
         assert_eq!(field.name, "username");
-        assert_eq!(field.value, "");
+        assert_eq!(field.value, Value::Null);
         assert!(field.rules.is_empty());
         assert!(field.status.is_none());
         assert!(field.error.is_none());
@@ -644,7 +720,7 @@ mod tests {
         // assert_eq!(field.status, Some(ValidateStatus::Error));
 
         // 有值校验成功
-        field.value = "test".to_string();
+        field.value = json!("test");
         assert!(field.validate());
         assert!(field.error.is_none());
         // assert_eq!(field.status, Some(ValidateStatus::Success));
@@ -658,15 +734,85 @@ mod tests {
 
         let rule = FormRule::min_length(3, "最少3个字符");
         assert!(!rule.required);
-        assert_eq!(rule.min, Some(3));
+        assert_eq!(rule.min, Some(3.0));
         assert_eq!(rule.message, "最少3个字符");
 
         let rule = FormRule::max_length(10, "最多10个字符");
-        assert_eq!(rule.max, Some(10));
+        assert_eq!(rule.max, Some(10.0));
         assert_eq!(rule.message, "最多10个字符");
 
+        let rule = FormRule::pattern(r"^\d+$", "只能输入数字");
+        assert_eq!(rule.pattern, Some(RulePattern::from(r"^\d+$")));
+        assert_eq!(rule.message, "只能输入数字");
+
+        // "email" 仍表示邮箱校验，而不是字面量正则
         let rule = FormRule::pattern("email", "邮箱格式不正确");
-        assert_eq!(rule.pattern, Some("email".to_string()));
+        assert_eq!(rule.rule_type, Some(RuleType::Email));
+        assert_eq!(rule.pattern, None);
         assert_eq!(rule.message, "邮箱格式不正确");
+        let locale = LocaleConfig::default();
+        assert!(rule.check(&json!("a@b.com"), "邮箱", &locale).is_ok());
+        assert_eq!(
+            rule.check(&json!("email"), "邮箱", &locale),
+            Err("邮箱格式不正确".to_string())
+        );
+    }
+
+    thread_local! {
+        /// 组件内执行完成的检查数
+        static CHECKED: Cell<usize> = const { Cell::new(0) };
+        /// 等待在组件内运行的测试
+        static TEST: Cell<Option<fn()>> = const { Cell::new(None) };
+    }
+
+    /// 记录组件内的一次检查执行完成
+    ///
+    /// VirtualDom 会吞掉组件内的 panic，断言失败的检查不会计数，由调用方核对数量。
+    pub(super) fn mark_checked() {
+        CHECKED.with(|checked| checked.set(checked.get() + 1));
+    }
+
+    /// 渲染组件，返回组件内执行完成的检查数
+    pub(super) fn render_checked(app: fn() -> Element) -> usize {
+        CHECKED.with(|checked| checked.set(0));
+        let mut dom = VirtualDom::new(app);
+        dom.rebuild_in_place();
+        CHECKED.with(Cell::get)
+    }
+
+    /// 在组件内运行测试，表单实例依赖 Dioxus 运行时
+    pub(super) fn run_in_component(test: fn()) {
+        #[component]
+        fn App() -> Element {
+            if let Some(test) = TEST.with(Cell::take) {
+                test();
+                mark_checked();
+            }
+            rsx! {}
+        }
+
+        TEST.with(|current| current.set(Some(test)));
+        assert_eq!(render_checked(App), 1, "组件内的测试未执行完成");
+    }
+
+    #[test]
+    fn test_form_field_validate_value() {
+        let mut field = FormField::new("tags");
+        field.rules = vec![
+            FormRule::required("请选择标签"),
+            FormRule::max_length(2, "最多两个标签"),
+        ];
+        field.value = json!([]);
+        assert!(!field.validate());
+        field.value = json!(["a", "b", "c"]);
+        assert!(!field.validate());
+        assert_eq!(field.error, Some("最多两个标签".to_string()));
+        field.value = json!(["a"]);
+        assert!(field.validate());
+
+        let mut field = FormField::new("age");
+        field.rules = vec![FormRule::required("请输入年龄")];
+        field.value = json!(0);
+        assert!(field.validate());
     }
 }
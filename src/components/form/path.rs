@@ -0,0 +1,180 @@
+//! Form 字段路径
+//!
+//! 字段名支持 `user.address.city`、`items[2].qty` 形式的嵌套路径，表单值以
+//! `serde_json::Value` 的嵌套结构保存。
+
+use serde_json::{Map, Value};
+
+/// 字段路径中的一段
+#[derive(Debug, Clone, PartialEq)]
+pub enum PathSegment {
+    /// 对象的键
+    Key(String),
+    /// 数组的下标
+    Index(usize),
+}
+
+/// 解析字段路径，`items[2].qty` 解析为 `[Key("items"), Index(2), Key("qty")]`
+///
+/// 方括号内不是数字时按对象的键处理。
+pub fn parse_name_path(name: &str) -> Vec<PathSegment> {
+    let mut segments = Vec::new();
+    let mut key = String::new();
+    let mut chars = name.chars();
+
+    while let Some(ch) = chars.next() {
+        match ch {
+            '.' => {
+                if !key.is_empty() {
+                    segments.push(PathSegment::Key(std::mem::take(&mut key)));
+                }
+            }
+            '[' => {
+                if !key.is_empty() {
+                    segments.push(PathSegment::Key(std::mem::take(&mut key)));
+                }
+                let index: String = chars.by_ref().take_while(|ch| *ch != ']').collect();
+                segments.push(match index.trim().parse::<usize>() {
+                    Ok(index) => PathSegment::Index(index),
+                    Err(_) => PathSegment::Key(index),
+                });
+            }
+            ch => key.push(ch),
+        }
+    }
+    if !key.is_empty() {
+        segments.push(PathSegment::Key(key));
+    }
+    segments
+}
+
+/// 按字段路径读取值
+pub fn get_value_by_path<'a>(value: &'a Value, name: &str) -> Option<&'a Value> {
+    parse_name_path(name)
+        .iter()
+        .try_fold(value, |value, segment| match segment {
+            PathSegment::Key(key) => value.get(key),
+            PathSegment::Index(index) => value.get(index),
+        })
+}
+
+/// 按字段路径写入值，自动创建中间的对象与数组，数组长度不足时以 `null` 补齐
+pub fn set_value_by_path(target: &mut Value, name: &str, value: Value) {
+    let mut current = target;
+    for segment in parse_name_path(name) {
+        current = match segment {
+            PathSegment::Key(key) => {
+                if !current.is_object() {
+                    *current = Value::Object(Map::new());
+                }
+                match current {
+                    Value::Object(map) => map.entry(key).or_insert(Value::Null),
+                    _ => unreachable!(),
+                }
+            }
+            PathSegment::Index(index) => {
+                if !current.is_array() {
+                    *current = Value::Array(Vec::new());
+                }
+                match current {
+                    Value::Array(items) => {
+                        if items.len() <= index {
+                            items.resize(index + 1, Value::Null);
+                        }
+                        &mut items[index]
+                    }
+                    _ => unreachable!(),
+                }
+            }
+        };
+    }
+    *current = value;
+}
+
+/// 展开嵌套对象，返回各叶子节点的字段路径与值；数组与标量视为叶子节点
+pub fn flatten_object(value: &Value) -> Vec<(String, Value)> {
+    fn walk(prefix: &str, value: &Value, output: &mut Vec<(String, Value)>) {
+        match value {
+            Value::Object(map) if !map.is_empty() => {
+                for (key, value) in map {
+                    let name = if prefix.is_empty() {
+                        key.clone()
+                    } else {
+                        format!("{prefix}.{key}")
+                    };
+                    walk(&name, value, output);
+                }
+            }
+            value if !prefix.is_empty() => output.push((prefix.to_string(), value.clone())),
+            _ => {}
+        }
+    }
+
+    let mut output = Vec::new();
+    walk("", value, &mut output);
+    output
+}
+
+/// 路径 `parent` 是否为 `name` 本身或其上级路径
+pub fn is_path_prefix(parent: &str, name: &str) -> bool {
+    let parent = parse_name_path(parent);
+    let name = parse_name_path(name);
+    parent.len() <= name.len() && parent.iter().zip(&name).all(|(a, b)| a == b)
+}
+
+/// 字段值的文本形式：字符串原样返回，`null` 为空字符串，其他值序列化为 JSON
+pub fn value_to_text(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(text) => text.clone(),
+        value => value.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_name_path() {
+        assert_eq!(
+            parse_name_path("items[2].qty"),
+            vec![
+                PathSegment::Key("items".to_string()),
+                PathSegment::Index(2),
+                PathSegment::Key("qty".to_string()),
+            ]
+        );
+        assert_eq!(parse_name_path("user.address.city").len(), 3);
+        assert_eq!(
+            parse_name_path("map[key]"),
+            vec![
+                PathSegment::Key("map".to_string()),
+                PathSegment::Key("key".to_string()),
+            ]
+        );
+
+        let mut value = Value::Null;
+        set_value_by_path(&mut value, "items[1].qty", json!(2));
+        set_value_by_path(&mut value, "user.name", json!("admin"));
+        assert_eq!(
+            value,
+            json!({ "items": [null, { "qty": 2 }], "user": { "name": "admin" } })
+        );
+        assert_eq!(get_value_by_path(&value, "items[1].qty"), Some(&json!(2)));
+        assert_eq!(get_value_by_path(&value, "items[5].qty"), None);
+
+        assert!(is_path_prefix("items", "items[1].qty"));
+        assert!(!is_path_prefix("item", "items[1].qty"));
+        assert_eq!(
+            flatten_object(&json!({ "user": { "name": "a" }, "tags": [1] })),
+            vec![
+                ("user.name".to_string(), json!("a")),
+                ("tags".to_string(), json!([1])),
+            ]
+        );
+        assert_eq!(value_to_text(&json!(18)), "18");
+        assert_eq!(value_to_text(&Value::Null), "");
+    }
+}
@@ -0,0 +1,280 @@
+//! Form 表单值的持久化
+//!
+//! 设置 `Form` 的 `persist` 后，表单挂载时恢复保存的草稿，用户修改表单值后按防抖时间
+//! 保存，表单值恢复为初始值或提交成功后清除。
+
+use dioxus::prelude::*;
+use serde_json::Value;
+use std::rc::Rc;
+
+/// 自定义存储，如 IndexedDB 或服务端草稿接口的同步封装
+pub trait FormStorage {
+    /// 读取保存的内容
+    fn get_item(&self, key: &str) -> Option<String>;
+    /// 保存内容
+    fn set_item(&self, key: &str, value: &str);
+    /// 删除保存的内容
+    fn remove_item(&self, key: &str);
+}
+
+/// 表单值的存储位置
+#[derive(Clone, Default)]
+pub enum PersistStorage {
+    /// localStorage，关闭页面后保留
+    #[default]
+    Local,
+    /// sessionStorage，关闭标签页后清除
+    Session,
+    /// 自定义存储
+    Custom(Rc<dyn FormStorage>),
+}
+
+impl PartialEq for PersistStorage {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (PersistStorage::Local, PersistStorage::Local) => true,
+            (PersistStorage::Session, PersistStorage::Session) => true,
+            (PersistStorage::Custom(a), PersistStorage::Custom(b)) => Rc::ptr_eq(a, b),
+            _ => false,
+        }
+    }
+}
+
+impl PersistStorage {
+    /// 浏览器提供的存储，不可用（如隐私模式禁用了存储）时为 None
+    fn web_storage(&self) -> Option<web_sys::Storage> {
+        let window = web_sys::window()?;
+        match self {
+            PersistStorage::Local => window.local_storage().ok().flatten(),
+            PersistStorage::Session => window.session_storage().ok().flatten(),
+            PersistStorage::Custom(_) => None,
+        }
+    }
+
+    fn get_item(&self, key: &str) -> Option<String> {
+        match self {
+            PersistStorage::Custom(storage) => storage.get_item(key),
+            _ => self.web_storage()?.get_item(key).ok().flatten(),
+        }
+    }
+
+    fn set_item(&self, key: &str, value: &str) {
+        match self {
+            PersistStorage::Custom(storage) => storage.set_item(key, value),
+            _ => {
+                if let Some(storage) = self.web_storage() {
+                    let _ = storage.set_item(key, value);
+                }
+            }
+        }
+    }
+
+    fn remove_item(&self, key: &str) {
+        match self {
+            PersistStorage::Custom(storage) => storage.remove_item(key),
+            _ => {
+                if let Some(storage) = self.web_storage() {
+                    let _ = storage.remove_item(key);
+                }
+            }
+        }
+    }
+}
+
+/// 表单值持久化配置
+#[derive(Clone, PartialEq)]
+pub struct FormPersist {
+    /// 存储的键
+    pub key: String,
+    /// 存储位置
+    pub storage: PersistStorage,
+    /// 保存的防抖时间（毫秒），期间的再次修改会重新计时
+    pub debounce: u32,
+}
+
+impl FormPersist {
+    /// 保存到 localStorage，防抖时间为 500 毫秒
+    pub fn new(key: &str) -> Self {
+        Self {
+            key: key.to_string(),
+            storage: PersistStorage::Local,
+            debounce: 500,
+        }
+    }
+
+    /// 设置存储位置
+    pub fn storage(mut self, storage: PersistStorage) -> Self {
+        self.storage = storage;
+        self
+    }
+
+    /// 使用自定义存储
+    pub fn custom(self, storage: impl FormStorage + 'static) -> Self {
+        self.storage(PersistStorage::Custom(Rc::new(storage)))
+    }
+
+    /// 设置保存的防抖时间
+    pub fn debounce(mut self, debounce: u32) -> Self {
+        self.debounce = debounce;
+        self
+    }
+
+    /// 读取保存的表单值，内容无法解析时为 None
+    pub fn load(&self) -> Option<Value> {
+        serde_json::from_str(&self.storage.get_item(&self.key)?).ok()
+    }
+
+    /// 保存表单值
+    pub fn save(&self, values: &Value) {
+        self.storage.set_item(&self.key, &values.to_string());
+    }
+
+    /// 清除保存的表单值
+    pub fn clear(&self) {
+        self.storage.remove_item(&self.key);
+    }
+}
+
+/// 按防抖时间保存表单值，由 `Form` 使用
+#[derive(Clone, Copy)]
+pub(crate) struct PersistSaver {
+    /// 等待保存的任务，表单值再次变化或提交成功时取消
+    task: CopyValue<Option<Task>>,
+}
+
+impl PersistSaver {
+    pub(crate) fn new() -> Self {
+        Self {
+            task: CopyValue::new(None),
+        }
+    }
+
+    /// 取消等待保存的任务
+    pub(crate) fn cancel(&self) {
+        let mut task = self.task;
+        if let Some(task) = task.take() {
+            task.cancel();
+        }
+    }
+
+    /// 表单值变化后调用：有修改时按防抖时间保存，否则清除保存的表单值
+    pub(crate) fn schedule(&self, persist: FormPersist, values: Value, dirty: bool) {
+        self.cancel();
+        if !dirty {
+            persist.clear();
+            return;
+        }
+        let mut task = self.task;
+        task.set(Some(spawn(async move {
+            gloo_timers::future::TimeoutFuture::new(persist.debounce).await;
+            persist.save(&values);
+        })));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::form::tests::run_in_component;
+    use crate::components::form::{use_form, Form, FormInstance};
+    use serde_json::json;
+    use std::cell::{Cell, RefCell};
+    use std::collections::HashMap;
+
+    /// 测试用的内存存储
+    #[derive(Default)]
+    struct MemoryStorage(RefCell<HashMap<String, String>>);
+
+    impl FormStorage for MemoryStorage {
+        fn get_item(&self, key: &str) -> Option<String> {
+            self.0.borrow().get(key).cloned()
+        }
+
+        fn set_item(&self, key: &str, value: &str) {
+            self.0
+                .borrow_mut()
+                .insert(key.to_string(), value.to_string());
+        }
+
+        fn remove_item(&self, key: &str) {
+            self.0.borrow_mut().remove(key);
+        }
+    }
+
+    #[test]
+    fn test_form_persist() {
+        let storage = Rc::new(MemoryStorage::default());
+        let persist = FormPersist::new("draft").storage(PersistStorage::Custom(storage.clone()));
+        assert_eq!(persist.load(), None);
+
+        persist.save(&json!({ "title": "草稿" }));
+        assert_eq!(persist.load(), Some(json!({ "title": "草稿" })));
+        storage.set_item("draft", "not json");
+        assert_eq!(persist.load(), None);
+        persist.clear();
+        assert_eq!(storage.get_item("draft"), None);
+
+        run_in_component(|| {
+            let form = use_form();
+            form.set_initial_values(json!({ "title": "", "tags": ["a"] }));
+            form.register_field("title", None, Vec::new());
+            form.register_field("tags", None, Vec::new());
+            assert!(!form.is_dirty());
+
+            // 恢复的草稿中与初始值不同的字段视为修改过
+            form.restore_values(json!({ "title": "草稿", "tags": ["a"] }));
+            assert_eq!(form.get_field_value("title"), Some(json!("草稿")));
+            assert!(form.is_field_dirty("title"));
+            assert!(!form.is_field_dirty("tags"));
+            assert!(form.is_dirty());
+
+            form.change_field("title", json!(""));
+            assert!(!form.is_dirty());
+        });
+    }
+
+    #[test]
+    fn test_form_persist_cleared_on_finish() {
+        thread_local! {
+            static STORAGE: Rc<MemoryStorage> = Rc::new(MemoryStorage::default());
+            static FORM: Cell<Option<FormInstance>> = const { Cell::new(None) };
+            static FINISHED: RefCell<Option<Value>> = const { RefCell::new(None) };
+        }
+
+        #[component]
+        fn App() -> Element {
+            let form = use_form();
+            FORM.with(|current| current.set(Some(form)));
+            let storage = STORAGE.with(Rc::clone);
+            rsx! {
+                Form {
+                    form,
+                    persist: FormPersist::new("draft").storage(PersistStorage::Custom(storage)),
+                    on_finish: move |values| {
+                        FINISHED.with(|finished| finished.replace(Some(values)));
+                    },
+                }
+            }
+        }
+
+        let storage = STORAGE.with(Rc::clone);
+        storage.set_item("draft", r#"{"title":"草稿"}"#);
+        let mut dom = VirtualDom::new(App);
+        dom.rebuild_in_place();
+        let form = FORM.with(Cell::get).expect("表单已创建");
+        dom.in_runtime(|| {
+            assert!(form.is_dirty());
+            form.submit();
+        });
+        // 提交时校验写入了字段，执行随之重新运行的保存逻辑；若仍视为有修改会启动保存草稿的
+        // 定时器，非 wasm 环境下直接失败
+        dom.process_events();
+
+        assert_eq!(
+            FINISHED.with(|finished| finished.take()),
+            Some(json!({ "title": "草稿" }))
+        );
+        assert!(!dom.in_runtime(|| form.is_dirty()));
+        assert_eq!(storage.get_item("draft"), None);
+    }
+}
@@ -0,0 +1,680 @@
+//! Form 校验规则
+//!
+//! 内置必填、类型、长度/数值范围、枚举、空白字符与正则校验，未设置 `message` 时
+//! 使用语言包中的提示模板，模板中的 `${label}`、`${min}` 等占位符会被替换。
+
+use super::{is_empty_value, value_to_text};
+use crate::locale::LocaleConfig;
+use crate::utils::rc_option_eq;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde_json::Value;
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+
+static EMAIL_REGEX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^[^\s@]+@[^\s@]+\.[^\s@]+$").expect("valid email regex"));
+
+static URL_REGEX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^(?i)(https?|ftp)://[^\s/$.?#][^\s]*$").expect("valid url regex"));
+
+/// 自定义校验函数
+pub type RuleValidatorFn = Rc<dyn Fn(&str) -> Result<(), String>>;
+
+/// 依赖其他字段的校验函数，参数为字段值与整个表单的值
+pub type FormValuesValidatorFn = Rc<dyn Fn(&Value, &Value) -> Result<(), String>>;
+
+/// 异步校验返回的 Future
+pub type RuleValidateFuture = Pin<Box<dyn Future<Output = Result<(), String>>>>;
+
+/// 异步校验函数，例如请求服务端检查用户名是否可用
+pub type AsyncValidatorFn = Rc<dyn Fn(Value) -> RuleValidateFuture>;
+
+/// 正则校验规则，创建时编译一次，无效的正则表达式在校验时报错
+#[derive(Debug, Clone)]
+pub struct RulePattern {
+    source: String,
+    regex: Result<Regex, regex::Error>,
+}
+
+impl RulePattern {
+    pub fn new(pattern: &str) -> Self {
+        Self {
+            source: pattern.to_string(),
+            regex: Regex::new(pattern),
+        }
+    }
+
+    /// 正则表达式原文
+    pub fn as_str(&self) -> &str {
+        &self.source
+    }
+
+    /// 编译后的正则表达式，无效时返回编译错误
+    pub fn regex(&self) -> Result<&Regex, &regex::Error> {
+        self.regex.as_ref()
+    }
+}
+
+impl PartialEq for RulePattern {
+    fn eq(&self, other: &Self) -> bool {
+        self.source == other.source
+    }
+}
+
+impl From<&str> for RulePattern {
+    fn from(pattern: &str) -> Self {
+        Self::new(pattern)
+    }
+}
+
+impl From<String> for RulePattern {
+    fn from(pattern: String) -> Self {
+        Self::new(&pattern)
+    }
+}
+
+/// 内置的值类型校验
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RuleType {
+    /// 字符串
+    String,
+    /// 数字，数字字符串同样有效
+    Number,
+    /// 布尔值
+    Boolean,
+    /// 整数，整数字符串同样有效
+    Integer,
+    /// 浮点数，数字字符串同样有效
+    Float,
+    /// 数组
+    Array,
+    /// 枚举，值须为 `enum_values` 之一
+    Enum,
+    /// 邮箱地址
+    Email,
+    /// URL
+    Url,
+}
+
+impl RuleType {
+    /// 类型名称，用于提示模板中的 `${type}`
+    pub fn name(&self) -> &'static str {
+        match self {
+            RuleType::String => "string",
+            RuleType::Number => "number",
+            RuleType::Boolean => "boolean",
+            RuleType::Integer => "integer",
+            RuleType::Float => "float",
+            RuleType::Array => "array",
+            RuleType::Enum => "enum",
+            RuleType::Email => "email",
+            RuleType::Url => "url",
+        }
+    }
+
+    fn is_numeric(&self) -> bool {
+        matches!(self, RuleType::Number | RuleType::Integer | RuleType::Float)
+    }
+}
+
+/// 校验规则
+#[derive(Clone, Default)]
+pub struct FormRule {
+    /// 是否必填
+    pub required: bool,
+    /// 错误信息，为空时使用语言包中的提示模板
+    pub message: String,
+    /// 值类型
+    pub rule_type: Option<RuleType>,
+    /// 字符串长度、数组长度或数值须等于该值
+    pub len: Option<f64>,
+    /// 字符串最小长度、数组最小长度或最小数值
+    pub min: Option<f64>,
+    /// 字符串最大长度、数组最大长度或最大数值
+    pub max: Option<f64>,
+    /// 正则表达式
+    pub pattern: Option<RulePattern>,
+    /// 枚举类型的可选值
+    pub enum_values: Vec<Value>,
+    /// 是否将只包含空白字符的字符串视为错误
+    pub whitespace: bool,
+    /// 自定义校验函数
+    pub validator: Option<RuleValidatorFn>,
+    /// 依赖其他字段的校验函数，可读取整个表单的值
+    pub values_validator: Option<FormValuesValidatorFn>,
+    /// 异步校验函数，在同步校验通过后执行
+    pub async_validator: Option<AsyncValidatorFn>,
+    /// 值变化后等待多久（毫秒）再执行异步校验，期间的再次修改会重新计时
+    pub validate_debounce: u32,
+}
+
+impl FormRule {
+    pub fn required(message: &str) -> Self {
+        Self {
+            required: true,
+            message: message.to_string(),
+            ..Default::default()
+        }
+    }
+
+    pub fn min_length(min: usize, message: &str) -> Self {
+        Self {
+            message: message.to_string(),
+            min: Some(min as f64),
+            ..Default::default()
+        }
+    }
+
+    pub fn max_length(max: usize, message: &str) -> Self {
+        Self {
+            message: message.to_string(),
+            max: Some(max as f64),
+            ..Default::default()
+        }
+    }
+
+    /// 正则校验，`"email"` 沿用旧版的含义，等同于 [`FormRule::email`]
+    pub fn pattern(pattern: &str, message: &str) -> Self {
+        if pattern == "email" {
+            return Self::email().message(message);
+        }
+        Self {
+            message: message.to_string(),
+            pattern: Some(RulePattern::new(pattern)),
+            ..Default::default()
+        }
+    }
+
+    pub fn custom<F>(validator: F, message: &str) -> Self
+    where
+        F: Fn(&str) -> Result<(), String> + 'static,
+    {
+        Self {
+            message: message.to_string(),
+            validator: Some(Rc::new(validator)),
+            ..Default::default()
+        }
+    }
+
+    /// 跨字段校验，参数为字段值与整个表单的值，错误信息支持 `${label}` 占位符
+    ///
+    /// 通常与 `FormItem` 的 `dependencies` 配合使用，例如确认密码须与密码一致。
+    pub fn cross_field<F>(validator: F) -> Self
+    where
+        F: Fn(&Value, &Value) -> Result<(), String> + 'static,
+    {
+        Self {
+            values_validator: Some(Rc::new(validator)),
+            ..Default::default()
+        }
+    }
+
+    /// 异步校验，校验失败时返回错误信息，错误信息支持 `${label}` 占位符
+    pub fn async_validator<F, Fut>(validator: F) -> Self
+    where
+        F: Fn(Value) -> Fut + 'static,
+        Fut: Future<Output = Result<(), String>> + 'static,
+    {
+        Self {
+            async_validator: Some(Rc::new(move |value| Box::pin(validator(value)))),
+            ..Default::default()
+        }
+    }
+
+    /// 设置异步校验的防抖时间
+    pub fn validate_debounce(mut self, debounce: u32) -> Self {
+        self.validate_debounce = debounce;
+        self
+    }
+
+    /// 类型校验
+    pub fn typed(rule_type: RuleType) -> Self {
+        Self {
+            rule_type: Some(rule_type),
+            ..Default::default()
+        }
+    }
+
+    /// 邮箱地址
+    pub fn email() -> Self {
+        Self::typed(RuleType::Email)
+    }
+
+    /// URL
+    pub fn url() -> Self {
+        Self::typed(RuleType::Url)
+    }
+
+    /// 整数
+    pub fn integer() -> Self {
+        Self::typed(RuleType::Integer)
+    }
+
+    /// 浮点数
+    pub fn float() -> Self {
+        Self::typed(RuleType::Float)
+    }
+
+    /// 枚举
+    pub fn one_of(values: Vec<Value>) -> Self {
+        Self {
+            rule_type: Some(RuleType::Enum),
+            enum_values: values,
+            ..Default::default()
+        }
+    }
+
+    /// 数值范围
+    pub fn range(min: f64, max: f64) -> Self {
+        Self {
+            rule_type: Some(RuleType::Number),
+            min: Some(min),
+            max: Some(max),
+            ..Default::default()
+        }
+    }
+
+    /// 字符串或数组长度范围
+    pub fn length(min: usize, max: usize) -> Self {
+        Self {
+            min: Some(min as f64),
+            max: Some(max as f64),
+            ..Default::default()
+        }
+    }
+
+    /// 不能只包含空白字符
+    pub fn no_whitespace() -> Self {
+        Self {
+            whitespace: true,
+            ..Default::default()
+        }
+    }
+
+    /// 设置错误信息，支持 `${label}` 等占位符
+    pub fn message(mut self, message: &str) -> Self {
+        self.message = message.to_string();
+        self
+    }
+
+    /// 设置为必填
+    pub fn with_required(mut self) -> Self {
+        self.required = true;
+        self
+    }
+
+    /// 设置值类型
+    pub fn with_type(mut self, rule_type: RuleType) -> Self {
+        self.rule_type = Some(rule_type);
+        self
+    }
+
+    /// 设置长度或数值须等于的值
+    pub fn with_len(mut self, len: f64) -> Self {
+        self.len = Some(len);
+        self
+    }
+
+    /// 设置最小长度或最小数值
+    pub fn with_min(mut self, min: f64) -> Self {
+        self.min = Some(min);
+        self
+    }
+
+    /// 设置最大长度或最大数值
+    pub fn with_max(mut self, max: f64) -> Self {
+        self.max = Some(max);
+        self
+    }
+
+    /// 按规则同步校验字段值（不含异步校验），`label` 用于替换提示中的 `${label}`
+    pub fn check(&self, value: &Value, label: &str, locale: &LocaleConfig) -> Result<(), String> {
+        self.check_with_values(value, &Value::Null, label, locale)
+    }
+
+    /// 同 [`FormRule::check`]，`values` 为整个表单的值，供跨字段校验读取
+    pub fn check_with_values(
+        &self,
+        value: &Value,
+        values: &Value,
+        label: &str,
+        locale: &LocaleConfig,
+    ) -> Result<(), String> {
+        let error = |key: &'static str, args: &[(&str, String)]| {
+            let template = if self.message.is_empty() {
+                locale.translate(key)
+            } else {
+                self.message.clone()
+            };
+            let mut args = args.to_vec();
+            args.push(("label", label.to_string()));
+            Err(format_message(&template, &args))
+        };
+
+        // 空白字符校验
+        if let Value::String(text) = value {
+            if self.whitespace && !text.is_empty() && text.trim().is_empty() {
+                return error("form_validate_whitespace", &[]);
+            }
+        }
+
+        // 必填校验
+        if is_empty_value(value) {
+            return if self.required {
+                error("form_validate_required", &[])
+            } else {
+                Ok(())
+            };
+        }
+
+        let text = value_to_text(value);
+
+        // 类型校验
+        if let Some(rule_type) = self.rule_type {
+            if !self.matches_type(rule_type, value, &text) {
+                return if rule_type == RuleType::Enum {
+                    let values: Vec<String> = self.enum_values.iter().map(value_to_text).collect();
+                    error("form_validate_enum", &[("enum", values.join(", "))])
+                } else {
+                    error(
+                        "form_validate_type",
+                        &[("type", rule_type.name().to_string())],
+                    )
+                };
+            }
+        }
+
+        // 长度与数值范围校验
+        let (kind, size) = match value {
+            Value::Array(items) => ("array", Some(items.len() as f64)),
+            Value::Number(number) => ("number", number.as_f64()),
+            _ if self
+                .rule_type
+                .is_some_and(|rule_type| rule_type.is_numeric()) =>
+            {
+                ("number", text.trim().parse::<f64>().ok())
+            }
+            _ => ("string", Some(text.chars().count() as f64)),
+        };
+        if let Some(size) = size {
+            if let Some(len) = self.len {
+                if size != len {
+                    return error(range_key(kind, "len"), &[("len", format_number(len))]);
+                }
+            }
+            let below = self.min.is_some_and(|min| size < min);
+            let above = self.max.is_some_and(|max| size > max);
+            if below || above {
+                let args = [
+                    ("min", self.min.map(format_number).unwrap_or_default()),
+                    ("max", self.max.map(format_number).unwrap_or_default()),
+                ];
+                let key = match (self.min, self.max) {
+                    (Some(_), Some(_)) => range_key(kind, "range"),
+                    (Some(_), None) => range_key(kind, "min"),
+                    _ => range_key(kind, "max"),
+                };
+                return error(key, &args);
+            }
+        }
+
+        // 正则校验，无效的正则表达式视为校验失败
+        if let Some(pattern) = &self.pattern {
+            let args = [("pattern", pattern.as_str().to_string())];
+            match pattern.regex() {
+                Ok(regex) if !regex.is_match(&text) => {
+                    return error("form_validate_pattern", &args);
+                }
+                Ok(_) => {}
+                Err(_) => return error("form_validate_pattern_invalid", &args),
+            }
+        }
+
+        // 自定义校验
+        if let Some(validator) = &self.validator {
+            validator(&text)?;
+        }
+
+        // 跨字段校验
+        if let Some(validator) = &self.values_validator {
+            validator(value, values)
+                .map_err(|message| format_message(&message, &[("label", label.to_string())]))?;
+        }
+
+        Ok(())
+    }
+
+    fn matches_type(&self, rule_type: RuleType, value: &Value, text: &str) -> bool {
+        let number = match value {
+            Value::Number(number) => number.as_f64(),
+            Value::String(text) => text.trim().parse::<f64>().ok(),
+            _ => None,
+        }
+        .filter(|number| number.is_finite());
+
+        match rule_type {
+            RuleType::String => value.is_string(),
+            RuleType::Number | RuleType::Float => number.is_some(),
+            RuleType::Integer => number.is_some_and(|number| number.fract() == 0.0),
+            RuleType::Boolean => value.is_boolean(),
+            RuleType::Array => value.is_array(),
+            RuleType::Enum => self
+                .enum_values
+                .iter()
+                .any(|item| item == value || value_to_text(item) == text),
+            RuleType::Email => value.is_string() && EMAIL_REGEX.is_match(text),
+            RuleType::Url => value.is_string() && URL_REGEX.is_match(text),
+        }
+    }
+}
+
+impl PartialEq for FormRule {
+    fn eq(&self, other: &Self) -> bool {
+        self.required == other.required
+            && self.message == other.message
+            && self.rule_type == other.rule_type
+            && self.len == other.len
+            && self.min == other.min
+            && self.max == other.max
+            && self.pattern == other.pattern
+            && self.enum_values == other.enum_values
+            && self.whitespace == other.whitespace
+            && rc_option_eq(&self.validator, &other.validator)
+            && rc_option_eq(&self.values_validator, &other.values_validator)
+            && rc_option_eq(&self.async_validator, &other.async_validator)
+            && self.validate_debounce == other.validate_debounce
+    }
+}
+
+/// 长度与范围提示的语言包键
+fn range_key(kind: &str, check: &str) -> &'static str {
+    match (kind, check) {
+        ("array", "len") => "form_validate_array_len",
+        ("array", "min") => "form_validate_array_min",
+        ("array", "max") => "form_validate_array_max",
+        ("array", _) => "form_validate_array_range",
+        ("number", "len") => "form_validate_number_len",
+        ("number", "min") => "form_validate_number_min",
+        ("number", "max") => "form_validate_number_max",
+        ("number", _) => "form_validate_number_range",
+        (_, "len") => "form_validate_string_len",
+        (_, "min") => "form_validate_string_min",
+        (_, "max") => "form_validate_string_max",
+        _ => "form_validate_string_range",
+    }
+}
+
+/// 整数不带小数部分
+fn format_number(number: f64) -> String {
+    if number.fract() == 0.0 && number.abs() < 1e15 {
+        format!("{}", number as i64)
+    } else {
+        number.to_string()
+    }
+}
+
+/// 替换提示模板中的 `${name}` 占位符
+pub fn format_message(template: &str, args: &[(&str, String)]) -> String {
+    args.iter()
+        .fold(template.to_string(), |text, (name, value)| {
+            text.replace(&format!("${{{name}}}"), value)
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::form::FormField;
+    use crate::locale::Locale;
+    use serde_json::json;
+
+    fn check(rule: FormRule, value: Value) -> Result<(), String> {
+        rule.check(&value, "年龄", &LocaleConfig::default())
+    }
+
+    #[test]
+    fn test_form_rule_pattern() {
+        let rule = FormRule::pattern(r"^1[3-9]\d{9}$", "手机号格式不正确");
+        assert!(check(rule.clone(), json!("13800138000")).is_ok());
+        assert_eq!(
+            check(rule.clone(), json!("1380013800a")),
+            Err("手机号格式不正确".to_string())
+        );
+        // 非必填时空值不参与校验
+        assert!(check(rule, json!("")).is_ok());
+        // 无效的正则表达式视为校验失败
+        assert_eq!(
+            check(FormRule::pattern("(", "无效"), json!("a")),
+            Err("无效".to_string())
+        );
+        assert_eq!(
+            check(FormRule::pattern("(", ""), json!("a")),
+            Err("年龄的校验格式(无效".to_string())
+        );
+        // 通过结构体字面量创建的规则同样生效
+        let rule = FormRule {
+            pattern: Some("^a+$".into()),
+            ..Default::default()
+        };
+        assert!(check(rule, json!("aa")).is_ok());
+        assert_eq!(
+            check(FormRule::pattern("^a+$", ""), json!("b")),
+            Err("年龄不符合格式^a+$".to_string())
+        );
+    }
+
+    #[test]
+    fn test_form_rule_types() {
+        assert!(check(FormRule::email(), json!("admin@example.com")).is_ok());
+        assert_eq!(
+            check(FormRule::email(), json!("admin@")),
+            Err("年龄不是一个有效的email".to_string())
+        );
+        assert!(check(FormRule::url(), json!("https://example.com/a?b=1")).is_ok());
+        assert!(check(FormRule::url(), json!("example.com")).is_err());
+        assert!(check(FormRule::integer(), json!(18)).is_ok());
+        assert!(check(FormRule::integer(), json!("18")).is_ok());
+        assert!(check(FormRule::integer(), json!(18.5)).is_err());
+        assert!(check(FormRule::float(), json!("18.5")).is_ok());
+        assert!(check(FormRule::float(), json!("abc")).is_err());
+        assert!(check(FormRule::typed(RuleType::Boolean), json!(true)).is_ok());
+        assert!(check(FormRule::typed(RuleType::Array), json!("a")).is_err());
+
+        let rule = FormRule::one_of(vec![json!("male"), json!("female")]);
+        assert!(check(rule.clone(), json!("male")).is_ok());
+        assert_eq!(
+            check(rule, json!("other")),
+            Err("年龄必须是其中一个[male, female]".to_string())
+        );
+    }
+
+    #[test]
+    fn test_form_rule_ranges() {
+        assert_eq!(
+            check(FormRule::range(18.0, 60.0), json!(16)),
+            Err("年龄须在18-60之间".to_string())
+        );
+        assert!(check(FormRule::range(18.0, 60.0), json!("30")).is_ok());
+        assert_eq!(check(FormRule::integer().with_min(0.5), json!(1)), Ok(()));
+        assert_eq!(
+            check(FormRule::typed(RuleType::Number).with_min(0.5), json!(0.2)),
+            Err("年龄最小值为0.5".to_string())
+        );
+        assert_eq!(
+            check(FormRule::length(2, 4), json!("一二三四五")),
+            Err("年龄须在2-4字符之间".to_string())
+        );
+        assert_eq!(
+            check(FormRule::default().with_len(2.0), json!(["a"])),
+            Err("须为2个年龄".to_string())
+        );
+        assert_eq!(
+            check(FormRule::default().with_max(1.0), json!(["a", "b"])),
+            Err("最多1个年龄".to_string())
+        );
+        assert_eq!(
+            check(FormRule::no_whitespace(), json!("   ")),
+            Err("年龄不能为空字符".to_string())
+        );
+    }
+
+    #[test]
+    fn test_form_rule_locale_messages() {
+        let en = LocaleConfig::new(Locale::En);
+        let rule = FormRule::default().with_required();
+        assert_eq!(
+            rule.check(&Value::Null, "Age", &en),
+            Err("Age is required".to_string())
+        );
+        assert_eq!(check(rule, Value::Null), Err("请输入年龄".to_string()));
+        // 自定义提示同样支持占位符
+        let rule = FormRule::range(1.0, 9.0).message("${label}应在${min}到${max}之间");
+        assert_eq!(check(rule, json!(10)), Err("年龄应在1到9之间".to_string()));
+
+        let mut field = FormField::new("user.age");
+        field.rules = vec![FormRule::default().with_required()];
+        assert!(!field.validate());
+        assert_eq!(field.error, Some("请输入user.age".to_string()));
+        field.label = Some("年龄".to_string());
+        assert!(!field.validate_with(&en));
+        assert_eq!(field.error, Some("年龄 is required".to_string()));
+    }
+
+    #[test]
+    fn test_form_rule_validator_eq() {
+        let rule = FormRule::custom(|_| Ok(()), "自定义");
+        assert!(rule == rule.clone());
+        assert!(rule != FormRule::custom(|_| Ok(()), "自定义"));
+        assert!(rule != FormRule::default().message("自定义"));
+
+        let rule = FormRule::cross_field(|_, _| Ok(()));
+        assert!(rule == rule.clone());
+        assert!(rule != FormRule::cross_field(|_, _| Ok(())));
+
+        let rule = FormRule::async_validator(|_| async { Ok(()) });
+        assert!(rule == rule.clone());
+        assert!(rule != FormRule::default());
+    }
+
+    #[test]
+    fn test_form_rule_cross_field() {
+        let rule = FormRule::cross_field(|value, values| {
+            if values.get("password") == Some(value) {
+                Ok(())
+            } else {
+                Err("${label}与密码不一致".to_string())
+            }
+        });
+        let locale = LocaleConfig::default();
+        let values = json!({ "password": "secret" });
+        assert!(rule
+            .check_with_values(&json!("secret"), &values, "确认密码", &locale)
+            .is_ok());
+        assert_eq!(
+            rule.check_with_values(&json!("other"), &values, "确认密码", &locale),
+            Err("确认密码与密码不一致".to_string())
+        );
+    }
+}
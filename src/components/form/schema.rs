@@ -0,0 +1,742 @@
+//! SchemaForm 根据 JSON Schema 生成表单
+//!
+//! 按属性的 `type`、`format` 与 `enum` 选择控件，将 `required`、`minLength`、`pattern`
+//! 等约束转换为 [`FormRule`]；ui-schema 以属性名为键覆盖控件、标签与布局，如
+//! `{ "ui:order": ["name", "*"], "bio": { "ui:widget": "password", "ui:label_col": 4 } }`。
+
+use super::{
+    flatten_object, set_value_by_path, use_form_item_boundary, use_form_item_control,
+    value_to_text, FinishFailedInfo, Form, FormInstance, FormItem, FormLayout, FormList,
+    FormListRenderArgs, FormPersist, FormRule, RuleType,
+};
+use crate::components::button::{Button, ButtonType};
+use crate::components::checkbox::Checkbox;
+use crate::components::date_picker::{DatePicker, DatePickerMode};
+use crate::components::input::Input;
+use crate::components::input_number::InputNumber;
+use crate::components::radio::{RadioGroup, RadioOption};
+use crate::components::select::{Select, SelectOptionData};
+use crate::components::switch::Switch;
+use crate::config_provider::ValidateTrigger;
+use crate::locale::LocaleConfig;
+use dioxus::prelude::*;
+use serde_json::{Map, Value};
+
+/// 字段使用的控件
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SchemaWidget {
+    /// 输入框，`string` 类型的默认控件
+    Input,
+    /// 密码输入框，`format` 为 `password` 时使用
+    Password,
+    /// 数字输入框，`number` 与 `integer` 类型的默认控件
+    InputNumber,
+    /// 下拉选择，带 `enum` 的属性的默认控件；数组的 `items` 带 `enum` 时为多选
+    Select,
+    /// 单选框组
+    Radio,
+    /// 开关，`boolean` 类型的默认控件
+    Switch,
+    /// 复选框
+    Checkbox,
+    /// 日期选择，`format` 为 `date` 时使用
+    Date,
+    /// 日期时间选择，`format` 为 `date-time` 时使用
+    DateTime,
+    /// 嵌套对象，按属性渲染下级字段
+    Object,
+    /// 动态列表，`array` 类型的默认控件
+    List,
+    /// 不渲染
+    Hidden,
+}
+
+impl SchemaWidget {
+    /// 由 ui-schema 的 `ui:widget` 解析
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "input" | "text" => Some(Self::Input),
+            "password" => Some(Self::Password),
+            "number" | "updown" => Some(Self::InputNumber),
+            "select" => Some(Self::Select),
+            "radio" => Some(Self::Radio),
+            "switch" => Some(Self::Switch),
+            "checkbox" => Some(Self::Checkbox),
+            "date" => Some(Self::Date),
+            "date-time" | "datetime" => Some(Self::DateTime),
+            "hidden" => Some(Self::Hidden),
+            _ => None,
+        }
+    }
+
+    /// 控件绑定字段值的属性
+    fn value_prop_name(&self) -> Option<String> {
+        matches!(self, Self::Switch | Self::Checkbox).then(|| "checked".to_string())
+    }
+}
+
+/// 选择类控件的选项
+#[derive(Debug, Clone, PartialEq)]
+pub struct SchemaOption {
+    /// 选项值，保留 schema 中的原始值，如数字枚举写回表单时仍为数字
+    pub value: Value,
+    /// 选项标签
+    pub label: String,
+}
+
+/// 由 JSON Schema 属性解析出的字段
+#[derive(Clone, PartialEq)]
+pub struct SchemaField {
+    /// 相对于所在对象或列表项的字段路径，列表项为基本类型时为空
+    pub name: String,
+    /// 标签，取 `ui:title` 或 `title`
+    pub label: Option<String>,
+    /// 控件
+    pub widget: SchemaWidget,
+    /// 是否必填，由所在对象的 `required` 决定
+    pub required: bool,
+    /// 由约束转换的校验规则，不含必填规则
+    pub rules: Vec<FormRule>,
+    /// 选择类控件的选项，由 `enum` 或 `oneOf` 生成
+    pub options: Vec<SchemaOption>,
+    /// 是否多选
+    pub multiple: bool,
+    /// 占位文本，取 `ui:placeholder`
+    pub placeholder: Option<String>,
+    /// 额外的提示信息，取 `ui:help` 或 `description`
+    pub extra: Option<String>,
+    /// 是否禁用，取 `ui:disabled` 或 `readOnly`
+    pub disabled: bool,
+    /// 数字输入框的最小值
+    pub min: Option<f64>,
+    /// 数字输入框的最大值
+    pub max: Option<f64>,
+    /// 是否为整数
+    pub integer: bool,
+    /// 标签宽度，取 `ui:label_col`
+    pub label_col: Option<usize>,
+    /// 控件宽度，取 `ui:wrapper_col`
+    pub wrapper_col: Option<usize>,
+    /// 嵌套对象的属性，或列表项的字段
+    pub children: Vec<SchemaField>,
+    /// 列表新增项的默认值
+    pub default_item: Value,
+}
+
+/// 属性的类型，`type` 为数组时取第一个非 `null` 的类型；未设置时按 `properties`、`items` 推断
+fn type_of(schema: &Value) -> &str {
+    match &schema["type"] {
+        Value::String(schema_type) => schema_type,
+        Value::Array(types) => types
+            .iter()
+            .filter_map(Value::as_str)
+            .find(|schema_type| *schema_type != "null")
+            .unwrap_or("string"),
+        _ if schema.get("properties").is_some() => "object",
+        _ if schema.get("items").is_some() => "array",
+        _ => "string",
+    }
+}
+
+/// 由 `enum` 与 `enumNames`，或 `oneOf` 中的 `const` 与 `title` 生成选项，
+/// 选项值为原始的 JSON 值，未设置标签时以值的文本作为标签
+fn schema_options(schema: &Value) -> Vec<SchemaOption> {
+    if let Some(values) = schema["enum"].as_array() {
+        let names = schema["enumNames"].as_array();
+        return values
+            .iter()
+            .enumerate()
+            .map(|(index, value)| SchemaOption {
+                value: value.clone(),
+                label: names
+                    .and_then(|names| names.get(index))
+                    .map(value_to_text)
+                    .unwrap_or_else(|| value_to_text(value)),
+            })
+            .collect();
+    }
+    schema["oneOf"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|option| {
+            let value = option.get("const")?;
+            Some(SchemaOption {
+                value: value.clone(),
+                label: option["title"]
+                    .as_str()
+                    .map_or_else(|| value_to_text(value), str::to_string),
+            })
+        })
+        .collect()
+}
+
+/// 将属性的约束转换为校验规则
+fn schema_rules(schema: &Value, schema_type: &str) -> Vec<FormRule> {
+    let mut rules = Vec::new();
+    let bounds = |min_key: &str, max_key: &str| {
+        let (min, max) = (schema[min_key].as_f64(), schema[max_key].as_f64());
+        (min.is_some() || max.is_some()).then(|| FormRule {
+            min,
+            max,
+            ..Default::default()
+        })
+    };
+
+    match schema_type {
+        "string" => {
+            rules.extend(bounds("minLength", "maxLength"));
+            if let Some(pattern) = schema["pattern"].as_str() {
+                rules.push(FormRule::pattern(pattern, ""));
+            }
+            match schema["format"].as_str() {
+                Some("email") => rules.push(FormRule::email()),
+                Some("uri") | Some("url") => rules.push(FormRule::url()),
+                _ => {}
+            }
+        }
+        "number" | "integer" => {
+            if schema_type == "integer" {
+                rules.push(FormRule::integer());
+            }
+            rules.extend(bounds("minimum", "maximum").map(|rule| rule.with_type(RuleType::Number)));
+        }
+        "array" => rules.extend(bounds("minItems", "maxItems")),
+        _ => {}
+    }
+    if let Some(values) = schema["enum"].as_array() {
+        rules.push(FormRule::one_of(values.clone()));
+    }
+    rules
+}
+
+/// 解析单个属性，`ui_schema` 为该属性对应的 ui-schema
+pub fn parse_schema_field(
+    name: &str,
+    schema: &Value,
+    ui_schema: &Value,
+    required: bool,
+) -> SchemaField {
+    let schema_type = type_of(schema);
+    let items = &schema["items"];
+    let options = match schema_type {
+        "array" => schema_options(items),
+        _ => schema_options(schema),
+    };
+
+    let default_widget = match schema_type {
+        _ if !options.is_empty() => SchemaWidget::Select,
+        "boolean" => SchemaWidget::Switch,
+        "number" | "integer" => SchemaWidget::InputNumber,
+        "object" => SchemaWidget::Object,
+        "array" => SchemaWidget::List,
+        _ => match schema["format"].as_str() {
+            Some("date") => SchemaWidget::Date,
+            Some("date-time") => SchemaWidget::DateTime,
+            Some("password") => SchemaWidget::Password,
+            _ => SchemaWidget::Input,
+        },
+    };
+    let widget = if ui_schema["ui:hidden"].as_bool() == Some(true) {
+        SchemaWidget::Hidden
+    } else {
+        ui_schema["ui:widget"]
+            .as_str()
+            .and_then(SchemaWidget::from_name)
+            .unwrap_or(default_widget)
+    };
+
+    let children = match widget {
+        SchemaWidget::Object => parse_schema(schema, ui_schema),
+        SchemaWidget::List if type_of(items) == "object" => {
+            parse_schema(items, &ui_schema["items"])
+        }
+        SchemaWidget::List => vec![parse_schema_field("", items, &ui_schema["items"], false)],
+        _ => Vec::new(),
+    };
+
+    let text = |value: &Value| value.as_str().map(str::to_string);
+    let col = |value: &Value| value.as_u64().map(|col| col as usize);
+    SchemaField {
+        name: name.to_string(),
+        label: text(&ui_schema["ui:title"]).or_else(|| text(&schema["title"])),
+        widget,
+        required,
+        rules: schema_rules(schema, schema_type),
+        options,
+        multiple: schema_type == "array",
+        placeholder: text(&ui_schema["ui:placeholder"]),
+        extra: text(&ui_schema["ui:help"]).or_else(|| text(&schema["description"])),
+        disabled: ui_schema["ui:disabled"].as_bool().unwrap_or(false)
+            || schema["readOnly"].as_bool().unwrap_or(false),
+        min: schema["minimum"].as_f64(),
+        max: schema["maximum"].as_f64(),
+        integer: schema_type == "integer",
+        label_col: col(&ui_schema["ui:label_col"]),
+        wrapper_col: col(&ui_schema["ui:wrapper_col"]),
+        children,
+        default_item: schema_defaults(items),
+    }
+}
+
+/// 解析 `object` 类型 schema 的各属性
+///
+/// 属性按 `properties` 中的顺序排列，ui-schema 的 `ui:order` 可调整顺序，其中的 `*`
+/// 代表未列出的属性，未包含 `*` 时未列出的属性排在最后。
+pub fn parse_schema(schema: &Value, ui_schema: &Value) -> Vec<SchemaField> {
+    let Some(properties) = schema["properties"].as_object() else {
+        return Vec::new();
+    };
+    let required: Vec<&str> = schema["required"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(Value::as_str)
+        .collect();
+
+    let mut names: Vec<&String> = properties.keys().collect();
+    if let Some(order) = ui_schema["ui:order"].as_array() {
+        let position = |name: &str| order.iter().position(|item| item.as_str() == Some(name));
+        let rest = position("*").unwrap_or(order.len());
+        names.sort_by_key(|name| position(name).unwrap_or(rest));
+    }
+
+    names
+        .into_iter()
+        .map(|name| {
+            parse_schema_field(
+                name,
+                &properties[name],
+                &ui_schema[name.as_str()],
+                required.contains(&name.as_str()),
+            )
+        })
+        .collect()
+}
+
+/// 收集 schema 中的 `default` 作为表单值，`object` 类型返回各属性的默认值组成的对象
+pub fn schema_defaults(schema: &Value) -> Value {
+    if let Some(default) = schema.get("default") {
+        return default.clone();
+    }
+    match schema["properties"].as_object() {
+        Some(properties) => Value::Object(
+            properties
+                .iter()
+                .map(|(name, schema)| (name.clone(), schema_defaults(schema)))
+                .filter(|(_, value)| !value.is_null() && value != &Value::Object(Map::new()))
+                .collect(),
+        ),
+        None => Value::Null,
+    }
+}
+
+/// 拼接字段路径
+fn join_path(parent: &str, name: &str) -> String {
+    match (parent.is_empty(), name.is_empty()) {
+        (true, _) => name.to_string(),
+        (_, true) => parent.to_string(),
+        _ => format!("{parent}.{name}"),
+    }
+}
+
+/// SchemaForm 组件属性
+#[derive(Props, Clone, PartialEq)]
+pub struct SchemaFormProps {
+    /// JSON Schema，根节点为 `object` 类型
+    pub schema: Value,
+    /// ui-schema，以属性名为键覆盖控件（`ui:widget`）、标签（`ui:title`）、占位文本
+    /// （`ui:placeholder`）、提示（`ui:help`）、布局（`ui:label_col`、`ui:wrapper_col`）、
+    /// 顺序（`ui:order`）以及是否禁用、隐藏（`ui:disabled`、`ui:hidden`）
+    #[props(default)]
+    pub ui_schema: Value,
+    /// 通过 [`use_form`](super::use_form) 创建的表单实例
+    pub form: Option<FormInstance>,
+    /// 表单的初始值，覆盖 schema 中的 `default`
+    pub initial_values: Option<Value>,
+    /// 表单布局
+    #[props(default = FormLayout::Horizontal)]
+    pub layout: FormLayout,
+    /// 标签宽度
+    pub label_col: Option<usize>,
+    /// 控件宽度
+    pub wrapper_col: Option<usize>,
+    /// 是否禁用表单
+    #[props(default = false)]
+    pub disabled: bool,
+    /// 字段的校验时机
+    pub validate_trigger: Option<Vec<ValidateTrigger>>,
+    /// 将表单值保存为草稿
+    pub persist: Option<FormPersist>,
+    /// 表单提交事件
+    pub on_finish: Option<EventHandler<Value>>,
+    /// 表单提交失败事件
+    pub on_finish_failed: Option<EventHandler<FinishFailedInfo>>,
+    /// 字段值变化事件
+    pub on_values_change: Option<EventHandler<(String, Value)>>,
+    /// 渲染在字段之后的内容，如提交按钮
+    pub children: Element,
+    /// 自定义样式类名
+    #[props(default = String::new())]
+    pub class: String,
+    /// 自定义样式
+    #[props(default = String::new())]
+    pub style: String,
+}
+
+/// SchemaForm 根据 JSON Schema 生成的表单
+#[component]
+pub fn SchemaForm(props: SchemaFormProps) -> Element {
+    let schema = props.schema.clone();
+    let ui_schema = props.ui_schema.clone();
+    let fields = use_memo(use_reactive!(|schema, ui_schema| parse_schema(
+        &schema, &ui_schema
+    )));
+
+    // schema 中的默认值与 `initial_values` 合并为表单的初始值
+    let mut initial_values = schema_defaults(&props.schema);
+    if let Some(values) = &props.initial_values {
+        for (name, value) in flatten_object(values) {
+            set_value_by_path(&mut initial_values, &name, value);
+        }
+    }
+
+    rsx! {
+        Form {
+            form: props.form,
+            initial_values: initial_values,
+            layout: props.layout,
+            label_col: props.label_col,
+            wrapper_col: props.wrapper_col,
+            disabled: props.disabled,
+            validate_trigger: props.validate_trigger.clone(),
+            persist: props.persist.clone(),
+            on_finish: props.on_finish,
+            on_finish_failed: props.on_finish_failed,
+            on_values_change: props.on_values_change,
+            class: props.class.clone(),
+            style: props.style.clone(),
+
+            for field in fields() {
+                SchemaFieldItem { key: "{field.name}", path: field.name.clone(), field: field }
+            }
+            {props.children}
+        }
+    }
+}
+
+/// 渲染单个字段，`path` 为字段的完整路径
+#[component]
+fn SchemaFieldItem(field: SchemaField, path: String) -> Element {
+    let locale = try_use_context::<Signal<LocaleConfig>>()
+        .map(|locale| locale.read().clone())
+        .unwrap_or_default();
+
+    match field.widget {
+        SchemaWidget::Hidden => rsx! {},
+        SchemaWidget::Object => rsx! {
+            div { class: "ant-form-schema-group",
+                if let Some(label) = &field.label {
+                    div { class: "ant-form-schema-group-title", "{label}" }
+                }
+                for child in field.children {
+                    SchemaFieldItem {
+                        key: "{child.name}",
+                        path: join_path(&path, &child.name),
+                        field: child,
+                    }
+                }
+            }
+        },
+        SchemaWidget::List => {
+            let add_text = locale.translate("form_list_add");
+            let delete_text = locale.translate("delete");
+            let children = field.children.clone();
+            let default_item = field.default_item.clone();
+            let mut rules = field.rules.clone();
+            if field.required {
+                rules.push(FormRule::default().with_required());
+            }
+            rsx! {
+                div { class: "ant-form-schema-group",
+                    if let Some(label) = &field.label {
+                        div { class: "ant-form-schema-group-title", "{label}" }
+                    }
+                    FormList {
+                        name: path,
+                        rules: rules,
+                        render: move |(items, operation, errors): FormListRenderArgs| rsx! {
+                            for item in items {
+                                div { key: "{item.key}", class: "ant-form-schema-list-item",
+                                    div { class: "ant-form-schema-list-item-fields",
+                                        for child in children.clone() {
+                                            SchemaFieldItem {
+                                                key: "{child.name}",
+                                                path: join_path(&item.name, &child.name),
+                                                field: child,
+                                            }
+                                        }
+                                    }
+                                    Button {
+                                        danger: true,
+                                        onclick: move |_| operation.remove(item.index),
+                                        "{delete_text}"
+                                    }
+                                }
+                            }
+                            FormItem {
+                                Button {
+                                    button_type: ButtonType::Dashed,
+                                    block: true,
+                                    onclick: {
+                                        let default_item = default_item.clone();
+                                        move |_| operation.add(default_item.clone(), None)
+                                    },
+                                    "{add_text}"
+                                }
+                                for error in errors {
+                                    div { class: "ant-form-item-explain ant-form-item-explain-error",
+                                        "{error}"
+                                    }
+                                }
+                            }
+                        },
+                    }
+                }
+            }
+        }
+        widget => {
+            let placeholder = field.placeholder.clone();
+            let control = match widget {
+                SchemaWidget::Password => rsx! {
+                    Input { input_type: "password", placeholder, disabled: field.disabled }
+                },
+                SchemaWidget::InputNumber => rsx! {
+                    InputNumber {
+                        placeholder,
+                        min: field.min,
+                        max: field.max,
+                        precision: field.integer.then_some(0),
+                        disabled: field.disabled,
+                    }
+                },
+                SchemaWidget::Select => rsx! {
+                    Select::<Value> {
+                        placeholder,
+                        multiple: field.multiple,
+                        allow_clear: !field.required,
+                        disabled: field.disabled,
+                        options: field
+                            .options
+                            .iter()
+                            .map(|option| {
+                                SelectOptionData::new(option.value.clone(), option.label.clone())
+                            })
+                            .collect::<Vec<_>>(),
+                    }
+                },
+                SchemaWidget::Radio => rsx! {
+                    SchemaRadio { options: field.options.clone(), disabled: field.disabled }
+                },
+                SchemaWidget::Switch => rsx! {
+                    Switch { disabled: field.disabled }
+                },
+                SchemaWidget::Checkbox => rsx! {
+                    Checkbox { disabled: field.disabled }
+                },
+                SchemaWidget::Date | SchemaWidget::DateTime => rsx! {
+                    DatePicker {
+                        placeholder,
+                        mode: if widget == SchemaWidget::DateTime {
+                            DatePickerMode::DateTime
+                        } else {
+                            DatePickerMode::Date
+                        },
+                        disabled: field.disabled,
+                    }
+                },
+                _ => rsx! {
+                    Input { placeholder, disabled: field.disabled }
+                },
+            };
+            rsx! {
+                FormItem {
+                    name: path,
+                    label: field.label.clone(),
+                    rules: field.rules.clone(),
+                    required: field.required,
+                    extra: field.extra.clone(),
+                    label_col: field.label_col,
+                    wrapper_col: field.wrapper_col,
+                    value_prop_name: widget.value_prop_name(),
+                    {control}
+                }
+            }
+        }
+    }
+}
+
+/// 单选组只支持字符串值，按文本匹配选项后写回原始的选项值
+#[component]
+fn SchemaRadio(options: Vec<SchemaOption>, disabled: bool) -> Element {
+    let control = use_form_item_control();
+    // 内部的 RadioGroup 不直接绑定字段，避免以字符串写回
+    use_form_item_boundary();
+    let value = control.map(|control| value_to_text(&control.value()));
+    let disabled = disabled || control.is_some_and(|control| control.is_disabled());
+    let radio_options: Vec<RadioOption> = options
+        .iter()
+        .map(|option| RadioOption {
+            label: option.label.clone(),
+            value: value_to_text(&option.value),
+            disabled: None,
+        })
+        .collect();
+
+    rsx! {
+        RadioGroup {
+            value,
+            disabled,
+            options: radio_options,
+            on_change: move |text: String| {
+                let option = options.iter().find(|option| value_to_text(&option.value) == text);
+                if let (Some(control), Some(option)) = (control, option) {
+                    control.trigger("on_change", option.value.clone());
+                }
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn user_schema() -> Value {
+        json!({
+            "type": "object",
+            "required": ["name", "role"],
+            "properties": {
+                "name": { "type": "string", "title": "姓名", "minLength": 2, "maxLength": 8 },
+                "email": { "type": "string", "format": "email" },
+                "age": { "type": "integer", "minimum": 18 },
+                "role": { "type": "string", "enum": ["admin", "user"], "enumNames": ["管理员", "用户"] },
+                "active": { "type": "boolean", "default": true },
+                "birthday": { "type": "string", "format": "date-time" },
+                "tags": { "type": "array", "items": { "type": "string", "enum": ["a", "b"] } },
+                "address": {
+                    "type": "object",
+                    "properties": {
+                        "city": { "type": "string", "default": "上海" },
+                        "zip": { "type": "string", "pattern": "^\\d{6}$" },
+                    },
+                },
+                "phones": {
+                    "type": "array",
+                    "maxItems": 3,
+                    "items": { "type": "object", "properties": { "number": { "type": "string" } } },
+                },
+            },
+        })
+    }
+
+    #[test]
+    fn test_parse_schema() {
+        let ui_schema = json!({
+            "ui:order": ["role", "*", "email"],
+            "name": { "ui:placeholder": "请输入姓名", "ui:label_col": 4 },
+            "age": { "ui:hidden": true },
+            "address": { "city": { "ui:widget": "radio" } },
+        });
+        let fields = parse_schema(&user_schema(), &ui_schema);
+        let names: Vec<&str> = fields.iter().map(|field| field.name.as_str()).collect();
+        assert_eq!(
+            names,
+            vec!["role", "name", "age", "active", "birthday", "tags", "address", "phones", "email"]
+        );
+
+        let widgets: Vec<SchemaWidget> = fields.iter().map(|field| field.widget).collect();
+        assert_eq!(
+            widgets,
+            vec![
+                SchemaWidget::Select,
+                SchemaWidget::Input,
+                SchemaWidget::Hidden,
+                SchemaWidget::Switch,
+                SchemaWidget::DateTime,
+                SchemaWidget::Select,
+                SchemaWidget::Object,
+                SchemaWidget::List,
+                SchemaWidget::Input,
+            ]
+        );
+
+        let role = &fields[0];
+        assert!(role.required);
+        assert_eq!(
+            role.options[0],
+            SchemaOption {
+                value: json!("admin"),
+                label: "管理员".to_string(),
+            }
+        );
+        assert!(fields[5].multiple);
+
+        // 数字枚举保留原始值，文本只用作标签
+        let schema = json!({
+            "type": "object",
+            "properties": {
+                "level": { "type": "integer", "enum": [1, 2] },
+                "size": { "oneOf": [{ "const": 1.5, "title": "中" }, { "const": null }] },
+            },
+        });
+        let enum_fields = parse_schema(&schema, &json!({}));
+        let option = |value: Value, label: &str| SchemaOption {
+            value,
+            label: label.to_string(),
+        };
+        assert_eq!(
+            enum_fields[0].options,
+            vec![option(json!(1), "1"), option(json!(2), "2")]
+        );
+        assert_eq!(
+            enum_fields[1].options,
+            vec![option(json!(1.5), "中"), option(Value::Null, "")]
+        );
+
+        let name = &fields[1];
+        assert_eq!(name.label.as_deref(), Some("姓名"));
+        assert_eq!(name.placeholder.as_deref(), Some("请输入姓名"));
+        assert_eq!(name.label_col, Some(4));
+        let locale = LocaleConfig::default();
+        let check = |field: &SchemaField, value: Value| {
+            field
+                .rules
+                .iter()
+                .try_for_each(|rule| rule.check(&value, "字段", &locale))
+        };
+        assert!(check(name, json!("a")).is_err());
+        assert!(check(name, json!("Tom")).is_ok());
+        assert!(check(&fields[8], json!("tom")).is_err());
+
+        let address = &fields[6];
+        assert_eq!(address.children[0].widget, SchemaWidget::Radio);
+        assert!(check(&address.children[1], json!("12345")).is_err());
+        assert!(check(&address.children[1], json!("123456")).is_ok());
+
+        let phones = &fields[7];
+        assert_eq!(phones.children[0].name, "number");
+        assert_eq!(phones.default_item, json!({}));
+        assert!(check(phones, json!([1, 2, 3, 4])).is_err());
+    }
+
+    #[test]
+    fn test_schema_defaults() {
+        assert_eq!(
+            schema_defaults(&user_schema()),
+            json!({ "active": true, "address": { "city": "上海" } })
+        );
+        assert_eq!(schema_defaults(&json!({ "type": "string" })), Value::Null);
+    }
+}
@@ -27,6 +27,7 @@
 //! }
 //! ```
 
+use crate::components::form::{merge_form_status, use_form_item_control, value_to_text};
 use dioxus::prelude::*;
 
 const INPUT_STYLE: &str = include_str!("./style.css");
@@ -184,17 +185,33 @@ pub struct InputProps {
 /// 通过鼠标或键盘输入内容，是最基础的表单域的包装
 #[component]
 pub fn Input(props: InputProps) -> Element {
+    let form_item = use_form_item_control();
     let mut internal_value = use_signal(|| props.value.clone());
     let mut is_focused = use_signal(|| false);
 
-    // 同步外部 value 到内部状态
-    use_effect(move || {
-        internal_value.set(props.value.clone());
-    });
+    // 同步外部 value 到内部状态，位于 FormItem 内时使用字段值
+    use_effect(
+        move || match form_item.and_then(|item| item.value_for("value", "value")) {
+            Some(value) => internal_value.set(value_to_text(&value)),
+            None => internal_value.set(props.value.clone()),
+        },
+    );
+
+    let disabled = props.disabled || form_item.is_some_and(|item| item.is_disabled());
+    let status = merge_form_status(
+        form_item,
+        props.status,
+        InputStatus::Normal,
+        InputStatus::Error,
+        InputStatus::Warning,
+    );
 
     let handle_input = move |evt: FormEvent| {
         let value = evt.value();
         internal_value.set(value.clone());
+        if let Some(item) = form_item {
+            item.trigger("on_change", value.clone());
+        }
         if let Some(on_change) = &props.on_change {
             on_change.call(value);
         }
@@ -217,6 +234,9 @@ pub fn Input(props: InputProps) -> Element {
 
     let handle_blur = move |evt: FocusEvent| {
         is_focused.set(false);
+        if let Some(item) = form_item {
+            item.trigger("on_blur", internal_value());
+        }
         if let Some(on_blur) = &props.on_blur {
             on_blur.call(evt);
         }
@@ -224,6 +244,9 @@ pub fn Input(props: InputProps) -> Element {
 
     let handle_clear = move |_| {
         internal_value.set(String::new());
+        if let Some(item) = form_item {
+            item.trigger("on_change", "");
+        }
         if let Some(on_change) = &props.on_change {
             on_change.call(String::new());
         }
@@ -240,12 +263,12 @@ pub fn Input(props: InputProps) -> Element {
             classes.push(size_class);
         }
 
-        let status_class = props.status.to_class();
+        let status_class = status.to_class();
         if !status_class.is_empty() {
             classes.push(status_class);
         }
 
-        if props.disabled {
+        if disabled {
             classes.push("ant-input-disabled");
         }
 
@@ -271,12 +294,12 @@ pub fn Input(props: InputProps) -> Element {
                 classes.push(size_class);
             }
 
-            let status_class = props.status.to_class();
+            let status_class = status.to_class();
             if !status_class.is_empty() {
                 classes.push(status_class);
             }
 
-            if props.disabled {
+            if disabled {
                 classes.push("ant-input-affix-wrapper-disabled");
             }
 
@@ -350,7 +373,7 @@ pub fn Input(props: InputProps) -> Element {
                                 r#type: props.input_type,
                                 value: internal_value(),
                                 placeholder: props.placeholder,
-                                disabled: props.disabled,
+                                disabled: disabled,
                                 readonly: props.readonly,
                                 maxlength: if max_length != usize::MAX { max_length.to_string() } else { String::new() },
                                 oninput: handle_input,
@@ -359,7 +382,7 @@ pub fn Input(props: InputProps) -> Element {
                                 onblur: handle_blur
                             }
 
-                            if props.allow_clear && !internal_value().is_empty() && !props.disabled {
+                            if props.allow_clear && !internal_value().is_empty() && !disabled {
                                 span {
                                     class: "ant-input-clear-icon",
                                     onclick: handle_clear,
@@ -380,7 +403,7 @@ pub fn Input(props: InputProps) -> Element {
                             r#type: props.input_type,
                             value: internal_value(),
                             placeholder: props.placeholder,
-                            disabled: props.disabled,
+                            disabled: disabled,
                             readonly: props.readonly,
                             maxlength: if max_length != usize::MAX { max_length.to_string() } else { String::new() },
                             oninput: handle_input,
@@ -436,7 +459,7 @@ pub fn Input(props: InputProps) -> Element {
                         r#type: props.input_type,
                         value: internal_value(),
                         placeholder: props.placeholder,
-                        disabled: props.disabled,
+                        disabled: disabled,
                         readonly: props.readonly,
                         maxlength: if max_length != usize::MAX { max_length.to_string() } else { String::new() },
                         oninput: handle_input,
@@ -445,7 +468,7 @@ pub fn Input(props: InputProps) -> Element {
                         onblur: handle_blur
                     }
 
-                    if props.allow_clear && !internal_value().is_empty() && !props.disabled {
+                    if props.allow_clear && !internal_value().is_empty() && !disabled {
                         span {
                             class: "ant-input-clear-icon",
                             onclick: handle_clear,
@@ -489,7 +512,7 @@ pub fn Input(props: InputProps) -> Element {
                     r#type: props.input_type,
                     value: internal_value(),
                     placeholder: props.placeholder,
-                    disabled: props.disabled,
+                    disabled: disabled,
                     readonly: props.readonly,
                     maxlength: if max_length != usize::MAX { max_length.to_string() } else { String::new() },
                     oninput: handle_input,
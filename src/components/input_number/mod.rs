@@ -27,6 +27,7 @@
 //! }
 //! ```
 
+use crate::components::form::{merge_form_status, use_form_item_control};
 use dioxus::prelude::*;
 use std::fmt;
 
@@ -215,9 +216,40 @@ pub fn InputNumber(props: InputNumberProps) -> Element {
     });
     let mut focused = use_signal(|| false);
 
+    // 位于 FormItem 内时使用字段值，字段值与当前值不同时更新输入框
+    let form_item = use_form_item_control();
+    let precision = props.precision;
+    let formatter = props.formatter;
+    use_effect(move || {
+        if let Some(value) = form_item.and_then(|item| item.value_for("value", "value")) {
+            let value = value.as_f64();
+            if value != *current_value.peek() {
+                current_value.set(value);
+                input_value.set(
+                    value
+                        .map(|value| format_number(value, precision, formatter.as_ref()))
+                        .unwrap_or_default(),
+                );
+            }
+        }
+    });
+    let disabled = props.disabled || form_item.is_some_and(|item| item.is_disabled());
+    let status = merge_form_status(
+        form_item,
+        props.status.clone(),
+        InputNumberStatus::Default,
+        InputNumberStatus::Error,
+        InputNumberStatus::Warning,
+    );
+    let trigger_form = move |event: &str, value: Option<f64>| {
+        if let Some(item) = form_item {
+            item.trigger(event, value);
+        }
+    };
+
     let class_name = format!(
         "ant-input-number {} {} {} {} {} {}",
-        if props.disabled {
+        if disabled {
             "ant-input-number-disabled"
         } else {
             ""
@@ -238,8 +270,8 @@ pub fn InputNumber(props: InputNumberProps) -> Element {
             ""
         },
         format!("ant-input-number-{}", props.size),
-        if props.status != InputNumberStatus::Default {
-            format!("ant-input-number-status-{}", props.status)
+        if status != InputNumberStatus::Default {
+            format!("ant-input-number-status-{}", status)
         } else {
             String::new()
         },
@@ -260,12 +292,14 @@ pub fn InputNumber(props: InputNumberProps) -> Element {
         if let Ok(parsed_value) = input_val.parse::<f64>() {
             let validated_value = validate_value(parsed_value, props.min, props.max);
             current_value.set(Some(validated_value));
+            trigger_form("on_change", Some(validated_value));
 
             if let Some(handler) = &props.on_change {
                 handler.call(Some(validated_value));
             }
         } else if input_val.is_empty() {
             current_value.set(None);
+            trigger_form("on_change", None);
             if let Some(handler) = &props.on_change {
                 handler.call(None);
             }
@@ -288,6 +322,7 @@ pub fn InputNumber(props: InputNumberProps) -> Element {
             props.precision,
             props.formatter.as_ref(),
         ));
+        trigger_form("on_change", Some(validated_value));
 
         if let Some(handler) = &props.on_change {
             handler.call(Some(validated_value));
@@ -305,13 +340,13 @@ pub fn InputNumber(props: InputNumberProps) -> Element {
     };
 
     let handle_up_click = move |_| {
-        if !props.disabled {
+        if !disabled {
             handle_step(StepType::Up);
         }
     };
 
     let handle_down_click = move |_| {
-        if !props.disabled {
+        if !disabled {
             handle_step(StepType::Down);
         }
     };
@@ -335,6 +370,8 @@ pub fn InputNumber(props: InputNumberProps) -> Element {
             ));
         }
 
+        trigger_form("on_blur", current_value());
+
         if let Some(handler) = &props.on_blur {
             handler.call(e);
         }
@@ -348,13 +385,13 @@ pub fn InputNumber(props: InputNumberProps) -> Element {
         match e.key() {
             Key::ArrowUp => {
                 e.prevent_default();
-                if !props.disabled {
+                if !disabled {
                     handle_step(StepType::Up);
                 }
             }
             Key::ArrowDown => {
                 e.prevent_default();
-                if !props.disabled {
+                if !disabled {
                     handle_step(StepType::Down);
                 }
             }
@@ -392,7 +429,7 @@ pub fn InputNumber(props: InputNumberProps) -> Element {
                     r#type: "text",
                     placeholder: props.placeholder.as_deref().unwrap_or(""),
                     value: input_value(),
-                    disabled: props.disabled,
+                    disabled: disabled,
                     readonly: props.readonly,
                     autofocus: props.auto_focus,
                     oninput: handle_input_change,
@@ -416,7 +453,7 @@ pub fn InputNumber(props: InputNumberProps) -> Element {
                     span {
                         class: format!(
                             "ant-input-number-handler ant-input-number-handler-up {}",
-                            if !can_up || props.disabled { "ant-input-number-handler-up-disabled" } else { "" }
+                            if !can_up || disabled { "ant-input-number-handler-up-disabled" } else { "" }
                         ),
                         onclick: handle_up_click,
 
@@ -429,7 +466,7 @@ pub fn InputNumber(props: InputNumberProps) -> Element {
                     span {
                         class: format!(
                             "ant-input-number-handler ant-input-number-handler-down {}",
-                            if !can_down || props.disabled { "ant-input-number-handler-down-disabled" } else { "" }
+                            if !can_down || disabled { "ant-input-number-handler-down-disabled" } else { "" }
                         ),
                         onclick: handle_down_click,
 
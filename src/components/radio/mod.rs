@@ -7,6 +7,7 @@
 //! - 用于在多个备选项中选中单个状态。
 //! - 和 Select 的区别是，Radio 所有选项默认可见，方便用户在比较中选择，因此选项不宜过多。
 
+use crate::components::form::{use_form_item_boundary, use_form_item_control, value_to_text};
 use dioxus::prelude::*;
 
 const RADIO_STYLE: &str = include_str!("./style.css");
@@ -50,19 +51,24 @@ pub struct RadioProps {
 
 #[component]
 pub fn Radio(props: RadioProps) -> Element {
+    let form_item = use_form_item_control();
     let mut checked = use_signal(|| props.default_checked);
 
-    // 如果传入了 checked 属性，使用受控模式
-    let is_checked = if props.checked != props.default_checked {
-        props.checked
-    } else {
-        checked.read().clone()
-    };
+    // 如果传入了 checked 属性，使用受控模式；位于 FormItem 内时使用字段值
+    let is_checked =
+        if let Some(value) = form_item.and_then(|item| item.value_for("checked", "checked")) {
+            value.as_bool().unwrap_or_default()
+        } else if props.checked != props.default_checked {
+            props.checked
+        } else {
+            checked.read().clone()
+        };
+    let disabled = props.disabled || form_item.is_some_and(|item| item.is_disabled());
 
     let class_name = {
         let mut classes = vec!["ant-radio-wrapper"];
 
-        if props.disabled {
+        if disabled {
             classes.push("ant-radio-wrapper-disabled");
         }
 
@@ -78,12 +84,15 @@ pub fn Radio(props: RadioProps) -> Element {
     };
 
     let handle_change = move |_| {
-        if props.disabled {
+        if disabled {
             return;
         }
 
         let new_checked = !is_checked;
         checked.set(new_checked);
+        if let Some(item) = form_item {
+            item.trigger("on_change", new_checked);
+        }
 
         if let Some(on_change) = &props.on_change {
             on_change.call(new_checked);
@@ -102,7 +111,7 @@ pub fn Radio(props: RadioProps) -> Element {
                     r#type: "radio",
                     class: "ant-radio-input",
                     checked: is_checked,
-                    disabled: props.disabled,
+                    disabled: disabled,
                     value: props.value.as_deref().unwrap_or(""),
                 }
                 span {
@@ -153,14 +162,20 @@ pub struct RadioOption {
 
 #[component]
 pub fn RadioGroup(props: RadioGroupProps) -> Element {
+    let form_item = use_form_item_control();
+    use_form_item_boundary();
     let mut selected_value = use_signal(|| props.default_value.clone().unwrap_or_default());
 
-    // 如果传入了 value 属性，使用受控模式
-    let current_value = if let Some(ref value) = props.value {
-        value.clone()
-    } else {
-        selected_value.read().clone()
-    };
+    // 如果传入了 value 属性，使用受控模式；位于 FormItem 内时使用字段值
+    let current_value =
+        if let Some(value) = form_item.and_then(|item| item.value_for("value", "value")) {
+            value_to_text(&value)
+        } else if let Some(ref value) = props.value {
+            value.clone()
+        } else {
+            selected_value.read().clone()
+        };
+    let disabled = props.disabled || form_item.is_some_and(|item| item.is_disabled());
 
     let class_name = {
         let mut classes = vec!["ant-radio-group"];
@@ -180,6 +195,9 @@ pub fn RadioGroup(props: RadioGroupProps) -> Element {
 
     let handle_change = move |value: String| {
         selected_value.set(value.clone());
+        if let Some(item) = form_item {
+            item.trigger("on_change", value.clone());
+        }
 
         if let Some(on_change) = &props.on_change {
             on_change.call(value);
@@ -200,7 +218,7 @@ pub fn RadioGroup(props: RadioGroupProps) -> Element {
                         label: option.label.clone(),
                         value: option.value.clone(),
                         checked: current_value == option.value,
-                        disabled: props.disabled || option.disabled.unwrap_or(false),
+                        disabled: disabled || option.disabled.unwrap_or(false),
                         name: props.name.clone(),
                         on_change: handle_change.clone(),
                     }
@@ -25,6 +25,7 @@
 //! }
 //! ```
 
+use crate::components::form::use_form_item_control;
 use dioxus::prelude::*;
 use std::fmt;
 
@@ -137,19 +138,29 @@ pub struct RateProps {
 /// Rate 组件
 #[component]
 pub fn Rate(props: RateProps) -> Element {
+    let form_item = use_form_item_control();
     let mut current_value = use_signal(|| props.value.unwrap_or(props.default_value));
     let mut hover_value = use_signal(|| None::<f64>);
     let mut focused = use_signal(|| false);
 
+    // 位于 FormItem 内时使用字段值
+    use_effect(move || {
+        if let Some(value) = form_item.and_then(|item| item.value_for("value", "value")) {
+            current_value.set(value.as_f64().unwrap_or_default());
+        }
+    });
+    let disabled = props.disabled || form_item.is_some_and(|item| item.is_disabled());
+    let trigger_form = move |event: &str, value: f64| {
+        if let Some(item) = form_item {
+            item.trigger(event, value);
+        }
+    };
+
     let display_value = hover_value().unwrap_or(current_value());
 
     let class_name = format!(
         "ant-rate {} {} {} {}",
-        if props.disabled {
-            "ant-rate-disabled"
-        } else {
-            ""
-        },
+        if disabled { "ant-rate-disabled" } else { "" },
         if props.readonly {
             "ant-rate-readonly"
         } else {
@@ -168,7 +179,7 @@ pub fn Rate(props: RateProps) -> Element {
     };
 
     let mut handle_star_click = move |index: usize, is_half: bool| {
-        if props.disabled || props.readonly {
+        if disabled || props.readonly {
             return;
         }
 
@@ -187,13 +198,15 @@ pub fn Rate(props: RateProps) -> Element {
 
         current_value.set(final_value);
 
+        trigger_form("on_change", final_value);
+
         if let Some(handler) = &props.on_change {
             handler.call(final_value);
         }
     };
 
     let mut handle_star_hover = move |index: usize, is_half: bool| {
-        if props.disabled {
+        if disabled {
             return;
         }
 
@@ -227,13 +240,14 @@ pub fn Rate(props: RateProps) -> Element {
 
     let handle_blur = move |e: FocusEvent| {
         focused.set(false);
+        trigger_form("on_blur", current_value());
         if let Some(handler) = &props.on_blur {
             handler.call(e);
         }
     };
 
     let handle_key_down = move |e: KeyboardEvent| {
-        if props.disabled || props.readonly {
+        if disabled || props.readonly {
             return;
         }
 
@@ -243,6 +257,7 @@ pub fn Rate(props: RateProps) -> Element {
                 let new_value = (current_value() + if props.allow_half { 0.5 } else { 1.0 })
                     .min(props.count as f64);
                 current_value.set(new_value);
+                trigger_form("on_change", new_value);
 
                 if let Some(handler) = &props.on_change {
                     handler.call(new_value);
@@ -253,6 +268,7 @@ pub fn Rate(props: RateProps) -> Element {
                 let new_value =
                     (current_value() - if props.allow_half { 0.5 } else { 1.0 }).max(0.0);
                 current_value.set(new_value);
+                trigger_form("on_change", new_value);
 
                 if let Some(handler) = &props.on_change {
                     handler.call(new_value);
@@ -261,6 +277,7 @@ pub fn Rate(props: RateProps) -> Element {
             Key::Home => {
                 e.prevent_default();
                 current_value.set(0.0);
+                trigger_form("on_change", 0.0);
 
                 if let Some(handler) = &props.on_change {
                     handler.call(0.0);
@@ -270,6 +287,7 @@ pub fn Rate(props: RateProps) -> Element {
                 e.prevent_default();
                 let new_value = props.count as f64;
                 current_value.set(new_value);
+                trigger_form("on_change", new_value);
 
                 if let Some(handler) = &props.on_change {
                     handler.call(new_value);
@@ -301,7 +319,7 @@ pub fn Rate(props: RateProps) -> Element {
         div {
             class: class_name,
             style: props.style.as_deref().unwrap_or(""),
-            tabindex: if props.disabled { "-1" } else { "0" },
+            tabindex: if disabled { "-1" } else { "0" },
             autofocus: props.auto_focus,
             onmouseleave: handle_mouse_leave,
             onfocus: handle_focus,
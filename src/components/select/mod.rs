@@ -11,11 +11,11 @@
 //!
 //! ```rust
 //! use dioxus::prelude::*;
-//! use ant_design_dioxus::{Select, SelectOption, SelectSize};
+//! use ant_design_dioxus::prelude::*;
 //!
 //! #[component]
 //! fn App() -> Element {
-//!     let mut value = use_signal(String::new);
+//!     let mut value = use_signal(|| None::<String>);
 //!
 //!     rsx! {
 //!         Select {
@@ -35,11 +35,76 @@
 //!     }
 //! }
 //! ```
+//!
+//! 选项较多时使用 `options` 传入选项数据，下拉菜单会按 `list_height` 和
+//! `list_item_height` 进行虚拟滚动，只渲染可视区域内的选项：
+//!
+//! ```rust
+//! use dioxus::prelude::*;
+//! use ant_design_dioxus::prelude::*;
+//!
+//! #[component]
+//! fn App() -> Element {
+//!     let options = (0..10000)
+//!         .map(|i| OptionData::new(format!("{i}"), format!("选项 {i}")))
+//!         .collect::<Vec<_>>();
+//!
+//!     rsx! {
+//!         Select {
+//!             options,
+//!             show_search: true,
+//!             placeholder: "请选择",
+//!         }
+//!     }
+//! }
+//! ```
+//!
+//! 选项值可以是任意可序列化的类型，`options` 不为空的选项作为分组：
+//!
+//! ```rust
+//! use dioxus::prelude::*;
+//! use ant_design_dioxus::prelude::*;
+//!
+//! #[component]
+//! fn App() -> Element {
+//!     let mut city = use_signal(|| None::<u32>);
+//!
+//!     rsx! {
+//!         Select {
+//!             value: city(),
+//!             options: vec![
+//!                 SelectOptionData::group("华北", vec![SelectOptionData::new(1_u32, "北京")]),
+//!                 SelectOptionData::group("华东", vec![SelectOptionData::new(2_u32, "上海")]),
+//!             ],
+//!             on_change: move |v| city.set(v),
+//!         }
+//!     }
+//! }
+//! ```
+
+mod option;
+mod remote;
 
+pub use option::*;
+pub use remote::*;
+
+use crate::components::form::{merge_form_status, use_form_item_control};
+use crate::components::spin::{Spin, SpinSize};
+use crate::components::table::{virtual_range, VirtualRange};
+use crate::locale::LocaleConfig;
+use crate::utils::highlight_segments;
 use dioxus::prelude::*;
+use serde_json::Value;
+use std::rc::Rc;
+use std::sync::atomic::{AtomicUsize, Ordering};
 
 const SELECT_STYLES: &str = include_str!("./style.css");
 
+/// 虚拟滚动时可视区域上下额外渲染的选项数
+const LIST_OVERSCAN: usize = 4;
+
+static SELECT_ID: AtomicUsize = AtomicUsize::new(0);
+
 /// Select 组件尺寸
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum SelectSize {
@@ -96,27 +161,88 @@ impl SelectStatus {
     }
 }
 
-/// SelectOption 选项数据
-#[derive(Debug, Clone, PartialEq)]
-pub struct OptionData {
-    /// 选项值
-    pub value: String,
-    /// 选项标签
+/// Select 模式
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum SelectMode {
+    /// 单选（默认）
+    #[default]
+    Single,
+    /// 多选
+    Multiple,
+    /// 标签模式，可以输入新的值
+    Tags,
+}
+
+/// 多选时最多显示的标签数
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MaxTagCount {
+    /// 固定数量
+    Count(usize),
+    /// 按选择框宽度自适应
+    Responsive,
+}
+
+/// 自定义标签渲染的参数
+#[derive(Clone, PartialEq)]
+pub struct SelectTagRenderArgs<T: SelectValue> {
+    /// 标签对应的值
+    pub value: T,
+    /// 标签文字，已按 `max_tag_text_length` 截断
     pub label: String,
     /// 是否禁用
     pub disabled: bool,
+    /// 是否可关闭
+    pub closable: bool,
+    /// 关闭标签，即取消选中该值
+    pub on_close: Callback<()>,
+}
+
+/// 自适应标签数时 "+N" 标签预留的宽度
+const REST_TAG_WIDTH: f64 = 56.0;
+
+/// 按容器宽度计算能完整显示的标签数，放不下全部标签时为 "+N" 标签预留空间
+pub(crate) fn responsive_tag_count(widths: &[f64], available: f64) -> usize {
+    if widths.iter().sum::<f64>() <= available {
+        return widths.len();
+    }
+    let mut used = 0.0;
+    widths
+        .iter()
+        .take_while(|width| {
+            used += **width;
+            used <= available - REST_TAG_WIDTH
+        })
+        .count()
+}
+
+/// 使第 `index` 项完整可见所需的滚动位置，已可见时返回 None
+pub(crate) fn scroll_top_for_index(
+    index: usize,
+    item_height: f64,
+    list_height: f64,
+    scroll_top: f64,
+) -> Option<f64> {
+    let top = index as f64 * item_height;
+    let bottom = top + item_height;
+    if top < scroll_top {
+        Some(top)
+    } else if bottom > scroll_top + list_height {
+        Some((bottom - list_height).max(0.0))
+    } else {
+        None
+    }
 }
 
 /// Select 组件属性
 #[derive(Props, Clone, PartialEq)]
-pub struct SelectProps {
-    /// 当前选中的值
+pub struct SelectProps<T: SelectValue> {
+    /// 当前选中的值，为 `None` 时表示未选择
     #[props(default)]
-    pub value: String,
+    pub value: Option<T>,
 
     /// 多选时的值
     #[props(default)]
-    pub values: Vec<String>,
+    pub values: Vec<T>,
 
     /// 选择框默认文字
     #[props(default)]
@@ -138,6 +264,26 @@ pub struct SelectProps {
     #[props(default = false)]
     pub multiple: bool,
 
+    /// 选择模式，`Multiple` 与 `multiple: true` 相同，`Tags` 时可以输入新的值
+    #[props(default)]
+    pub mode: SelectMode,
+
+    /// 自动分词的分隔符，多选和标签模式下输入或粘贴包含分隔符的内容时拆分为多个值
+    #[props(default)]
+    pub token_separators: Vec<String>,
+
+    /// 最多显示多少个标签，超出部分显示为 "+N"
+    #[props(default)]
+    pub max_tag_count: Option<MaxTagCount>,
+
+    /// 标签文字的最大长度，超出部分显示省略号
+    #[props(default)]
+    pub max_tag_text_length: Option<usize>,
+
+    /// 自定义多选标签的渲染
+    #[props(default)]
+    pub tag_render: Option<Callback<SelectTagRenderArgs<T>, Element>>,
+
     /// 是否支持清除
     #[props(default = false)]
     pub allow_clear: bool,
@@ -166,6 +312,47 @@ pub struct SelectProps {
     #[props(default = 256)]
     pub list_height: u32,
 
+    /// 数据化配置选项内容，设置后忽略 SelectOption 子元素
+    #[props(default)]
+    pub options: Vec<SelectOptionData<T>>,
+
+    /// JSON 格式的选项数据，按 `field_names` 读取，追加在 `options` 之后
+    #[props(default)]
+    pub raw_options: Vec<Value>,
+
+    /// 自定义 `raw_options` 中标签、值、分组和禁用的字段名
+    #[props(default)]
+    pub field_names: SelectFieldNames,
+
+    /// 自定义下拉菜单中选项的渲染
+    #[props(default)]
+    pub option_render: Option<Callback<SelectOptionData<T>, Element>>,
+
+    /// 自定义选择框中已选项的渲染
+    #[props(default)]
+    pub label_render: Option<Callback<SelectOptionData<T>, Element>>,
+
+    /// 是否把选项的标签包装到值中，开启后表单字段值为 `{ value, label }`，
+    /// 并触发 `on_labeled_change`
+    #[props(default = false)]
+    pub label_in_value: bool,
+
+    /// 远程搜索，搜索内容变化时防抖调用异步加载函数获取选项，设置后不再在本地筛选
+    #[props(default)]
+    pub load_options: Option<LoadOptions<SelectOptionData<T>>>,
+
+    /// 下拉列表为空时显示的内容，默认为“暂无数据”
+    #[props(default)]
+    pub not_found_content: Option<Element>,
+
+    /// 是否对 `options` 启用虚拟滚动
+    #[props(default = true)]
+    pub virtual_list: bool,
+
+    /// 虚拟滚动时每个选项的高度
+    #[props(default = 32)]
+    pub list_item_height: u32,
+
     /// 自定义样式类名
     #[props(default)]
     pub class: Option<String>,
@@ -174,13 +361,17 @@ pub struct SelectProps {
     #[props(default)]
     pub style: Option<String>,
 
-    /// 选中选项时调用
+    /// 选中选项时调用，清除时参数为 `None`
     #[props(default)]
-    pub on_change: Option<EventHandler<String>>,
+    pub on_change: Option<EventHandler<Option<T>>>,
 
     /// 多选时的回调
     #[props(default)]
-    pub on_change_multiple: Option<EventHandler<Vec<String>>>,
+    pub on_change_multiple: Option<EventHandler<Vec<T>>>,
+
+    /// `label_in_value` 时选中项变化的回调，单选时最多一项
+    #[props(default)]
+    pub on_labeled_change: Option<EventHandler<Vec<LabeledValue<T>>>>,
 
     /// 搜索时的回调
     #[props(default)]
@@ -210,87 +401,357 @@ pub struct SelectProps {
 ///
 /// 下拉选择器
 #[component]
-pub fn Select(props: SelectProps) -> Element {
+pub fn Select<T: SelectValue>(props: SelectProps<T>) -> Element {
     let mut is_open = use_signal(|| false);
     let mut search_value = use_signal(|| String::new());
     let mut is_focused = use_signal(|| false);
+    let mut active_index = use_signal(|| None::<usize>);
+    let mut list_ref = use_signal(|| None::<Rc<MountedData>>);
+    let mut scroll_top = use_signal(|| 0.0_f64);
+    let mut responsive_count = use_signal(|| None::<usize>);
+    let select_id = use_hook(|| SELECT_ID.fetch_add(1, Ordering::Relaxed));
+    let form_item = use_form_item_control();
+    let empty_text = try_use_context::<Signal<LocaleConfig>>()
+        .map(|locale| locale.read().translate("empty"))
+        .unwrap_or_else(|| "暂无数据".to_string());
+    let tags = props.mode == SelectMode::Tags;
+    let multiple = props.multiple || props.mode != SelectMode::Single;
+    let remote = use_remote_options::<SelectOptionData<T>>();
+    let mut loader = use_hook(|| CopyValue::new(None::<LoadOptions<SelectOptionData<T>>>));
+    loader.set(props.load_options.clone());
+    let is_remote = props.load_options.is_some();
+    let show_search = props.show_search || tags || is_remote;
+    // 远程搜索时选中过的选项，查询结果变化后仍能显示其标签
+    let mut known_options = use_signal(Vec::<SelectOptionData<T>>::new);
     let mut selected_values = use_signal(|| {
-        if props.multiple {
+        if multiple {
             props.values.clone()
-        } else if !props.value.is_empty() {
-            vec![props.value.clone()]
         } else {
-            vec![]
+            props.value.clone().into_iter().collect()
         }
     });
 
-    // 同步外部值到内部状态
-    use_effect(move || {
-        if props.multiple {
-            selected_values.set(props.values.clone());
-        } else if !props.value.is_empty() {
-            selected_values.set(vec![props.value.clone()]);
+    // 同步外部值到内部状态，位于 FormItem 内时使用字段值
+    let value = props.value.clone();
+    let values = props.values.clone();
+    use_effect(use_reactive!(|value, values| {
+        if let Some(field_value) = form_item.and_then(|item| item.value_for("value", "value")) {
+            selected_values.set(match field_value {
+                Value::Array(items) => items.iter().filter_map(value_from_json).collect(),
+                Value::Null => vec![],
+                field_value => value_from_json(&field_value).into_iter().collect(),
+            });
+        } else if multiple {
+            selected_values.set(values);
         } else {
-            selected_values.set(vec![]);
+            selected_values.set(value.into_iter().collect());
         }
-    });
+    }));
+
+    let disabled = props.disabled || form_item.is_some_and(|item| item.is_disabled());
+    let status = merge_form_status(
+        form_item,
+        props.status,
+        SelectStatus::Normal,
+        SelectStatus::Error,
+        SelectStatus::Warning,
+    );
+
+    // 合并类型化选项与按字段映射解析的 JSON 选项
+    let options = props.options.clone();
+    let raw_options = props.raw_options.clone();
+    let field_names = props.field_names.clone();
+    let all_options = use_memo(use_reactive!(|options, raw_options, field_names| {
+        let mut options = options;
+        options.extend(
+            raw_options
+                .iter()
+                .filter_map(|data| SelectOptionData::from_json(data, &field_names)),
+        );
+        options.extend(remote.options.read().iter().cloned());
+        options
+    }));
+
+    // 选中值对应的选项，不在选项中时以值的文本作为标签
+    let option_of = move |value: &T| {
+        find_option(&all_options.read(), value)
+            .cloned()
+            .or_else(|| find_option(&known_options.read(), value).cloned())
+            .unwrap_or_else(|| SelectOptionData::new(value.clone(), value_text(value)))
+    };
 
-    let handle_click = move |_| {
-        if !props.disabled {
-            is_open.set(!is_open());
-            if let Some(on_dropdown_visible_change) = &props.on_dropdown_visible_change {
-                on_dropdown_visible_change.call(!is_open());
+    // 发起远程搜索
+    let search_remote = move |query: String| {
+        if let Some(loader) = loader.read().as_ref() {
+            remote.search(loader, query);
+        }
+    };
+
+    // 位于 FormItem 内时将选中值写回表单，多选时为数组
+    let label_in_value = props.label_in_value;
+    let trigger_form = move |event: &str, values: Vec<T>| {
+        if let Some(item) = form_item {
+            let to_json = |value: &T| {
+                if label_in_value {
+                    LabeledValue {
+                        value: value.clone(),
+                        label: option_of(value).label,
+                    }
+                    .to_json()
+                } else {
+                    value_to_json(value)
+                }
+            };
+            let value = if multiple {
+                Value::Array(values.iter().map(to_json).collect())
+            } else {
+                values.first().map(to_json).unwrap_or(Value::Null)
+            };
+            item.trigger(event, value);
+        }
+    };
+
+    // 更新选中值并触发回调
+    let mut commit_values = move |values: Vec<T>| {
+        selected_values.set(values.clone());
+        trigger_form("on_change", values.clone());
+        if label_in_value {
+            if let Some(on_labeled_change) = &props.on_labeled_change {
+                on_labeled_change.call(
+                    values
+                        .iter()
+                        .map(|value| LabeledValue {
+                            value: value.clone(),
+                            label: option_of(value).label,
+                        })
+                        .collect(),
+                );
+            }
+        }
+        if multiple {
+            if let Some(on_change_multiple) = &props.on_change_multiple {
+                on_change_multiple.call(values);
+            }
+        } else if let Some(on_change) = &props.on_change {
+            on_change.call(values.into_iter().next());
+        }
+    };
+
+    // 展开分组并按搜索内容筛选后的下拉行，标签模式下包含已输入的值
+    let filter = show_search && props.filter_option && !is_remote;
+    let visible_rows = use_memo(use_reactive!(|filter, tags| {
+        let search = if filter {
+            search_value()
+        } else {
+            String::new()
+        };
+        if !tags {
+            return flatten_options(&all_options.read(), &search);
+        }
+        let mut options = all_options();
+        for value in selected_values.read().iter() {
+            if find_option(&options, value).is_none() {
+                options.push(SelectOptionData::new(value.clone(), value_text(value)));
             }
         }
+        let mut rows = flatten_options(&options, &search);
+        let text = search_value();
+        let exists = options
+            .iter()
+            .any(|option| option.label == text || value_text(&option.value) == text);
+        if let Some(value) = value_from_text::<T>(&text).filter(|_| !exists) {
+            rows.insert(
+                0,
+                SelectRow::Option {
+                    option: SelectOptionData::new(value, text),
+                    grouped: false,
+                    ranges: vec![],
+                },
+            );
+        }
+        rows
+    }));
+
+    // 激活项默认为已选中的选项，否则为第一个可用选项
+    let mut reset_active = move || {
+        let rows = visible_rows.read();
+        let selected = selected_values.peek();
+        let index = rows
+            .iter()
+            .position(|row| match row {
+                SelectRow::Option { option, .. } => {
+                    !option.disabled && selected.contains(&option.value)
+                }
+                SelectRow::Group(_) => false,
+            })
+            .or_else(|| step_active_index(&rows, None, true));
+        active_index.set(index);
     };
 
-    let _handle_option_click = move |value: String| {
-        if props.multiple {
+    use_effect(reset_active);
+
+    // 滚动下拉列表，同时更新虚拟滚动的可视区间
+    let item_height = props.list_item_height.max(1) as f64;
+    let list_height = props.list_height as f64;
+    let list_id = format!("ant-select-{select_id}-list");
+    let mut scroll_list = move |top: f64| {
+        scroll_top.set(top);
+        document::eval(&format!(
+            "var list = document.getElementById('ant-select-{select_id}-list'); if (list) {{ list.scrollTop = {top}; }}"
+        ));
+    };
+    let mut scroll_into_view = move |index: usize| {
+        if let Some(top) = scroll_top_for_index(index, item_height, list_height, scroll_top()) {
+            scroll_list(top);
+        }
+    };
+
+    let mut set_open = move |open: bool| {
+        is_open.set(open);
+        if open {
+            scroll_top.set(0.0);
+            reset_active();
+            if is_remote && remote.options.peek().is_empty() && !*remote.loading.peek() {
+                search_remote(search_value.peek().clone());
+            }
+        }
+        if let Some(on_dropdown_visible_change) = &props.on_dropdown_visible_change {
+            on_dropdown_visible_change.call(open);
+        }
+    };
+
+    let handle_click = move |_| {
+        if !disabled {
+            set_open(!is_open());
+        }
+    };
+
+    let mut handle_option_click = move |value: T| {
+        if is_remote && find_option(&known_options.read(), &value).is_none() {
+            if let Some(option) = find_option(&all_options.read(), &value).cloned() {
+                known_options.write().push(option);
+            }
+        }
+        if multiple {
             let mut current_values = selected_values();
             if current_values.contains(&value) {
                 current_values.retain(|v| v != &value);
             } else {
                 current_values.push(value);
             }
-            selected_values.set(current_values.clone());
-            if let Some(on_change_multiple) = &props.on_change_multiple {
-                on_change_multiple.call(current_values);
-            }
+            commit_values(current_values);
             if props.auto_clear_search_value {
                 search_value.set(String::new());
             }
         } else {
-            selected_values.set(vec![value.clone()]);
-            if let Some(on_change) = &props.on_change {
-                on_change.call(value);
+            commit_values(vec![value]);
+            set_open(false);
+        }
+    };
+
+    // 键盘上下切换激活项，回车选中，Esc 关闭
+    let handle_keydown = move |evt: KeyboardEvent| {
+        if disabled {
+            return;
+        }
+        match evt.key() {
+            key @ (Key::ArrowDown | Key::ArrowUp) => {
+                evt.prevent_default();
+                if !is_open() {
+                    set_open(true);
+                    return;
+                }
+                let next =
+                    step_active_index(&visible_rows.read(), active_index(), key == Key::ArrowDown);
+                active_index.set(next);
+                if let Some(index) = next {
+                    scroll_into_view(index);
+                }
             }
-            is_open.set(false);
-            if let Some(on_dropdown_visible_change) = &props.on_dropdown_visible_change {
-                on_dropdown_visible_change.call(false);
+            Key::Enter => {
+                evt.prevent_default();
+                if !is_open() {
+                    set_open(true);
+                    return;
+                }
+                let row = active_index().and_then(|index| visible_rows.read().get(index).cloned());
+                if let Some(SelectRow::Option { option, .. }) = row {
+                    if !option.disabled {
+                        handle_option_click(option.value);
+                    }
+                }
+            }
+            // 搜索框为空时退格删除最后一个标签
+            Key::Backspace if multiple && search_value.read().is_empty() => {
+                let mut current_values = selected_values();
+                if current_values.pop().is_some() {
+                    commit_values(current_values);
+                }
+            }
+            Key::Escape if is_open() => {
+                evt.prevent_default();
+                set_open(false);
             }
+            _ => {}
         }
     };
 
+    let handle_list_mounted = move |evt: MountedEvent| {
+        list_ref.set(Some(evt.data()));
+        if let Some(index) = active_index() {
+            scroll_into_view(index);
+        }
+    };
+
+    let handle_list_scroll = move |_| {
+        let Some(list) = list_ref.cloned() else {
+            return;
+        };
+        spawn(async move {
+            if let Ok(offset) = list.get_scroll_offset().await {
+                scroll_top.set(offset.y);
+            }
+        });
+    };
+
     let handle_search = move |evt: FormEvent| {
         let value = evt.value();
+        if multiple {
+            // 包含分隔符时拆分为多个值，标签模式直接创建，多选模式匹配已有选项
+            if let Some(tokens) = split_tokens(&value, &props.token_separators) {
+                let options = all_options.read().clone();
+                let mut current_values = selected_values();
+                for token in tokens {
+                    let token_value = if tags {
+                        value_from_text::<T>(&token)
+                    } else {
+                        find_option_by_text(&options, &token).map(|option| option.value.clone())
+                    };
+                    if let Some(token_value) = token_value {
+                        if !current_values.contains(&token_value) {
+                            current_values.push(token_value);
+                        }
+                    }
+                }
+                search_value.set(String::new());
+                commit_values(current_values);
+                return;
+            }
+        }
         search_value.set(value.clone());
+        scroll_list(0.0);
+        search_remote(value.clone());
+        if !is_open() {
+            set_open(true);
+        }
         if let Some(on_search) = &props.on_search {
             on_search.call(value);
         }
     };
 
     let handle_clear = move |_| {
-        selected_values.set(vec![]);
         search_value.set(String::new());
-        if props.multiple {
-            if let Some(on_change_multiple) = &props.on_change_multiple {
-                on_change_multiple.call(vec![]);
-            }
-        } else {
-            if let Some(on_change) = &props.on_change {
-                on_change.call(String::new());
-            }
-        }
+        commit_values(vec![]);
         if let Some(on_clear) = &props.on_clear {
             on_clear.call(());
         }
@@ -305,6 +766,7 @@ pub fn Select(props: SelectProps) -> Element {
 
     let handle_blur = move |evt: FocusEvent| {
         is_focused.set(false);
+        trigger_form("on_blur", selected_values());
         if let Some(on_blur) = &props.on_blur {
             on_blur.call(evt);
         }
@@ -318,12 +780,12 @@ pub fn Select(props: SelectProps) -> Element {
             classes.push(size_class);
         }
 
-        let status_class = props.status.to_class();
+        let status_class = status.to_class();
         if !status_class.is_empty() {
             classes.push(status_class);
         }
 
-        if props.disabled {
+        if disabled {
             classes.push("ant-select-disabled");
         }
 
@@ -339,11 +801,11 @@ pub fn Select(props: SelectProps) -> Element {
             classes.push("ant-select-focused");
         }
 
-        if props.multiple {
+        if multiple {
             classes.push("ant-select-multiple");
         }
 
-        if props.show_search {
+        if show_search {
             classes.push("ant-select-show-search");
         }
 
@@ -354,23 +816,139 @@ pub fn Select(props: SelectProps) -> Element {
         classes.join(" ")
     };
 
-    // 获取显示文本
-    let display_text = if props.multiple {
-        if selected_values().is_empty() {
-            props.placeholder.unwrap_or_default()
-        } else {
-            format!("{} 项已选择", selected_values().len())
+    // 已选项的显示内容
+    let label_render = props.label_render;
+    let render_label = move |value: &T| {
+        let option = option_of(value);
+        match label_render {
+            Some(label_render) => label_render.call(option),
+            None => rsx! { {option.label} },
         }
+    };
+
+    // 获取显示文本
+    let display_text = if selected_values().is_empty() {
+        props.placeholder.clone().unwrap_or_default()
+    } else if multiple {
+        format!("{} 项已选择", selected_values().len())
     } else {
-        if selected_values().is_empty() {
-            props.placeholder.unwrap_or_default()
-        } else {
-            selected_values().first().cloned().unwrap_or_default()
-        }
+        selected_values()
+            .first()
+            .map(|value| option_of(value).label)
+            .unwrap_or_default()
     };
 
     let has_value = !selected_values().is_empty();
 
+    // 多选标签，超出 max_tag_count 的部分折叠为 "+N"
+    let mut remove_value = move |value: T| {
+        let mut current_values = selected_values();
+        current_values.retain(|v| v != &value);
+        commit_values(current_values);
+    };
+    let responsive = props.max_tag_count == Some(MaxTagCount::Responsive);
+    let overflow_id = format!("ant-select-{select_id}-overflow");
+    let measure_tags = move || {
+        if !responsive {
+            return;
+        }
+        spawn(async move {
+            let result = document::eval(&format!(
+                "var list = document.getElementById('ant-select-{select_id}-overflow'); \
+                 if (!list) {{ return null; }} \
+                 var items = Array.from(list.querySelectorAll('[data-tag-index]')); \
+                 var search = list.querySelector('.ant-select-selection-overflow-item-suffix'); \
+                 return {{ width: list.clientWidth - (search ? 16 : 0), \
+                   items: items.map(function (item) {{ return item.offsetWidth + 4; }}) }};"
+            ))
+            .await;
+            if let Ok(Value::Object(result)) = result {
+                let width = result.get("width").and_then(Value::as_f64).unwrap_or(0.0);
+                let widths: Vec<f64> = result
+                    .get("items")
+                    .and_then(Value::as_array)
+                    .map(|items| items.iter().filter_map(Value::as_f64).collect())
+                    .unwrap_or_default();
+                responsive_count.set(Some(responsive_tag_count(&widths, width)));
+            }
+        });
+    };
+    use_effect(move || {
+        selected_values.read();
+        measure_tags();
+    });
+    let tag_limit = match props.max_tag_count {
+        Some(MaxTagCount::Count(count)) => count,
+        Some(MaxTagCount::Responsive) => responsive_count().unwrap_or(usize::MAX),
+        None => usize::MAX,
+    };
+    let rest_count = selected_values().len().saturating_sub(tag_limit);
+    let max_tag_text_length = props.max_tag_text_length;
+    let tag_render = props.tag_render;
+    let render_tag = move |value: T| {
+        let option = option_of(&value);
+        let label = truncate_label(&option.label, max_tag_text_length);
+        if let Some(tag_render) = tag_render {
+            return tag_render.call(SelectTagRenderArgs {
+                value: value.clone(),
+                label,
+                disabled: disabled || option.disabled,
+                closable: !disabled,
+                on_close: Callback::new(move |_| remove_value(value.clone())),
+            });
+        }
+        let title = option.label.clone();
+        let content = match label_render {
+            Some(label_render) if max_tag_text_length.is_none() => label_render.call(option),
+            _ => rsx! { {label} },
+        };
+        rsx! {
+            span {
+                class: "ant-select-selection-item",
+                title,
+
+                span {
+                    class: "ant-select-selection-item-content",
+                    {content}
+                }
+
+                if !disabled {
+                    span {
+                        class: "ant-select-selection-item-remove",
+                        onclick: move |evt: MouseEvent| {
+                            evt.stop_propagation();
+                            remove_value(value.clone());
+                        },
+                        "×"
+                    }
+                }
+            }
+        }
+    };
+
+    // 选项超出下拉高度时只渲染可视区域内的选项
+    let use_options = tags || is_remote || !all_options.read().is_empty();
+    let loading = (remote.loading)();
+    let total = visible_rows.read().len();
+    let range = if props.virtual_list && total as f64 * item_height > list_height {
+        virtual_range(total, scroll_top(), list_height, item_height, LIST_OVERSCAN)
+    } else {
+        VirtualRange {
+            start: 0,
+            end: total,
+            offset_top: 0.0,
+            offset_bottom: 0.0,
+        }
+    };
+    let window_rows: Vec<(usize, SelectRow<T>)> = visible_rows.read()[range.start..range.end]
+        .iter()
+        .cloned()
+        .enumerate()
+        .map(|(offset, row)| (range.start + offset, row))
+        .collect();
+    let row_style =
+        format!("height: {item_height}px; min-height: {item_height}px; box-sizing: border-box;");
+
     rsx! {
         style { {SELECT_STYLES} }
 
@@ -383,78 +961,101 @@ pub fn Select(props: SelectProps) -> Element {
                 onclick: handle_click,
                 onfocus: handle_focus,
                 onblur: handle_blur,
+                onkeydown: handle_keydown,
                 tabindex: "0",
 
-                // 多选标签
-                if props.multiple && !selected_values().is_empty() {
+                if multiple {
+                    // 多选标签
                     div {
+                        id: overflow_id,
                         class: "ant-select-selection-overflow",
+                        onresize: move |_| measure_tags(),
+
+                        for (index, value) in selected_values().into_iter().enumerate() {
+                            if responsive || index < tag_limit {
+                                div {
+                                    key: "{value_text(&value)}",
+                                    class: if index < tag_limit {
+                                        "ant-select-selection-overflow-item"
+                                    } else {
+                                        "ant-select-selection-overflow-item ant-select-selection-overflow-item-hidden"
+                                    },
+                                    "data-tag-index": index,
+                                    {render_tag(value)}
+                                }
+                            }
+                        }
 
-                        for value in selected_values() {
+                        if rest_count > 0 {
                             div {
-                                class: "ant-select-selection-overflow-item",
-
+                                class: "ant-select-selection-overflow-item ant-select-selection-overflow-item-rest",
                                 span {
                                     class: "ant-select-selection-item",
-
                                     span {
                                         class: "ant-select-selection-item-content",
-                                        {value.clone()}
+                                        "+ {rest_count} ..."
                                     }
+                                }
+                            }
+                        }
 
-                                    span {
-                                        class: "ant-select-selection-item-remove",
-                                        onclick: {
-                                            let value_clone = value.clone();
-                                            move |_| {
-                                                let mut current_values = selected_values();
-                                                current_values.retain(|v| v != &value_clone);
-                                                selected_values.set(current_values.clone());
-                                                if let Some(on_change_multiple) = &props.on_change_multiple {
-                                                    on_change_multiple.call(current_values);
-                                                }
-                                            }
-                                        },
-                                        "×"
+                        if show_search {
+                            div {
+                                class: "ant-select-selection-overflow-item ant-select-selection-overflow-item-suffix",
+                                div {
+                                    class: "ant-select-selection-search",
+                                    input {
+                                        class: "ant-select-selection-search-input",
+                                        value: search_value(),
+                                        disabled,
+                                        oninput: handle_search
                                     }
                                 }
                             }
                         }
                     }
-                }
 
-                // 搜索输入框
-                if props.show_search {
+                    // 占位符
+                    if !has_value && search_value.read().is_empty() {
+                        div {
+                            class: "ant-select-selection-placeholder",
+                            {display_text.clone()}
+                        }
+                    }
+                } else if show_search {
+                    // 搜索输入框
                     div {
                         class: "ant-select-selection-search",
 
                         input {
                             class: "ant-select-selection-search-input",
                             value: search_value(),
-                            placeholder: if has_value { "" } else { display_text.as_str() },
+                            placeholder: display_text.as_str(),
                             oninput: handle_search
                         }
                     }
                 } else {
                     // 选择项显示
-                    div {
-                        class: "ant-select-selection-item",
-                        title: display_text.clone(),
-                        {display_text.clone()}
-                    }
-                }
-
-                // 占位符
-                if !has_value && !props.show_search {
-                    div {
-                        class: "ant-select-selection-placeholder",
-                        {display_text.clone()}
+                    if has_value {
+                        div {
+                            class: "ant-select-selection-item",
+                            title: display_text.clone(),
+                            for value in selected_values().into_iter().take(1) {
+                                {render_label(&value)}
+                            }
+                        }
+                    } else {
+                        // 占位符
+                        div {
+                            class: "ant-select-selection-placeholder",
+                            {display_text.clone()}
+                        }
                     }
                 }
             }
 
             // 清除按钮
-            if props.allow_clear && has_value && !props.disabled {
+            if props.allow_clear && has_value && !disabled {
                 span {
                     class: "ant-select-clear",
                     onclick: handle_clear,
@@ -472,7 +1073,116 @@ pub fn Select(props: SelectProps) -> Element {
             }
 
             // 下拉菜单
-            if is_open() {
+            if is_open() && use_options {
+                div {
+                    class: "ant-select-dropdown",
+
+                    div {
+                        id: list_id,
+                        class: "ant-select-dropdown-menu",
+                        style: "max-height: {props.list_height}px;",
+                        role: "listbox",
+                        onmounted: handle_list_mounted,
+                        onscroll: handle_list_scroll,
+                        // 保持选择框焦点，以便继续使用键盘操作
+                        onmousedown: move |evt: MouseEvent| evt.prevent_default(),
+
+                        if loading {
+                            div {
+                                class: "ant-select-item-loading",
+                                Spin { size: SpinSize::Small }
+                            }
+                        } else if total == 0 && !tags {
+                            div {
+                                class: "ant-select-item-empty",
+                                if let Some(not_found_content) = props.not_found_content {
+                                    {not_found_content}
+                                } else {
+                                    {empty_text}
+                                }
+                            }
+                        }
+
+                        if !loading && range.offset_top > 0.0 {
+                            div { style: "height: {range.offset_top}px;" }
+                        }
+
+                        for (index, row) in window_rows.into_iter().filter(|_| !loading) {
+                            match row {
+                                SelectRow::Group(label) => rsx! {
+                                    div {
+                                        key: "group-{index}",
+                                        class: "ant-select-item ant-select-item-group",
+                                        style: row_style.clone(),
+                                        title: label.clone(),
+                                        {label.clone()}
+                                    }
+                                },
+                                SelectRow::Option { option, grouped, ranges } => {
+                                    let selected = selected_values().contains(&option.value);
+                                    let option_class = {
+                                        let mut classes = vec!["ant-select-item", "ant-select-item-option"];
+                                        if grouped {
+                                            classes.push("ant-select-item-option-grouped");
+                                        }
+                                        if selected {
+                                            classes.push("ant-select-item-option-selected");
+                                        }
+                                        if active_index() == Some(index) {
+                                            classes.push("ant-select-item-option-active");
+                                        }
+                                        if option.disabled {
+                                            classes.push("ant-select-item-option-disabled");
+                                        }
+                                        classes.join(" ")
+                                    };
+                                    let disabled = option.disabled;
+                                    let value = option.value.clone();
+                                    rsx! {
+                                        div {
+                                            key: "{value_text(&option.value)}",
+                                            class: option_class,
+                                            style: row_style.clone(),
+                                            role: "option",
+                                            title: option.title.clone().unwrap_or_else(|| option.label.clone()),
+                                            "aria-selected": selected,
+                                            onmouseenter: move |_| {
+                                                if !disabled {
+                                                    active_index.set(Some(index));
+                                                }
+                                            },
+                                            onclick: move |_| {
+                                                if !disabled {
+                                                    handle_option_click(value.clone());
+                                                }
+                                            },
+
+                                            div {
+                                                class: "ant-select-item-option-content",
+                                                if let Some(option_render) = props.option_render {
+                                                    {option_render.call(option.clone())}
+                                                } else {
+                                                    for (segment, matched) in highlight_segments(&option.label, &ranges) {
+                                                        if matched {
+                                                            span { class: "ant-select-item-option-highlight", "{segment}" }
+                                                        } else {
+                                                            "{segment}"
+                                                        }
+                                                    }
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+
+                        if !loading && range.offset_bottom > 0.0 {
+                            div { style: "height: {range.offset_bottom}px;" }
+                        }
+                    }
+                }
+            } else if is_open() {
                 div {
                     class: "ant-select-dropdown",
                     style: format!("max-height: {}px; overflow-y: auto;", props.list_height),
@@ -594,3 +1304,301 @@ pub fn SelectOptGroup(props: SelectOptGroupProps) -> Element {
 
 // 组件已通过#[component]宏自动导出
 // 无需重新导出
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_options() -> Vec<OptionData> {
+        vec![
+            OptionData::new("apple", "Apple"),
+            OptionData::new("banana", "Banana").disabled(true),
+            OptionData::new("cherry", "Cherry"),
+            OptionData::new("date", "Date"),
+        ]
+    }
+
+    fn row_values(rows: &[SelectRow<String>]) -> Vec<String> {
+        rows.iter()
+            .map(|row| match row {
+                SelectRow::Group(label) => format!("[{label}]"),
+                SelectRow::Option { option, .. } => option.value.clone(),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_flatten_options() {
+        let options = sample_options();
+        assert_eq!(flatten_options(&options, "").len(), 4);
+        assert_eq!(
+            row_values(&flatten_options(&options, " AN ")),
+            vec!["banana"]
+        );
+        assert_eq!(
+            row_values(&flatten_options(&options, "e")),
+            vec!["cherry", "date", "apple"]
+        );
+
+        // 分组标题只在有匹配项时显示
+        let grouped = vec![
+            OptionData::group("Fruit", sample_options()),
+            OptionData::group("Nut", vec![OptionData::new("walnut", "Walnut")]),
+            OptionData::new("other", "Other"),
+        ];
+        let rows = flatten_options(&grouped, "");
+        assert_eq!(rows.len(), 8);
+        assert_eq!(
+            rows[1],
+            SelectRow::Option {
+                option: OptionData::new("apple", "Apple"),
+                grouped: true,
+                ranges: vec![],
+            }
+        );
+        assert_eq!(
+            row_values(&flatten_options(&grouped, "nut")),
+            vec!["[Nut]", "walnut"]
+        );
+        assert!(flatten_options(&grouped, "xyz").is_empty());
+
+        // 按得分排序并返回标签中的匹配区间，支持拼音首字母
+        let cities = vec![
+            OptionData::new("sh", "上海"),
+            OptionData::group("北方", vec![OptionData::new("bj", "北京")]),
+            OptionData::new("nb", "宁波"),
+        ];
+        assert_eq!(
+            row_values(&flatten_options(&cities, "b")),
+            vec!["[北方]", "bj", "nb"]
+        );
+        match &flatten_options(&cities, "bj")[1] {
+            SelectRow::Option { ranges, .. } => assert_eq!(ranges, &vec![0..6]),
+            row => panic!("unexpected row {row:?}"),
+        }
+    }
+
+    #[test]
+    fn test_step_active_index() {
+        let rows = flatten_options(&sample_options(), "");
+        assert_eq!(step_active_index(&rows, None, true), Some(0));
+        assert_eq!(step_active_index(&rows, None, false), Some(3));
+        // 跳过禁用项
+        assert_eq!(step_active_index(&rows, Some(0), true), Some(2));
+        assert_eq!(step_active_index(&rows, Some(2), false), Some(0));
+        // 首尾循环
+        assert_eq!(step_active_index(&rows, Some(3), true), Some(0));
+        assert_eq!(step_active_index(&rows, Some(0), false), Some(3));
+
+        // 跳过分组标题
+        let grouped = flatten_options(&[OptionData::group("Fruit", sample_options())], "");
+        assert_eq!(step_active_index(&grouped, None, true), Some(1));
+        assert_eq!(step_active_index(&grouped, Some(1), false), Some(4));
+
+        let disabled = flatten_options(&[OptionData::new("a", "A").disabled(true)], "");
+        assert_eq!(step_active_index(&disabled, None, true), None);
+        assert_eq!(step_active_index::<String>(&[], None, true), None);
+    }
+
+    #[test]
+    fn test_option_from_json() {
+        let field_names = SelectFieldNames::default()
+            .label("name")
+            .value("id")
+            .options("children");
+        let data = serde_json::json!({
+            "name": "华东",
+            "children": [
+                { "name": "上海", "id": 2 },
+                { "name": "杭州", "id": 3, "disabled": true },
+                { "name": "无效", "id": "x" },
+            ],
+        });
+        let group = SelectOptionData::<u32>::from_json(&data, &field_names).unwrap();
+        assert!(group.is_group());
+        assert_eq!(group.label, "华东");
+        assert_eq!(
+            group.options,
+            vec![
+                SelectOptionData::new(2_u32, "上海"),
+                SelectOptionData::new(3_u32, "杭州").disabled(true),
+            ]
+        );
+
+        // 数字值按文本转换为字符串
+        let option =
+            OptionData::from_json(&serde_json::json!({ "value": 7 }), &Default::default()).unwrap();
+        assert_eq!(option, OptionData::new("7", "7"));
+        assert_eq!(
+            find_option(&[group], &3).map(|o| o.label.as_str()),
+            Some("杭州")
+        );
+    }
+
+    #[test]
+    fn test_labeled_value() {
+        let labeled = LabeledValue {
+            value: 2_u32,
+            label: "上海".to_string(),
+        };
+        assert_eq!(
+            labeled.to_json(),
+            serde_json::json!({ "value": 2, "label": "上海" })
+        );
+        assert_eq!(value_from_json::<u32>(&labeled.to_json()), Some(2));
+        assert_eq!(
+            value_from_json::<String>(&serde_json::json!(5)),
+            Some("5".to_string())
+        );
+        assert_eq!(value_from_json::<u32>(&serde_json::json!("a")), None);
+    }
+
+    #[test]
+    fn test_tags_helpers() {
+        let separators = vec![",".to_string(), " ".to_string()];
+        assert_eq!(split_tokens("abc", &separators), None);
+        assert_eq!(
+            split_tokens("a, b,,c d", &separators),
+            Some(vec![
+                "a".to_string(),
+                "b".to_string(),
+                "c".to_string(),
+                "d".to_string()
+            ])
+        );
+        assert_eq!(split_tokens("a,", &separators), Some(vec!["a".to_string()]));
+        assert_eq!(split_tokens("a,b", &[]), None);
+
+        assert_eq!(value_from_text::<String>(" tag "), Some("tag".to_string()));
+        assert_eq!(value_from_text::<u32>("42"), Some(42));
+        assert_eq!(value_from_text::<u32>("abc"), None);
+        assert_eq!(value_from_text::<String>("  "), None);
+
+        assert_eq!(truncate_label("上海市浦东新区", Some(3)), "上海市...");
+        assert_eq!(truncate_label("北京", Some(3)), "北京");
+        assert_eq!(truncate_label("北京", None), "北京");
+
+        let options = sample_options();
+        assert_eq!(
+            find_option_by_text(&options, "Cherry").map(|o| o.value.as_str()),
+            Some("cherry")
+        );
+        assert!(find_option_by_text(&options, "banana").is_none());
+    }
+
+    #[test]
+    fn test_responsive_tag_count() {
+        assert_eq!(responsive_tag_count(&[], 100.0), 0);
+        assert_eq!(responsive_tag_count(&[40.0, 40.0], 100.0), 2);
+        // 全部放得下时不显示 "+N"
+        assert_eq!(responsive_tag_count(&[40.0, 40.0, 40.0], 150.0), 3);
+        // 放不下时为 "+N" 预留空间
+        assert_eq!(responsive_tag_count(&[40.0, 40.0, 40.0, 40.0], 140.0), 2);
+        assert_eq!(responsive_tag_count(&[40.0, 40.0, 40.0], 100.0), 1);
+        assert_eq!(responsive_tag_count(&[200.0, 40.0], 100.0), 0);
+    }
+
+    #[test]
+    fn test_remote_options() {
+        use std::cell::RefCell;
+        use std::future::Future;
+        use std::task::{Context, Waker};
+
+        type Probe = (RemoteOptions<OptionData>, LoadOptions<OptionData>);
+
+        thread_local! {
+            static CALLS: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };
+            static PROBE: RefCell<Option<Probe>> = const { RefCell::new(None) };
+        }
+
+        #[component]
+        fn App() -> Element {
+            let remote = use_remote_options::<OptionData>();
+            use_hook(move || {
+                let loader = LoadOptions::new(|query: String| {
+                    CALLS.with(|calls| calls.borrow_mut().push(query.clone()));
+                    async move { vec![OptionData::new(query.clone(), query.to_uppercase())] }
+                })
+                .debounce(0);
+                assert!(loader == loader.clone());
+                PROBE.with(|probe| *probe.borrow_mut() = Some((remote, loader)));
+            });
+            rsx! {}
+        }
+
+        let mut dom = VirtualDom::new(App);
+        dom.rebuild_in_place();
+        let (remote, loader) = PROBE.with(|probe| probe.borrow().clone()).unwrap();
+        let search = |dom: &mut VirtualDom, query: &str| {
+            dom.in_runtime(|| {
+                ScopeId::APP.in_runtime(|| remote.search(&loader, query.to_string()))
+            });
+            let mut context = Context::from_waker(Waker::noop());
+            let _ = std::pin::pin!(dom.wait_for_work()).poll(&mut context);
+        };
+        let loaded = || {
+            remote
+                .options
+                .peek()
+                .iter()
+                .map(|option| option.label.clone())
+                .collect::<Vec<_>>()
+        };
+
+        // 新的查询取消进行中的请求
+        dom.in_runtime(|| ScopeId::APP.in_runtime(|| remote.search(&loader, "a".to_string())));
+        assert!(*remote.loading.peek());
+        search(&mut dom, "b");
+        assert_eq!(loaded(), vec!["B"]);
+        assert!(!*remote.loading.peek());
+        assert_eq!(CALLS.with(|calls| calls.borrow().clone()), vec!["b"]);
+
+        // 命中缓存时不再调用加载函数
+        search(&mut dom, "c");
+        search(&mut dom, "b");
+        assert_eq!(loaded(), vec!["B"]);
+        assert_eq!(CALLS.with(|calls| calls.borrow().clone()), vec!["b", "c"]);
+    }
+
+    #[test]
+    fn test_select_zero_value() {
+        use std::cell::RefCell;
+
+        thread_local! {
+            static RENDERED: RefCell<Vec<i32>> = const { RefCell::new(Vec::new()) };
+        }
+
+        // 0 是正常的选中值，不再被当作未选择
+        #[component]
+        fn App() -> Element {
+            rsx! {
+                Select::<i32> {
+                    value: 0,
+                    options: vec![SelectOptionData::new(0, "零"), SelectOptionData::new(1, "一")],
+                    label_render: move |option: SelectOptionData<i32>| {
+                        RENDERED.with(|rendered| rendered.borrow_mut().push(option.value));
+                        rsx! { {option.label} }
+                    },
+                }
+            }
+        }
+
+        let mut dom = VirtualDom::new(App);
+        dom.rebuild_in_place();
+        assert_eq!(RENDERED.with(|rendered| rendered.borrow().clone()), vec![0]);
+    }
+
+    #[test]
+    fn test_scroll_top_for_index() {
+        // 可视区域 [64, 320)，每项 32px
+        assert_eq!(scroll_top_for_index(3, 32.0, 256.0, 64.0), None);
+        assert_eq!(scroll_top_for_index(1, 32.0, 256.0, 64.0), Some(32.0));
+        assert_eq!(scroll_top_for_index(10, 32.0, 256.0, 64.0), Some(96.0));
+        assert_eq!(scroll_top_for_index(0, 32.0, 256.0, 0.0), None);
+        // 虚拟窗口之外的远端选项
+        assert_eq!(
+            scroll_top_for_index(19999, 32.0, 256.0, 0.0),
+            Some(19999.0 * 32.0 + 32.0 - 256.0)
+        );
+    }
+}
@@ -0,0 +1,407 @@
+//! Select 选项数据
+//!
+//! 类型化的选项与分组、JSON 选项的字段映射以及下拉菜单的行数据。
+
+use crate::components::form::value_to_text;
+use crate::utils::fuzzy_match;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+use std::ops::Range;
+
+/// Select 的值类型
+///
+/// Select 位于 FormItem 内时自动绑定字段，选中值与 JSON 字段值之间通过 serde 相互转换，
+/// 标签模式下也由输入的文本反序列化出新值，因此要求可序列化。
+pub trait SelectValue: Clone + PartialEq + Serialize + DeserializeOwned + 'static {}
+
+impl<T> SelectValue for T where T: Clone + PartialEq + Serialize + DeserializeOwned + 'static {}
+
+/// Select 选项数据，`options` 不为空时作为选项分组
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SelectOptionData<T> {
+    /// 选项值
+    pub value: T,
+    /// 选项标签
+    pub label: String,
+    /// 是否禁用
+    pub disabled: bool,
+    /// 选项的提示文字，默认为标签
+    pub title: Option<String>,
+    /// 分组内的选项
+    pub options: Vec<SelectOptionData<T>>,
+}
+
+/// 字符串值的选项数据
+pub type OptionData = SelectOptionData<String>;
+
+impl<T: SelectValue> SelectOptionData<T> {
+    /// 创建选项
+    pub fn new(value: impl Into<T>, label: impl Into<String>) -> Self {
+        Self {
+            value: value.into(),
+            label: label.into(),
+            disabled: false,
+            title: None,
+            options: vec![],
+        }
+    }
+
+    /// 设置是否禁用
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// 设置提示文字
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// 是否为选项分组
+    pub fn is_group(&self) -> bool {
+        !self.options.is_empty()
+    }
+
+    /// 按字段映射从 JSON 对象读取选项，值无法转换为 `T` 或分组下没有选项时返回 None
+    pub fn from_json(data: &Value, field_names: &SelectFieldNames) -> Option<Self> {
+        let label = data
+            .get(&field_names.label)
+            .map(value_to_text)
+            .unwrap_or_default();
+        let disabled = data
+            .get(&field_names.disabled)
+            .and_then(Value::as_bool)
+            .unwrap_or(false);
+
+        if let Some(children) = data.get(&field_names.options).and_then(Value::as_array) {
+            let options: Vec<Self> = children
+                .iter()
+                .filter_map(|child| Self::from_json(child, field_names))
+                .collect();
+            // 分组的值不参与选择，以第一个子选项的值占位
+            let value = options.first()?.value.clone();
+            return Some(Self {
+                value,
+                label,
+                disabled,
+                title: None,
+                options,
+            });
+        }
+
+        let raw_value = data.get(&field_names.value)?;
+        let value = value_from_json(raw_value)?;
+        let label = if label.is_empty() {
+            value_to_text(raw_value)
+        } else {
+            label
+        };
+        Some(Self {
+            value,
+            label,
+            disabled,
+            title: None,
+            options: vec![],
+        })
+    }
+}
+
+impl<T: SelectValue + Default> SelectOptionData<T> {
+    /// 创建选项分组，分组的值不参与选择
+    pub fn group(label: impl Into<String>, options: Vec<Self>) -> Self {
+        Self {
+            value: T::default(),
+            label: label.into(),
+            disabled: false,
+            title: None,
+            options,
+        }
+    }
+}
+
+/// JSON 选项数据的字段映射
+#[derive(Debug, Clone, PartialEq)]
+pub struct SelectFieldNames {
+    /// 标签字段
+    pub label: String,
+    /// 值字段
+    pub value: String,
+    /// 分组子选项字段
+    pub options: String,
+    /// 禁用字段
+    pub disabled: String,
+}
+
+impl Default for SelectFieldNames {
+    fn default() -> Self {
+        Self {
+            label: "label".to_string(),
+            value: "value".to_string(),
+            options: "options".to_string(),
+            disabled: "disabled".to_string(),
+        }
+    }
+}
+
+impl SelectFieldNames {
+    /// 设置标签字段
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = label.into();
+        self
+    }
+
+    /// 设置值字段
+    pub fn value(mut self, value: impl Into<String>) -> Self {
+        self.value = value.into();
+        self
+    }
+
+    /// 设置分组子选项字段
+    pub fn options(mut self, options: impl Into<String>) -> Self {
+        self.options = options.into();
+        self
+    }
+
+    /// 设置禁用字段
+    pub fn disabled(mut self, disabled: impl Into<String>) -> Self {
+        self.disabled = disabled.into();
+        self
+    }
+}
+
+/// `label_in_value` 模式下的选中值
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LabeledValue<T> {
+    /// 选项值
+    pub value: T,
+    /// 选项标签
+    pub label: String,
+}
+
+impl<T: SelectValue> LabeledValue<T> {
+    /// 转换为 `{ value, label }` 形式的表单字段值
+    pub fn to_json(&self) -> Value {
+        serde_json::json!({
+            "value": value_to_json(&self.value),
+            "label": self.label,
+        })
+    }
+}
+
+/// 值转换为 JSON
+pub(crate) fn value_to_json<T: Serialize>(value: &T) -> Value {
+    serde_json::to_value(value).unwrap_or(Value::Null)
+}
+
+/// 值的显示文本
+pub(crate) fn value_text<T: Serialize>(value: &T) -> String {
+    value_to_text(&value_to_json(value))
+}
+
+/// 从表单字段值读取选项值
+///
+/// 支持 `{ value, label }` 形式，类型不一致时按文本再转换一次，如数字转为字符串。
+pub(crate) fn value_from_json<T: SelectValue>(value: &Value) -> Option<T> {
+    if let Some(inner) = value.as_object().and_then(|object| object.get("value")) {
+        return value_from_json(inner);
+    }
+    serde_json::from_value(value.clone())
+        .ok()
+        .or_else(|| serde_json::from_value(Value::String(value_to_text(value))).ok())
+}
+
+/// 由输入的文本创建选项值，先按字符串转换，失败时按 JSON 解析，如数字
+pub(crate) fn value_from_text<T: SelectValue>(text: &str) -> Option<T> {
+    let text = text.trim();
+    if text.is_empty() {
+        return None;
+    }
+    serde_json::from_value(Value::String(text.to_string()))
+        .ok()
+        .or_else(|| serde_json::from_str(text).ok())
+}
+
+/// 按分隔符拆分输入内容，不包含分隔符时返回 None
+pub(crate) fn split_tokens(text: &str, separators: &[String]) -> Option<Vec<String>> {
+    let separators: Vec<&str> = separators
+        .iter()
+        .map(String::as_str)
+        .filter(|separator| !separator.is_empty())
+        .collect();
+    if !separators.iter().any(|separator| text.contains(separator)) {
+        return None;
+    }
+    let mut tokens = vec![text.to_string()];
+    for separator in separators {
+        tokens = tokens
+            .iter()
+            .flat_map(|token| token.split(separator).map(str::to_string))
+            .collect();
+    }
+    Some(
+        tokens
+            .into_iter()
+            .map(|token| token.trim().to_string())
+            .filter(|token| !token.is_empty())
+            .collect(),
+    )
+}
+
+/// 截断标签文字，超出部分显示省略号
+pub(crate) fn truncate_label(label: &str, max_length: Option<usize>) -> String {
+    match max_length {
+        Some(max_length) if label.chars().count() > max_length => {
+            format!("{}...", label.chars().take(max_length).collect::<String>())
+        }
+        _ => label.to_string(),
+    }
+}
+
+/// 按标签或值的文本查找可选的选项
+pub(crate) fn find_option_by_text<'a, T: SelectValue>(
+    options: &'a [SelectOptionData<T>],
+    text: &str,
+) -> Option<&'a SelectOptionData<T>> {
+    options.iter().find_map(|option| {
+        if option.is_group() {
+            find_option_by_text(&option.options, text)
+        } else if !option.disabled && (option.label == text || value_text(&option.value) == text) {
+            Some(option)
+        } else {
+            None
+        }
+    })
+}
+
+/// 在选项及分组中查找值对应的选项
+pub(crate) fn find_option<'a, T: SelectValue>(
+    options: &'a [SelectOptionData<T>],
+    value: &T,
+) -> Option<&'a SelectOptionData<T>> {
+    options.iter().find_map(|option| {
+        if option.is_group() {
+            find_option(&option.options, value)
+        } else if &option.value == value {
+            Some(option)
+        } else {
+            None
+        }
+    })
+}
+
+/// 下拉菜单中的一行
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum SelectRow<T> {
+    /// 分组标题
+    Group(String),
+    /// 选项，`grouped` 表示位于分组内，`ranges` 为标签中匹配搜索内容的区间
+    Option {
+        option: SelectOptionData<T>,
+        grouped: bool,
+        ranges: Vec<Range<usize>>,
+    },
+}
+
+impl<T> SelectRow<T> {
+    /// 是否可以被激活和选中
+    pub(crate) fn is_selectable(&self) -> bool {
+        matches!(self, SelectRow::Option { option, .. } if !option.disabled)
+    }
+}
+
+/// 选项与搜索内容的匹配得分及标签中的匹配区间，标签和值都不匹配时返回 None
+fn match_option<T: SelectValue>(
+    option: &SelectOptionData<T>,
+    search: &str,
+) -> Option<(i32, Vec<Range<usize>>)> {
+    let label = fuzzy_match(&option.label, search);
+    let value = fuzzy_match(&value_text(&option.value), search);
+    match (label, value) {
+        (Some(label), Some(value)) if value.score > label.score => {
+            Some((value.score, label.ranges))
+        }
+        (Some(label), _) => Some((label.score, label.ranges)),
+        (None, Some(value)) => Some((value.score, vec![])),
+        (None, None) => None,
+    }
+}
+
+/// 展开分组并按搜索内容模糊筛选，匹配标签、值及汉字拼音首字母
+///
+/// 有搜索内容时按匹配得分排序，分组内的选项在组内排序，分组按组内最高得分排序，
+/// 没有匹配项的分组不显示。
+pub(crate) fn flatten_options<T: SelectValue>(
+    options: &[SelectOptionData<T>],
+    search: &str,
+) -> Vec<SelectRow<T>> {
+    let search = search.trim();
+    let matched = |options: &[SelectOptionData<T>], grouped: bool| {
+        let mut rows: Vec<(i32, SelectRow<T>)> = options
+            .iter()
+            .filter_map(|option| {
+                let (score, ranges) = match_option(option, search)?;
+                Some((
+                    score,
+                    SelectRow::Option {
+                        option: option.clone(),
+                        grouped,
+                        ranges,
+                    },
+                ))
+            })
+            .collect();
+        rows.sort_by_key(|(score, _)| std::cmp::Reverse(*score));
+        rows
+    };
+
+    let mut blocks: Vec<(i32, Vec<SelectRow<T>>)> = Vec::new();
+    for option in options {
+        if option.is_group() {
+            let children = matched(&option.options, true);
+            if let Some((score, _)) = children.first() {
+                let mut rows = vec![SelectRow::Group(option.label.clone())];
+                let score = *score;
+                rows.extend(children.into_iter().map(|(_, row)| row));
+                blocks.push((score, rows));
+            }
+        } else {
+            blocks.extend(
+                matched(std::slice::from_ref(option), false)
+                    .into_iter()
+                    .map(|(score, row)| (score, vec![row])),
+            );
+        }
+    }
+    blocks.sort_by_key(|(score, _)| std::cmp::Reverse(*score));
+    blocks.into_iter().flat_map(|(_, rows)| rows).collect()
+}
+
+/// 键盘上下移动时的下一个激活行，跳过分组标题与禁用项并首尾循环
+pub(crate) fn step_active_index<T>(
+    rows: &[SelectRow<T>],
+    current: Option<usize>,
+    forward: bool,
+) -> Option<usize> {
+    let len = rows.len();
+    if len == 0 {
+        return None;
+    }
+    let mut index = match current {
+        Some(current) if current < len => current,
+        _ if forward => len - 1,
+        _ => 0,
+    };
+    for _ in 0..len {
+        index = if forward {
+            (index + 1) % len
+        } else {
+            (index + len - 1) % len
+        };
+        if rows[index].is_selectable() {
+            return Some(index);
+        }
+    }
+    None
+}
@@ -0,0 +1,150 @@
+//! Select 远程搜索
+//!
+//! 搜索内容变化时防抖调用异步加载函数获取选项，缓存已查询过的结果并丢弃过期的响应。
+//! AutoComplete 也使用同样的选项来源。
+
+use dioxus::prelude::*;
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+
+/// 搜索内容变化后默认的请求防抖时间（毫秒）
+pub const DEFAULT_SEARCH_DEBOUNCE: u32 = 300;
+
+/// 远程加载选项返回的 Future
+pub type LoadOptionsFuture<O> = Pin<Box<dyn Future<Output = Vec<O>>>>;
+
+/// 远程加载选项的函数，参数为搜索内容
+pub type LoadOptionsFn<O> = Rc<dyn Fn(String) -> LoadOptionsFuture<O>>;
+
+/// 远程搜索配置
+pub struct LoadOptions<O> {
+    /// 异步加载函数
+    pub load: LoadOptionsFn<O>,
+    /// 搜索内容变化后的请求防抖时间（毫秒）
+    pub debounce: u32,
+    /// 是否缓存已查询过的结果
+    pub cache: bool,
+}
+
+impl<O: 'static> LoadOptions<O> {
+    /// 通过异步加载函数创建远程搜索配置
+    pub fn new<F, Fut>(load: F) -> Self
+    where
+        F: Fn(String) -> Fut + 'static,
+        Fut: Future<Output = Vec<O>> + 'static,
+    {
+        Self {
+            load: Rc::new(move |query| Box::pin(load(query))),
+            debounce: DEFAULT_SEARCH_DEBOUNCE,
+            cache: true,
+        }
+    }
+
+    /// 设置请求防抖时间
+    pub fn debounce(mut self, debounce: u32) -> Self {
+        self.debounce = debounce;
+        self
+    }
+
+    /// 设置是否缓存查询结果
+    pub fn cache(mut self, cache: bool) -> Self {
+        self.cache = cache;
+        self
+    }
+}
+
+impl<O> Clone for LoadOptions<O> {
+    fn clone(&self) -> Self {
+        Self {
+            load: self.load.clone(),
+            debounce: self.debounce,
+            cache: self.cache,
+        }
+    }
+}
+
+impl<O> PartialEq for LoadOptions<O> {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.load, &other.load)
+            && self.debounce == other.debounce
+            && self.cache == other.cache
+    }
+}
+
+/// 远程搜索的状态
+pub(crate) struct RemoteOptions<O: 'static> {
+    /// 最近一次查询的选项
+    pub options: Signal<Vec<O>>,
+    /// 是否正在加载
+    pub loading: Signal<bool>,
+    /// 进行中的请求，发起新请求时取消
+    task: Signal<Option<Task>>,
+    /// 最近一次请求的序号，用于丢弃过期的响应
+    request_id: Signal<u64>,
+    /// 已查询过的结果
+    cache: Signal<HashMap<String, Vec<O>>>,
+}
+
+impl<O: 'static> Clone for RemoteOptions<O> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<O: 'static> Copy for RemoteOptions<O> {}
+
+/// 创建远程搜索的状态
+pub(crate) fn use_remote_options<O: 'static>() -> RemoteOptions<O> {
+    RemoteOptions {
+        options: use_signal(Vec::new),
+        loading: use_signal(|| false),
+        task: use_signal(|| None),
+        request_id: use_signal(|| 0),
+        cache: use_signal(HashMap::new),
+    }
+}
+
+impl<O: Clone + 'static> RemoteOptions<O> {
+    /// 发起查询：取消进行中的请求，命中缓存时直接使用，否则等待防抖时间后调用加载函数
+    pub fn search(mut self, loader: &LoadOptions<O>, query: String) {
+        if let Some(task) = self.task.take() {
+            task.cancel();
+        }
+        let request_id = *self.request_id.peek() + 1;
+        self.request_id.set(request_id);
+
+        let cached = loader
+            .cache
+            .then(|| self.cache.peek().get(&query).cloned())
+            .flatten();
+        if let Some(options) = cached {
+            self.options.set(options);
+            self.loading.set(false);
+            return;
+        }
+
+        let load = loader.load.clone();
+        let debounce = loader.debounce;
+        let cache = loader.cache;
+        self.loading.set(true);
+        let task = spawn(async move {
+            if debounce > 0 {
+                gloo_timers::future::TimeoutFuture::new(debounce).await;
+            }
+            let options = load(query.clone()).await;
+            // 只接受最近一次请求的响应
+            if *self.request_id.peek() != request_id {
+                return;
+            }
+            if cache {
+                self.cache.write().insert(query, options.clone());
+            }
+            self.options.set(options);
+            self.loading.set(false);
+            self.task.set(None);
+        });
+        self.task.set(Some(task));
+    }
+}
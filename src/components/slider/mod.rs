@@ -26,7 +26,9 @@
 //! }
 //! ```
 
+use crate::components::form::{merge_form_status, use_form_item_control};
 use dioxus::prelude::*;
+use serde_json::Value;
 use std::collections::HashMap;
 use std::fmt;
 
@@ -226,6 +228,7 @@ pub struct SliderProps {
 /// Slider 组件
 #[component]
 pub fn Slider(props: SliderProps) -> Element {
+    let form_item = use_form_item_control();
     let mut current_value = use_signal(|| {
         if props.range {
             props
@@ -238,6 +241,45 @@ pub fn Slider(props: SliderProps) -> Element {
         }
     });
 
+    // 位于 FormItem 内时使用字段值，范围模式下为 `[start, end]` 数组
+    use_effect(move || {
+        let Some(value) = form_item.and_then(|item| item.value_for("value", "value")) else {
+            return;
+        };
+        let number = |value: Option<&Value>| value.and_then(Value::as_f64);
+        match value {
+            Value::Array(items) if props.range => {
+                if let (Some(start), Some(end)) = (number(items.first()), number(items.get(1))) {
+                    current_value.set((start, end));
+                }
+            }
+            value => {
+                if let Some(value) = value.as_f64() {
+                    current_value.set((value, value));
+                }
+            }
+        }
+    });
+    let disabled = props.disabled || form_item.is_some_and(|item| item.is_disabled());
+    let status = merge_form_status(
+        form_item,
+        props.status.clone(),
+        SliderStatus::Default,
+        SliderStatus::Error,
+        SliderStatus::Warning,
+    );
+    let range = props.range;
+    let trigger_form = move |event: &str, (start, end): (f64, f64)| {
+        if let Some(item) = form_item {
+            let value = if range {
+                Value::from(vec![start, end])
+            } else {
+                Value::from(start)
+            };
+            item.trigger(event, value);
+        }
+    };
+
     let mut dragging = use_signal(|| false);
     let mut active_handle = use_signal(|| 0); // 0: left/single, 1: right
     let mut focused = use_signal(|| false);
@@ -245,11 +287,7 @@ pub fn Slider(props: SliderProps) -> Element {
 
     let class_name = format!(
         "ant-slider {} {} {} {} {} {}",
-        if props.disabled {
-            "ant-slider-disabled"
-        } else {
-            ""
-        },
+        if disabled { "ant-slider-disabled" } else { "" },
         if props.vertical {
             "ant-slider-vertical"
         } else {
@@ -258,8 +296,8 @@ pub fn Slider(props: SliderProps) -> Element {
         if props.range { "ant-slider-range" } else { "" },
         if focused() { "ant-slider-focused" } else { "" },
         format!("ant-slider-{}", props.size),
-        if props.status != SliderStatus::Default {
-            format!("ant-slider-status-{}", props.status)
+        if status != SliderStatus::Default {
+            format!("ant-slider-status-{}", status)
         } else {
             String::new()
         },
@@ -287,7 +325,7 @@ pub fn Slider(props: SliderProps) -> Element {
     };
 
     let mut handle_mouse_down = move |handle_index: usize| {
-        if props.disabled || props.readonly {
+        if disabled || props.readonly {
             return;
         }
 
@@ -318,7 +356,7 @@ pub fn Slider(props: SliderProps) -> Element {
     };
 
     let handle_track_click = move |_e: MouseEvent| {
-        if props.disabled || props.readonly {
+        if disabled || props.readonly {
             return;
         }
 
@@ -338,12 +376,14 @@ pub fn Slider(props: SliderProps) -> Element {
                 };
 
             current_value.set(new_range);
+            trigger_form("on_change", new_range);
 
             if let Some(handler) = &props.on_range_change {
                 handler.call(new_range);
             }
         } else {
             current_value.set((normalized_value, normalized_value));
+            trigger_form("on_change", (normalized_value, normalized_value));
 
             if let Some(handler) = &props.on_change {
                 handler.call(normalized_value);
@@ -352,7 +392,7 @@ pub fn Slider(props: SliderProps) -> Element {
     };
 
     let handle_key_down = move |e: KeyboardEvent| {
-        if props.disabled || props.readonly {
+        if disabled || props.readonly {
             return;
         }
 
@@ -399,12 +439,14 @@ pub fn Slider(props: SliderProps) -> Element {
             };
 
             current_value.set(new_range);
+            trigger_form("on_change", new_range);
 
             if let Some(handler) = &props.on_range_change {
                 handler.call(new_range);
             }
         } else {
             current_value.set((new_value, new_value));
+            trigger_form("on_change", (new_value, new_value));
 
             if let Some(handler) = &props.on_change {
                 handler.call(new_value);
@@ -421,6 +463,7 @@ pub fn Slider(props: SliderProps) -> Element {
 
     let handle_blur = move |e: FocusEvent| {
         focused.set(false);
+        trigger_form("on_blur", current_value());
         if let Some(handler) = &props.on_blur {
             handler.call(e);
         }
@@ -466,7 +509,7 @@ pub fn Slider(props: SliderProps) -> Element {
         div {
             class: class_name,
             style: props.style.as_deref().unwrap_or(""),
-            tabindex: if props.disabled { "-1" } else { "0" },
+            tabindex: if disabled { "-1" } else { "0" },
             autofocus: props.auto_focus,
             onkeydown: handle_key_down,
             onfocus: handle_focus,
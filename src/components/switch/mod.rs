@@ -28,6 +28,7 @@
 //! }
 //! ```
 
+use crate::components::form::use_form_item_control;
 use dioxus::prelude::*;
 use serde::{Deserialize, Serialize};
 
@@ -133,20 +134,26 @@ pub struct SwitchProps {
 /// - `id`: 自定义 id
 #[component]
 pub fn Switch(props: SwitchProps) -> Element {
+    let form_item = use_form_item_control();
+
     // 内部状态管理
     let mut internal_checked = use_signal(|| props.default_checked);
 
-    // 使用受控模式还是非受控模式
+    // 使用受控模式还是非受控模式，位于 FormItem 内时使用字段值
+    let form_checked = form_item.and_then(|item| item.value_for("checked", "checked"));
     let is_controlled = props.onchange.is_some();
-    let current_checked = if is_controlled {
+    let current_checked = if let Some(value) = &form_checked {
+        value.as_bool().unwrap_or_default()
+    } else if is_controlled {
         props.checked
     } else {
         internal_checked()
     };
+    let disabled = props.disabled || form_item.is_some_and(|item| item.is_disabled());
 
     // 处理点击事件
     let handle_click = move |evt: MouseEvent| {
-        if props.disabled || props.loading {
+        if disabled || props.loading {
             return;
         }
 
@@ -157,6 +164,10 @@ pub fn Switch(props: SwitchProps) -> Element {
             internal_checked.set(new_checked);
         }
 
+        if let Some(item) = form_item {
+            item.trigger("on_change", new_checked);
+        }
+
         // 触发回调
         if let Some(onchange) = &props.onchange {
             onchange.call(new_checked);
@@ -174,7 +185,7 @@ pub fn Switch(props: SwitchProps) -> Element {
         class_names.push("ant-switch-checked");
     }
 
-    if props.disabled {
+    if disabled {
         class_names.push("ant-switch-disabled");
     }
 
@@ -202,7 +213,7 @@ pub fn Switch(props: SwitchProps) -> Element {
             r#type: "button",
             role: "switch",
             "aria-checked": "{current_checked}",
-            disabled: props.disabled || props.loading,
+            disabled: disabled || props.loading,
             autofocus: props.auto_focus,
             onclick: handle_click,
 
@@ -0,0 +1,405 @@
+//! Table 列定义
+//!
+//! 列通过闭包从类型化的行数据 `T` 中提取单元格文本或渲染单元格内容。
+
+use super::{CellEditorFn, CellSetterFn, FilterFn, SortOrder, SorterFn, TableFilter};
+use crate::components::form::FormRule;
+use crate::utils::rc_option_eq;
+use dioxus::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::rc::Rc;
+
+/// 单元格取值函数，返回单元格的文本内容
+pub type CellValueFn<T> = Rc<dyn Fn(&T) -> String>;
+
+/// 单元格渲染函数，参数为行数据与行索引
+pub type CellRenderFn<T> = Rc<dyn Fn(&T, usize) -> Element>;
+
+/// 单元格属性函数，参数为行数据与行索引
+pub type CellPropsFn<T> = Rc<dyn Fn(&T, usize) -> TableCellProps>;
+
+/// 单元格的合并属性
+///
+/// `col_span` 或 `row_span` 为 `Some(0)` 时该单元格不渲染，用于被其他单元格合并的位置。
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct TableCellProps {
+    /// 合并的列数
+    pub col_span: Option<usize>,
+    /// 合并的行数
+    pub row_span: Option<usize>,
+}
+
+impl TableCellProps {
+    /// 单元格是否被其他单元格合并而不渲染
+    pub fn is_hidden(&self) -> bool {
+        self.col_span == Some(0) || self.row_span == Some(0)
+    }
+}
+
+/// 表格对齐方式
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum TableAlign {
+    Left,
+    Center,
+    Right,
+}
+
+impl TableAlign {
+    /// 获取对齐方式对应的CSS类名
+    pub fn to_class(&self) -> &'static str {
+        match self {
+            TableAlign::Left => "ant-table-cell-left",
+            TableAlign::Center => "ant-table-cell-center",
+            TableAlign::Right => "ant-table-cell-right",
+        }
+    }
+}
+
+/// 表格固定列
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum TableFixed {
+    Left,
+    Right,
+}
+
+/// 表格列配置
+///
+/// ```rust,ignore
+/// let column = TableColumn::new("name", "姓名")
+///     .value(|user: &User| user.name.clone())
+///     .width("150px");
+/// ```
+pub struct TableColumn<T> {
+    /// 列标题
+    pub title: String,
+    /// 列的唯一标识
+    pub key: String,
+    /// 从行数据中提取单元格文本
+    pub value: Option<CellValueFn<T>>,
+    /// 自定义单元格渲染，优先于 `value`
+    pub render: Option<CellRenderFn<T>>,
+    /// 列宽度
+    pub width: Option<String>,
+    /// 设置列的对齐方式
+    pub align: Option<TableAlign>,
+    /// 是否支持排序，未设置 `sorter` 时按单元格文本排序
+    pub sortable: bool,
+    /// 排序比较函数
+    pub sorter: Option<SorterFn<T>>,
+    /// 默认排序方向
+    pub default_sort_order: Option<SortOrder>,
+    /// 多列排序的优先级，数值越大优先级越高；未设置时点击该列会清除其他列的排序
+    pub sort_multiple: Option<u32>,
+    /// 表头的筛选菜单项
+    pub filters: Vec<TableFilter>,
+    /// 自定义筛选函数，未设置时按单元格文本完全匹配
+    pub on_filter: Option<FilterFn<T>>,
+    /// 是否多选筛选，为 false 时使用单选
+    pub filter_multiple: bool,
+    /// 筛选菜单是否显示搜索框
+    pub filter_search: bool,
+    /// 筛选的受控值
+    pub filtered_value: Option<Vec<String>>,
+    /// 默认筛选值
+    pub default_filtered_value: Option<Vec<String>>,
+    /// 是否固定列
+    pub fixed: Option<TableFixed>,
+    /// 表头是否显示下一次排序的 tooltip 提示
+    pub show_sorter_tooltip: bool,
+    /// 将编辑后的文本写回行数据，设置后该列可编辑
+    pub setter: Option<CellSetterFn<T>>,
+    /// 单元格编辑器，未设置时使用文本输入框
+    pub editor: Option<CellEditorFn>,
+    /// 编辑时的校验规则
+    pub rules: Vec<FormRule>,
+    /// 设置单元格的合并属性
+    pub on_cell: Option<CellPropsFn<T>>,
+    /// 分组表头的子列，设置后该列仅作为分组标题
+    pub children: Vec<TableColumn<T>>,
+}
+
+impl<T> TableColumn<T> {
+    /// 创建列
+    pub fn new(key: &str, title: &str) -> Self {
+        Self {
+            title: title.to_string(),
+            key: key.to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// 创建分组列
+    pub fn group(key: &str, title: &str, children: Vec<TableColumn<T>>) -> Self {
+        Self {
+            children,
+            ..Self::new(key, title)
+        }
+    }
+
+    /// 设置单元格取值函数
+    pub fn value<F>(mut self, value: F) -> Self
+    where
+        F: Fn(&T) -> String + 'static,
+    {
+        self.value = Some(Rc::new(value));
+        self
+    }
+
+    /// 设置单元格渲染函数
+    pub fn render<F>(mut self, render: F) -> Self
+    where
+        F: Fn(&T, usize) -> Element + 'static,
+    {
+        self.render = Some(Rc::new(render));
+        self
+    }
+
+    /// 启用按单元格文本排序
+    pub fn sortable(mut self) -> Self {
+        self.sortable = true;
+        self
+    }
+
+    /// 设置排序比较函数
+    pub fn sorter<F>(mut self, sorter: F) -> Self
+    where
+        F: Fn(&T, &T) -> std::cmp::Ordering + 'static,
+    {
+        self.sorter = Some(Rc::new(sorter));
+        self
+    }
+
+    /// 设置默认排序方向
+    pub fn default_sort_order(mut self, order: SortOrder) -> Self {
+        self.default_sort_order = Some(order);
+        self
+    }
+
+    /// 设置多列排序优先级
+    pub fn sort_multiple(mut self, priority: u32) -> Self {
+        self.sort_multiple = Some(priority);
+        self
+    }
+
+    /// 是否可排序
+    pub fn is_sortable(&self) -> bool {
+        self.sortable || self.sorter.is_some()
+    }
+
+    /// 设置筛选菜单项
+    pub fn filters(mut self, filters: Vec<TableFilter>) -> Self {
+        self.filters = filters;
+        self
+    }
+
+    /// 设置筛选函数
+    pub fn on_filter<F>(mut self, on_filter: F) -> Self
+    where
+        F: Fn(&str, &T) -> bool + 'static,
+    {
+        self.on_filter = Some(Rc::new(on_filter));
+        self
+    }
+
+    /// 设置是否多选筛选
+    pub fn filter_multiple(mut self, multiple: bool) -> Self {
+        self.filter_multiple = multiple;
+        self
+    }
+
+    /// 在筛选菜单中显示搜索框
+    pub fn filter_search(mut self) -> Self {
+        self.filter_search = true;
+        self
+    }
+
+    /// 设置受控的筛选值
+    pub fn filtered_value(mut self, values: Vec<String>) -> Self {
+        self.filtered_value = Some(values);
+        self
+    }
+
+    /// 设置默认筛选值
+    pub fn default_filtered_value(mut self, values: Vec<String>) -> Self {
+        self.default_filtered_value = Some(values);
+        self
+    }
+
+    /// 是否可筛选
+    pub fn is_filterable(&self) -> bool {
+        !self.filters.is_empty()
+    }
+
+    /// 设置列宽度
+    pub fn width(mut self, width: &str) -> Self {
+        self.width = Some(width.to_string());
+        self
+    }
+
+    /// 设置对齐方式
+    pub fn align(mut self, align: TableAlign) -> Self {
+        self.align = Some(align);
+        self
+    }
+
+    /// 设置固定列
+    pub fn fixed(mut self, fixed: TableFixed) -> Self {
+        self.fixed = Some(fixed);
+        self
+    }
+
+    /// 设置列可编辑，`setter` 将编辑后的文本写回行数据
+    pub fn editable<F>(mut self, setter: F) -> Self
+    where
+        F: Fn(&mut T, &str) + 'static,
+    {
+        self.setter = Some(Rc::new(setter));
+        self
+    }
+
+    /// 设置单元格编辑器
+    pub fn editor(mut self, editor: CellEditorFn) -> Self {
+        self.editor = Some(editor);
+        self
+    }
+
+    /// 设置编辑时的校验规则
+    pub fn rules(mut self, rules: Vec<FormRule>) -> Self {
+        self.rules = rules;
+        self
+    }
+
+    /// 设置单元格的合并属性
+    pub fn on_cell<F>(mut self, on_cell: F) -> Self
+    where
+        F: Fn(&T, usize) -> TableCellProps + 'static,
+    {
+        self.on_cell = Some(Rc::new(on_cell));
+        self
+    }
+
+    /// 获取单元格的合并属性
+    pub fn cell_props(&self, row: &T, index: usize) -> TableCellProps {
+        self.on_cell
+            .as_ref()
+            .map(|on_cell| on_cell(row, index))
+            .unwrap_or_default()
+    }
+
+    /// 是否可编辑
+    pub fn is_editable(&self) -> bool {
+        self.setter.is_some()
+    }
+
+    /// 获取单元格文本
+    pub fn cell_text(&self, row: &T) -> String {
+        self.value
+            .as_ref()
+            .map(|value| value(row))
+            .unwrap_or_default()
+    }
+
+    /// 渲染单元格内容
+    pub fn render_cell(&self, row: &T, index: usize) -> Element {
+        match &self.render {
+            Some(render) => render(row, index),
+            None => {
+                let text = self.cell_text(row);
+                rsx! { {text} }
+            }
+        }
+    }
+
+    /// 单元格对齐方式对应的CSS类名
+    pub(crate) fn align_class(&self) -> &'static str {
+        self.align.as_ref().map(TableAlign::to_class).unwrap_or("")
+    }
+}
+
+impl<T> Default for TableColumn<T> {
+    fn default() -> Self {
+        Self {
+            title: String::new(),
+            key: String::new(),
+            value: None,
+            render: None,
+            width: None,
+            align: None,
+            sortable: false,
+            sorter: None,
+            default_sort_order: None,
+            sort_multiple: None,
+            filters: Vec::new(),
+            on_filter: None,
+            filter_multiple: true,
+            filter_search: false,
+            filtered_value: None,
+            default_filtered_value: None,
+            fixed: None,
+            show_sorter_tooltip: true,
+            setter: None,
+            editor: None,
+            rules: Vec::new(),
+            on_cell: None,
+            children: Vec::new(),
+        }
+    }
+}
+
+impl<T> Clone for TableColumn<T> {
+    fn clone(&self) -> Self {
+        Self {
+            title: self.title.clone(),
+            key: self.key.clone(),
+            value: self.value.clone(),
+            render: self.render.clone(),
+            width: self.width.clone(),
+            align: self.align.clone(),
+            sortable: self.sortable,
+            sorter: self.sorter.clone(),
+            default_sort_order: self.default_sort_order,
+            sort_multiple: self.sort_multiple,
+            filters: self.filters.clone(),
+            on_filter: self.on_filter.clone(),
+            filter_multiple: self.filter_multiple,
+            filter_search: self.filter_search,
+            filtered_value: self.filtered_value.clone(),
+            default_filtered_value: self.default_filtered_value.clone(),
+            fixed: self.fixed.clone(),
+            show_sorter_tooltip: self.show_sorter_tooltip,
+            setter: self.setter.clone(),
+            editor: self.editor.clone(),
+            rules: self.rules.clone(),
+            on_cell: self.on_cell.clone(),
+            children: self.children.clone(),
+        }
+    }
+}
+
+impl<T> PartialEq for TableColumn<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.title == other.title
+            && self.key == other.key
+            && rc_option_eq(&self.value, &other.value)
+            && rc_option_eq(&self.render, &other.render)
+            && self.width == other.width
+            && self.align == other.align
+            && self.sortable == other.sortable
+            && rc_option_eq(&self.sorter, &other.sorter)
+            && self.default_sort_order == other.default_sort_order
+            && self.sort_multiple == other.sort_multiple
+            && self.filters == other.filters
+            && rc_option_eq(&self.on_filter, &other.on_filter)
+            && self.filter_multiple == other.filter_multiple
+            && self.filter_search == other.filter_search
+            && self.filtered_value == other.filtered_value
+            && self.default_filtered_value == other.default_filtered_value
+            && self.fixed == other.fixed
+            && self.show_sorter_tooltip == other.show_sorter_tooltip
+            && rc_option_eq(&self.setter, &other.setter)
+            && rc_option_eq(&self.editor, &other.editor)
+            && self.rules == other.rules
+            && rc_option_eq(&self.on_cell, &other.on_cell)
+            && self.children == other.children
+    }
+}
@@ -0,0 +1,94 @@
+//! Table 列状态
+//!
+//! 列的顺序、宽度与显隐状态，可通过 serde 序列化后保存到本地存储或后端，之后再恢复。
+
+use super::TableColumn;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// 拖拽调整列宽时的最小宽度
+pub const MIN_COLUMN_WIDTH: f64 = 50.0;
+
+/// 用户调整后的列布局
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ColumnState {
+    /// 列的显示顺序（列的 key），未列出的列按原有顺序排在最后
+    pub order: Vec<String>,
+    /// 调整后的列宽（像素）
+    pub widths: HashMap<String, f64>,
+    /// 隐藏的列
+    pub hidden: Vec<String>,
+}
+
+impl ColumnState {
+    /// 按列状态调整列配置：重新排序、覆盖列宽并移除隐藏的列
+    pub fn apply<T>(&self, columns: &[TableColumn<T>]) -> Vec<TableColumn<T>> {
+        self.ordered(columns)
+            .into_iter()
+            .filter(|column| !self.is_hidden(&column.key))
+            .map(|column| match self.widths.get(&column.key) {
+                Some(width) => column.width(&format!("{width}px")),
+                None => column,
+            })
+            .collect()
+    }
+
+    /// 按列状态中的顺序排列所有列（包含隐藏的列）
+    pub fn ordered<T>(&self, columns: &[TableColumn<T>]) -> Vec<TableColumn<T>> {
+        let position = |key: &str| {
+            self.order
+                .iter()
+                .position(|k| k == key)
+                .unwrap_or(self.order.len())
+        };
+        let mut ordered = columns.to_vec();
+        ordered.sort_by_key(|column| position(&column.key));
+        ordered
+    }
+
+    /// 列是否隐藏
+    pub fn is_hidden(&self, key: &str) -> bool {
+        self.hidden.iter().any(|k| k == key)
+    }
+
+    /// 将列 `from` 移动到列 `to` 的位置
+    pub fn move_column<T>(&self, columns: &[TableColumn<T>], from: &str, to: &str) -> Self {
+        let mut order: Vec<String> = self
+            .ordered(columns)
+            .into_iter()
+            .map(|column| column.key)
+            .collect();
+        if let (Some(from_index), Some(to_index)) = (
+            order.iter().position(|k| k == from),
+            order.iter().position(|k| k == to),
+        ) {
+            let key = order.remove(from_index);
+            order.insert(to_index, key);
+        }
+        Self {
+            order,
+            ..self.clone()
+        }
+    }
+
+    /// 设置列宽，不小于 [`MIN_COLUMN_WIDTH`]
+    pub fn resize(&self, key: &str, width: f64) -> Self {
+        let mut state = self.clone();
+        state
+            .widths
+            .insert(key.to_string(), width.max(MIN_COLUMN_WIDTH).round());
+        state
+    }
+
+    /// 切换列的显隐
+    pub fn toggle_hidden(&self, key: &str) -> Self {
+        let mut state = self.clone();
+        if state.is_hidden(key) {
+            state.hidden.retain(|k| k != key);
+        } else {
+            state.hidden.push(key.to_string());
+        }
+        state
+    }
+}
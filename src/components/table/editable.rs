@@ -0,0 +1,270 @@
+//! Table 可编辑单元格
+//!
+//! 单元格编辑器、行/单元格的编辑状态以及基于 `FormRule` 的编辑校验。
+
+use super::TableColumn;
+use crate::components::date_picker::{DatePicker, DatePickerStatus};
+use crate::components::form::{FormField, FormRule};
+use crate::components::input::{Input, InputStatus};
+use crate::components::input_number::{InputNumber, InputNumberStatus};
+use crate::components::select::{OptionData, Select, SelectStatus};
+use dioxus::prelude::*;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+/// 单元格编辑器渲染函数
+pub type CellEditorFn = Rc<dyn Fn(CellEditContext) -> Element>;
+
+/// 将编辑后的文本写回行数据
+pub type CellSetterFn<T> = Rc<dyn Fn(&mut T, &str)>;
+
+/// 编辑模式
+#[derive(Debug, Clone, PartialEq, Default)]
+pub enum TableEditMode {
+    /// 点击单元格编辑，回车或失焦时保存
+    #[default]
+    Cell,
+    /// 整行编辑，通过操作列保存或取消
+    Row,
+}
+
+/// 单元格编辑器的上下文
+#[derive(Clone, PartialEq)]
+pub struct CellEditContext {
+    /// 当前编辑的文本
+    pub value: String,
+    /// 校验错误信息
+    pub error: Option<String>,
+    /// 失焦或选择后是否立即保存，单元格编辑模式下为 true
+    pub save_on_blur: bool,
+    /// 修改编辑值
+    pub on_change: EventHandler<String>,
+    /// 保存编辑
+    pub on_save: EventHandler<()>,
+    /// 取消编辑
+    pub on_cancel: EventHandler<()>,
+}
+
+/// 内置的单元格编辑器
+pub struct TableCellEditor;
+
+impl TableCellEditor {
+    /// 文本输入框
+    pub fn input() -> CellEditorFn {
+        Rc::new(|ctx: CellEditContext| {
+            let on_change = ctx.on_change;
+            let on_save = ctx.on_save;
+            rsx! {
+                Input {
+                    value: ctx.value.clone(),
+                    status: if ctx.error.is_some() { InputStatus::Error } else { InputStatus::Normal },
+                    on_change: move |value| on_change.call(value),
+                    on_press_enter: move |_| on_save.call(()),
+                    on_blur: move |_| {
+                        if ctx.save_on_blur {
+                            on_save.call(());
+                        }
+                    },
+                }
+            }
+        })
+    }
+
+    /// 数字输入框
+    pub fn input_number() -> CellEditorFn {
+        Rc::new(|ctx: CellEditContext| {
+            let on_change = ctx.on_change;
+            let on_save = ctx.on_save;
+            rsx! {
+                InputNumber {
+                    value: ctx.value.trim().parse::<f64>().ok(),
+                    status: if ctx.error.is_some() { InputNumberStatus::Error } else { InputNumberStatus::Default },
+                    on_change: move |value: Option<f64>| {
+                        on_change.call(value.map(|value| value.to_string()).unwrap_or_default())
+                    },
+                    on_press_enter: move |_| on_save.call(()),
+                    on_blur: move |_| {
+                        if ctx.save_on_blur {
+                            on_save.call(());
+                        }
+                    },
+                }
+            }
+        })
+    }
+
+    /// 下拉选择，选项为 `(value, label)`
+    pub fn select(options: Vec<(String, String)>) -> CellEditorFn {
+        Rc::new(move |ctx: CellEditContext| {
+            let on_change = ctx.on_change;
+            let on_save = ctx.on_save;
+            rsx! {
+                Select {
+                    value: Some(ctx.value.clone()).filter(|value| !value.is_empty()),
+                    status: if ctx.error.is_some() { SelectStatus::Error } else { SelectStatus::Normal },
+                    style: "width: 100%;",
+                    on_change: move |value: Option<String>| {
+                        on_change.call(value.unwrap_or_default());
+                        if ctx.save_on_blur {
+                            on_save.call(());
+                        }
+                    },
+                    options: options
+                        .iter()
+                        .map(|(value, label)| OptionData::new(value.clone(), label.clone()))
+                        .collect::<Vec<_>>(),
+                }
+            }
+        })
+    }
+
+    /// 日期选择框
+    pub fn date_picker() -> CellEditorFn {
+        Rc::new(|ctx: CellEditContext| {
+            let on_change = ctx.on_change;
+            let on_save = ctx.on_save;
+            rsx! {
+                DatePicker {
+                    value: Some(ctx.value.clone()).filter(|value| !value.is_empty()),
+                    status: if ctx.error.is_some() { DatePickerStatus::Error } else { DatePickerStatus::Default },
+                    on_change: move |value: Option<String>| {
+                        on_change.call(value.unwrap_or_default());
+                        if ctx.save_on_blur {
+                            on_save.call(());
+                        }
+                    },
+                }
+            }
+        })
+    }
+}
+
+/// 可编辑表格配置
+pub struct TableEditable<T> {
+    /// 编辑模式
+    pub mode: TableEditMode,
+    /// 整行编辑模式下是否在末尾追加编辑/保存/取消的操作列
+    pub show_action_column: bool,
+    /// 操作列标题，未设置时使用语言包中的文案
+    pub action_title: Option<String>,
+    /// 保存编辑时的回调，参数为编辑后的行数据
+    pub on_save: Option<EventHandler<T>>,
+    /// 取消编辑时的回调，参数为原始行数据
+    pub on_cancel: Option<EventHandler<T>>,
+}
+
+impl<T> Default for TableEditable<T> {
+    fn default() -> Self {
+        Self {
+            mode: TableEditMode::default(),
+            show_action_column: true,
+            action_title: None,
+            on_save: None,
+            on_cancel: None,
+        }
+    }
+}
+
+impl<T> Clone for TableEditable<T> {
+    fn clone(&self) -> Self {
+        Self {
+            mode: self.mode.clone(),
+            show_action_column: self.show_action_column,
+            action_title: self.action_title.clone(),
+            on_save: self.on_save,
+            on_cancel: self.on_cancel,
+        }
+    }
+}
+
+impl<T: 'static> PartialEq for TableEditable<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.mode == other.mode
+            && self.show_action_column == other.show_action_column
+            && self.action_title == other.action_title
+            && self.on_save == other.on_save
+            && self.on_cancel == other.on_cancel
+    }
+}
+
+/// 当前的编辑状态
+#[derive(Debug, Clone, PartialEq, Default)]
+pub(crate) struct EditingState {
+    /// 正在编辑的行
+    pub row_key: String,
+    /// 单元格编辑模式下正在编辑的列，整行编辑时为 None
+    pub column_key: Option<String>,
+    /// 各列的编辑值
+    pub draft: HashMap<String, String>,
+    /// 各列的校验错误
+    pub errors: HashMap<String, String>,
+}
+
+impl EditingState {
+    /// 以行数据的当前值开始编辑
+    pub fn start<T>(
+        row_key: String,
+        row: &T,
+        columns: &[TableColumn<T>],
+        column_key: Option<&str>,
+    ) -> Self {
+        let draft = columns
+            .iter()
+            .filter(|column| column.is_editable())
+            .filter(|column| column_key.is_none() || column_key == Some(column.key.as_str()))
+            .map(|column| (column.key.clone(), column.cell_text(row)))
+            .collect();
+        Self {
+            row_key,
+            column_key: column_key.map(str::to_string),
+            draft,
+            errors: HashMap::new(),
+        }
+    }
+
+    /// 某个单元格是否处于编辑状态
+    pub fn is_editing(&self, row_key: &str, column_key: &str) -> bool {
+        self.row_key == row_key && self.draft.contains_key(column_key)
+    }
+}
+
+/// 按列上的校验规则校验编辑值，返回各列的错误信息
+pub fn validate_cell_edits<T>(
+    columns: &[TableColumn<T>],
+    draft: &HashMap<String, String>,
+) -> HashMap<String, String> {
+    columns
+        .iter()
+        .filter_map(|column| {
+            let value = draft.get(&column.key)?;
+            let mut field = FormField::new(&column.key);
+            field.value = value.clone().into();
+            field.rules = column.rules.clone();
+            if field.validate() {
+                None
+            } else {
+                field.error.map(|error| (column.key.clone(), error))
+            }
+        })
+        .collect()
+}
+
+/// 将编辑值写回行数据，返回编辑后的新行
+pub fn apply_cell_edits<T: Clone>(
+    row: &T,
+    columns: &[TableColumn<T>],
+    draft: &HashMap<String, String>,
+) -> T {
+    let mut edited = row.clone();
+    for column in columns {
+        if let (Some(setter), Some(value)) = (&column.setter, draft.get(&column.key)) {
+            setter(&mut edited, value);
+        }
+    }
+    edited
+}
+
+/// 列上的校验规则是否包含必填项
+pub(crate) fn is_required(rules: &[FormRule]) -> bool {
+    rules.iter().any(|rule| rule.required)
+}
@@ -0,0 +1,271 @@
+//! Table 展开行与树形数据
+//!
+//! 展开行配置、树形数据的展平以及父子行之间的选择联动。
+
+use super::{sort_rows, SorterState, TableColumn, TableRow};
+use crate::utils::rc_option_eq;
+use dioxus::prelude::*;
+use std::collections::HashSet;
+use std::rc::Rc;
+
+/// 展开行渲染函数，参数为行数据与行索引
+pub type ExpandedRowRenderFn<T> = Rc<dyn Fn(&T, usize) -> Element>;
+
+/// 判断行是否可展开
+pub type RowExpandableFn<T> = Rc<dyn Fn(&T) -> bool>;
+
+/// 展开行配置
+pub struct TableExpandable<T> {
+    /// 额外的展开行内容
+    pub expanded_row_render: Option<ExpandedRowRenderFn<T>>,
+    /// 设置行是否可展开，未设置时所有行均可展开
+    pub row_expandable: Option<RowExpandableFn<T>>,
+    /// 展开的行 key，设置后为受控模式
+    pub expanded_row_keys: Option<Vec<String>>,
+    /// 默认展开的行 key
+    pub default_expanded_row_keys: Vec<String>,
+    /// 初始时是否展开所有行
+    pub default_expand_all_rows: bool,
+    /// 是否通过点击行来展开
+    pub expand_row_by_click: bool,
+    /// 树形数据每一层的缩进宽度（像素）
+    pub indent_size: f64,
+    /// 点击展开图标时的回调，参数为是否展开与行数据
+    pub on_expand: Option<EventHandler<(bool, T)>>,
+    /// 展开的行变化时的回调
+    pub on_expanded_rows_change: Option<EventHandler<Vec<String>>>,
+}
+
+impl<T> Default for TableExpandable<T> {
+    fn default() -> Self {
+        Self {
+            expanded_row_render: None,
+            row_expandable: None,
+            expanded_row_keys: None,
+            default_expanded_row_keys: Vec::new(),
+            default_expand_all_rows: false,
+            expand_row_by_click: false,
+            indent_size: 15.0,
+            on_expand: None,
+            on_expanded_rows_change: None,
+        }
+    }
+}
+
+impl<T> Clone for TableExpandable<T> {
+    fn clone(&self) -> Self {
+        Self {
+            expanded_row_render: self.expanded_row_render.clone(),
+            row_expandable: self.row_expandable.clone(),
+            expanded_row_keys: self.expanded_row_keys.clone(),
+            default_expanded_row_keys: self.default_expanded_row_keys.clone(),
+            default_expand_all_rows: self.default_expand_all_rows,
+            expand_row_by_click: self.expand_row_by_click,
+            indent_size: self.indent_size,
+            on_expand: self.on_expand,
+            on_expanded_rows_change: self.on_expanded_rows_change,
+        }
+    }
+}
+
+impl<T: 'static> PartialEq for TableExpandable<T> {
+    fn eq(&self, other: &Self) -> bool {
+        rc_option_eq(&self.expanded_row_render, &other.expanded_row_render)
+            && rc_option_eq(&self.row_expandable, &other.row_expandable)
+            && self.expanded_row_keys == other.expanded_row_keys
+            && self.default_expanded_row_keys == other.default_expanded_row_keys
+            && self.default_expand_all_rows == other.default_expand_all_rows
+            && self.expand_row_by_click == other.expand_row_by_click
+            && self.indent_size == other.indent_size
+            && self.on_expand == other.on_expand
+            && self.on_expanded_rows_change == other.on_expanded_rows_change
+    }
+}
+
+impl<T: TableRow> TableExpandable<T> {
+    /// 行是否可以展开额外内容
+    pub fn is_row_expandable(&self, row: &T) -> bool {
+        self.expanded_row_render.is_some()
+            && self
+                .row_expandable
+                .as_ref()
+                .map(|row_expandable| row_expandable(row))
+                .unwrap_or(true)
+    }
+
+    /// 计算初始展开的行
+    pub(crate) fn initial_expanded_keys(&self, rows: &[T]) -> Vec<String> {
+        if self.default_expand_all_rows {
+            flatten_tree(rows)
+                .iter()
+                .filter(|row| !row.children().is_empty() || self.is_row_expandable(row))
+                .map(TableRow::row_key)
+                .collect()
+        } else {
+            self.default_expanded_row_keys.clone()
+        }
+    }
+}
+
+/// 展平后用于渲染的行
+#[derive(Debug, Clone, PartialEq)]
+pub struct FlatRow<T> {
+    /// 行数据
+    pub row: T,
+    /// 树形数据中的层级，顶层为 0
+    pub depth: usize,
+    /// 是否有子行
+    pub has_children: bool,
+    /// 是否已展开
+    pub expanded: bool,
+}
+
+/// 按展开状态将树形数据展平为渲染顺序，子行按相同的排序状态排序
+pub fn flatten_rows<T: TableRow>(
+    rows: &[T],
+    expanded_keys: &[String],
+    columns: &[TableColumn<T>],
+    sorters: &[SorterState],
+) -> Vec<FlatRow<T>> {
+    fn walk<T: TableRow>(
+        rows: &[T],
+        depth: usize,
+        expanded_keys: &HashSet<&str>,
+        columns: &[TableColumn<T>],
+        sorters: &[SorterState],
+        output: &mut Vec<FlatRow<T>>,
+    ) {
+        for row in rows {
+            let children = row.children();
+            let expanded = expanded_keys.contains(row.row_key().as_str());
+            output.push(FlatRow {
+                row: row.clone(),
+                depth,
+                has_children: !children.is_empty(),
+                expanded,
+            });
+            if expanded && !children.is_empty() {
+                let children = sort_rows(children, columns, sorters);
+                walk(
+                    &children,
+                    depth + 1,
+                    expanded_keys,
+                    columns,
+                    sorters,
+                    output,
+                );
+            }
+        }
+    }
+
+    let expanded_keys: HashSet<&str> = expanded_keys.iter().map(String::as_str).collect();
+    let mut output = Vec::with_capacity(rows.len());
+    walk(rows, 0, &expanded_keys, columns, sorters, &mut output);
+    output
+}
+
+/// 按先序遍历收集所有行（包含所有层级的子行）
+pub fn flatten_tree<T: TableRow>(rows: &[T]) -> Vec<T> {
+    let mut output = Vec::new();
+    for row in rows {
+        output.push(row.clone());
+        output.extend(flatten_tree(row.children()));
+    }
+    output
+}
+
+/// 切换展开状态，返回新的展开 key 列表
+pub(crate) fn toggle_expanded_key(expanded: &[String], key: &str) -> Vec<String> {
+    if expanded.iter().any(|k| k == key) {
+        expanded.iter().filter(|k| *k != key).cloned().collect()
+    } else {
+        let mut keys = expanded.to_vec();
+        keys.push(key.to_string());
+        keys
+    }
+}
+
+/// 父子联动地选择或取消选择某一行
+///
+/// 选择某行会同时选中其所有子孙行，之后根据子行的选中情况重新计算祖先行的状态。
+pub fn toggle_tree_selection<T: TableRow>(
+    roots: &[T],
+    selected: &[String],
+    row: &T,
+    checked: bool,
+) -> Vec<String> {
+    let affected: Vec<String> = flatten_tree(std::slice::from_ref(row))
+        .iter()
+        .map(TableRow::row_key)
+        .collect();
+
+    let keys = if checked {
+        selected.iter().cloned().chain(affected).collect()
+    } else {
+        let affected: HashSet<&str> = affected.iter().map(String::as_str).collect();
+        selected
+            .iter()
+            .filter(|key| !affected.contains(key.as_str()))
+            .cloned()
+            .collect()
+    };
+
+    normalize_tree_selection(roots, keys)
+}
+
+/// 根据子行的选中情况自下而上修正父行的选中状态：子行全部选中时父行选中，否则父行不选中
+///
+/// 返回的 key 保持原有顺序并去重，新选中的父行追加在末尾。
+pub fn normalize_tree_selection<T: TableRow>(roots: &[T], keys: Vec<String>) -> Vec<String> {
+    fn visit<T: TableRow>(
+        row: &T,
+        selected: &mut HashSet<String>,
+        added: &mut Vec<String>,
+    ) -> bool {
+        let key = row.row_key();
+        let children = row.children();
+        if children.is_empty() {
+            return selected.contains(&key);
+        }
+
+        // 需要访问所有子行以修正更深层的状态，因此不能短路
+        let children_selected: Vec<bool> = children
+            .iter()
+            .map(|child| visit(child, selected, added))
+            .collect();
+        let all_children_selected = children_selected.iter().all(|selected| *selected);
+
+        if all_children_selected {
+            if selected.insert(key.clone()) {
+                added.push(key);
+            }
+        } else {
+            selected.remove(&key);
+        }
+        all_children_selected
+    }
+
+    let mut selected: HashSet<String> = keys.iter().cloned().collect();
+    let mut added = Vec::new();
+    for root in roots {
+        visit(root, &mut selected, &mut added);
+    }
+    // 按原有顺序输出仍被选中的 key，每个 key 只输出一次
+    keys.into_iter()
+        .chain(added)
+        .filter(|key| selected.remove(key))
+        .collect()
+}
+
+/// 行是否处于半选状态：部分子孙行被选中
+pub fn is_row_indeterminate<T: TableRow>(row: &T, selected: &HashSet<&str>) -> bool {
+    let descendants = flatten_tree(row.children());
+    if descendants.is_empty() {
+        return false;
+    }
+    let count = descendants
+        .iter()
+        .filter(|child| selected.contains(child.row_key().as_str()))
+        .count();
+    count > 0 && count < descendants.len()
+}
@@ -0,0 +1,436 @@
+//! Table 导出
+//!
+//! 将筛选、排序后的行导出为 CSV 或 XLSX，并在浏览器中以 Blob 的形式下载。
+
+use super::{flatten_tree, TableColumn, TableRow};
+use dioxus::prelude::*;
+use std::cell::RefCell;
+use std::rc::Rc;
+use wasm_bindgen::JsCast;
+
+/// CSV 的 MIME 类型
+pub const CSV_MIME: &str = "text/csv;charset=utf-8";
+
+/// XLSX 的 MIME 类型
+pub const XLSX_MIME: &str = "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet";
+
+/// CSV 导出选项
+#[derive(Debug, Clone, PartialEq)]
+pub struct CsvOptions {
+    /// 是否在开头写入 UTF-8 BOM，便于 Excel 识别编码
+    pub bom: bool,
+    /// 字段分隔符
+    pub delimiter: char,
+    /// 是否写入表头
+    pub include_header: bool,
+}
+
+impl Default for CsvOptions {
+    fn default() -> Self {
+        Self {
+            bom: false,
+            delimiter: ',',
+            include_header: true,
+        }
+    }
+}
+
+/// 参与导出的列：只导出设置了 `value` 的列
+fn export_columns<T>(columns: &[TableColumn<T>]) -> Vec<&TableColumn<T>> {
+    columns
+        .iter()
+        .filter(|column| column.value.is_some())
+        .collect()
+}
+
+/// 按 RFC 4180 转义 CSV 字段：包含分隔符、引号或换行时用双引号包裹，引号加倍
+pub fn escape_csv_field(field: &str, delimiter: char) -> String {
+    if field.contains(delimiter) || field.contains(['"', '\r', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// 将行数据导出为 CSV，行之间以 CRLF 分隔
+pub fn to_csv<T>(columns: &[TableColumn<T>], rows: &[T], options: &CsvOptions) -> String {
+    let columns = export_columns(columns);
+    let delimiter = options.delimiter.to_string();
+    let mut lines = Vec::with_capacity(rows.len() + 1);
+
+    if options.include_header {
+        lines.push(
+            columns
+                .iter()
+                .map(|column| escape_csv_field(&column.title, options.delimiter))
+                .collect::<Vec<_>>()
+                .join(&delimiter),
+        );
+    }
+    for row in rows {
+        lines.push(
+            columns
+                .iter()
+                .map(|column| escape_csv_field(&column.cell_text(row), options.delimiter))
+                .collect::<Vec<_>>()
+                .join(&delimiter),
+        );
+    }
+
+    let mut csv = String::new();
+    if options.bom {
+        csv.push('\u{feff}');
+    }
+    for line in lines {
+        csv.push_str(&line);
+        csv.push_str("\r\n");
+    }
+    csv
+}
+
+/// 转义 XML 文本
+fn escape_xml(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for ch in text.chars() {
+        match ch {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            // XML 1.0 不允许的控制字符直接丢弃
+            ch if (ch as u32) < 0x20 && !matches!(ch, '\t' | '\n' | '\r') => {}
+            ch => escaped.push(ch),
+        }
+    }
+    escaped
+}
+
+/// 列序号对应的 Excel 列名：0 → A，25 → Z，26 → AA
+fn column_name(mut index: usize) -> String {
+    let mut name = Vec::new();
+    loop {
+        name.push(b'A' + (index % 26) as u8);
+        if index < 26 {
+            break;
+        }
+        index = index / 26 - 1;
+    }
+    name.reverse();
+    String::from_utf8(name).unwrap_or_default()
+}
+
+/// 单元格文本是否可以作为数字写入，避免丢失 `007` 这类前导零
+fn is_numeric_cell(text: &str) -> bool {
+    let digits = text.strip_prefix('-').unwrap_or(text);
+    let leading_zero = digits.len() > 1 && digits.starts_with('0') && !digits.starts_with("0.");
+    !digits.is_empty()
+        && !leading_zero
+        && digits.chars().all(|ch| ch.is_ascii_digit() || ch == '.')
+        && text.parse::<f64>().is_ok_and(f64::is_finite)
+}
+
+/// 生成工作表的 SpreadsheetML
+fn sheet_xml<T>(columns: &[&TableColumn<T>], rows: &[T]) -> String {
+    let cell = |row: usize, col: usize, text: &str, header: bool| {
+        let reference = format!("{}{}", column_name(col), row + 1);
+        if !header && is_numeric_cell(text) {
+            format!("<c r=\"{reference}\"><v>{text}</v></c>")
+        } else {
+            format!(
+                "<c r=\"{reference}\" t=\"inlineStr\"{}><is><t xml:space=\"preserve\">{}</t></is></c>",
+                if header { " s=\"1\"" } else { "" },
+                escape_xml(text)
+            )
+        }
+    };
+
+    let mut xml = String::from(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\" standalone=\"yes\"?>\
+         <worksheet xmlns=\"http://schemas.openxmlformats.org/spreadsheetml/2006/main\"><sheetData>",
+    );
+    xml.push_str("<row r=\"1\">");
+    for (col, column) in columns.iter().enumerate() {
+        xml.push_str(&cell(0, col, &column.title, true));
+    }
+    xml.push_str("</row>");
+    for (index, row) in rows.iter().enumerate() {
+        xml.push_str(&format!("<row r=\"{}\">", index + 2));
+        for (col, column) in columns.iter().enumerate() {
+            xml.push_str(&cell(index + 1, col, &column.cell_text(row), false));
+        }
+        xml.push_str("</row>");
+    }
+    xml.push_str("</sheetData></worksheet>");
+    xml
+}
+
+/// 将行数据导出为只包含一个工作表的 XLSX 文件
+pub fn to_xlsx<T>(columns: &[TableColumn<T>], rows: &[T], sheet_name: &str) -> Vec<u8> {
+    let columns = export_columns(columns);
+    // 工作表名称最长 31 个字符，且不能包含 []:*?/\
+    let sheet_name: String = sheet_name
+        .chars()
+        .filter(|ch| !matches!(ch, '[' | ']' | ':' | '*' | '?' | '/' | '\\'))
+        .take(31)
+        .collect();
+    let sheet_name = if sheet_name.is_empty() {
+        "Sheet1".to_string()
+    } else {
+        sheet_name
+    };
+
+    let files: Vec<(&str, String)> = vec![
+        (
+            "[Content_Types].xml",
+            "<?xml version=\"1.0\" encoding=\"UTF-8\" standalone=\"yes\"?>\
+             <Types xmlns=\"http://schemas.openxmlformats.org/package/2006/content-types\">\
+             <Default Extension=\"rels\" ContentType=\"application/vnd.openxmlformats-package.relationships+xml\"/>\
+             <Default Extension=\"xml\" ContentType=\"application/xml\"/>\
+             <Override PartName=\"/xl/workbook.xml\" ContentType=\"application/vnd.openxmlformats-officedocument.spreadsheetml.sheet.main+xml\"/>\
+             <Override PartName=\"/xl/worksheets/sheet1.xml\" ContentType=\"application/vnd.openxmlformats-officedocument.spreadsheetml.worksheet+xml\"/>\
+             <Override PartName=\"/xl/styles.xml\" ContentType=\"application/vnd.openxmlformats-officedocument.spreadsheetml.styles+xml\"/>\
+             </Types>"
+                .to_string(),
+        ),
+        (
+            "_rels/.rels",
+            "<?xml version=\"1.0\" encoding=\"UTF-8\" standalone=\"yes\"?>\
+             <Relationships xmlns=\"http://schemas.openxmlformats.org/package/2006/relationships\">\
+             <Relationship Id=\"rId1\" Type=\"http://schemas.openxmlformats.org/officeDocument/2006/relationships/officeDocument\" Target=\"xl/workbook.xml\"/>\
+             </Relationships>"
+                .to_string(),
+        ),
+        (
+            "xl/workbook.xml",
+            format!(
+                "<?xml version=\"1.0\" encoding=\"UTF-8\" standalone=\"yes\"?>\
+                 <workbook xmlns=\"http://schemas.openxmlformats.org/spreadsheetml/2006/main\" \
+                 xmlns:r=\"http://schemas.openxmlformats.org/officeDocument/2006/relationships\">\
+                 <sheets><sheet name=\"{}\" sheetId=\"1\" r:id=\"rId1\"/></sheets></workbook>",
+                escape_xml(&sheet_name)
+            ),
+        ),
+        (
+            "xl/_rels/workbook.xml.rels",
+            "<?xml version=\"1.0\" encoding=\"UTF-8\" standalone=\"yes\"?>\
+             <Relationships xmlns=\"http://schemas.openxmlformats.org/package/2006/relationships\">\
+             <Relationship Id=\"rId1\" Type=\"http://schemas.openxmlformats.org/officeDocument/2006/relationships/worksheet\" Target=\"worksheets/sheet1.xml\"/>\
+             <Relationship Id=\"rId2\" Type=\"http://schemas.openxmlformats.org/officeDocument/2006/relationships/styles\" Target=\"styles.xml\"/>\
+             </Relationships>"
+                .to_string(),
+        ),
+        (
+            "xl/styles.xml",
+            // 样式 1 为表头使用的粗体
+            "<?xml version=\"1.0\" encoding=\"UTF-8\" standalone=\"yes\"?>\
+             <styleSheet xmlns=\"http://schemas.openxmlformats.org/spreadsheetml/2006/main\">\
+             <fonts count=\"2\"><font><sz val=\"11\"/><name val=\"Calibri\"/></font>\
+             <font><b/><sz val=\"11\"/><name val=\"Calibri\"/></font></fonts>\
+             <fills count=\"1\"><fill><patternFill patternType=\"none\"/></fill></fills>\
+             <borders count=\"1\"><border><left/><right/><top/><bottom/><diagonal/></border></borders>\
+             <cellStyleXfs count=\"1\"><xf numFmtId=\"0\" fontId=\"0\" fillId=\"0\" borderId=\"0\"/></cellStyleXfs>\
+             <cellXfs count=\"2\"><xf numFmtId=\"0\" fontId=\"0\" fillId=\"0\" borderId=\"0\" xfId=\"0\"/>\
+             <xf numFmtId=\"0\" fontId=\"1\" fillId=\"0\" borderId=\"0\" xfId=\"0\" applyFont=\"1\"/></cellXfs>\
+             </styleSheet>"
+                .to_string(),
+        ),
+        ("xl/worksheets/sheet1.xml", sheet_xml(&columns, rows)),
+    ];
+
+    zip_store(
+        &files
+            .iter()
+            .map(|(name, content)| (*name, content.as_bytes()))
+            .collect::<Vec<_>>(),
+    )
+}
+
+/// CRC-32（IEEE 802.3）校验
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = 0xFFFF_FFFF_u32;
+    for byte in data {
+        crc ^= u32::from(*byte);
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ 0xEDB8_8320
+            } else {
+                crc >> 1
+            };
+        }
+    }
+    !crc
+}
+
+/// 以不压缩（stored）的方式打包 ZIP 文件
+fn zip_store(files: &[(&str, &[u8])]) -> Vec<u8> {
+    let mut output = Vec::new();
+    let mut central = Vec::new();
+
+    for (name, data) in files {
+        let offset = output.len() as u32;
+        let crc = crc32(data);
+        let size = data.len() as u32;
+        let name = name.as_bytes();
+
+        // 本地文件头
+        output.extend_from_slice(&0x0403_4b50_u32.to_le_bytes());
+        output.extend_from_slice(&20_u16.to_le_bytes()); // 解压所需版本
+        output.extend_from_slice(&0x0800_u16.to_le_bytes()); // 文件名使用 UTF-8
+        output.extend_from_slice(&0_u16.to_le_bytes()); // 不压缩
+        output.extend_from_slice(&0_u16.to_le_bytes()); // 修改时间
+        output.extend_from_slice(&0x0021_u16.to_le_bytes()); // 修改日期 1980-01-01
+        output.extend_from_slice(&crc.to_le_bytes());
+        output.extend_from_slice(&size.to_le_bytes());
+        output.extend_from_slice(&size.to_le_bytes());
+        output.extend_from_slice(&(name.len() as u16).to_le_bytes());
+        output.extend_from_slice(&0_u16.to_le_bytes());
+        output.extend_from_slice(name);
+        output.extend_from_slice(data);
+
+        // 中央目录记录
+        central.extend_from_slice(&0x0201_4b50_u32.to_le_bytes());
+        central.extend_from_slice(&20_u16.to_le_bytes()); // 创建版本
+        central.extend_from_slice(&20_u16.to_le_bytes());
+        central.extend_from_slice(&0x0800_u16.to_le_bytes());
+        central.extend_from_slice(&0_u16.to_le_bytes());
+        central.extend_from_slice(&0_u16.to_le_bytes());
+        central.extend_from_slice(&0x0021_u16.to_le_bytes());
+        central.extend_from_slice(&crc.to_le_bytes());
+        central.extend_from_slice(&size.to_le_bytes());
+        central.extend_from_slice(&size.to_le_bytes());
+        central.extend_from_slice(&(name.len() as u16).to_le_bytes());
+        central.extend_from_slice(&0_u16.to_le_bytes()); // 扩展字段长度
+        central.extend_from_slice(&0_u16.to_le_bytes()); // 注释长度
+        central.extend_from_slice(&0_u16.to_le_bytes()); // 磁盘编号
+        central.extend_from_slice(&0_u16.to_le_bytes()); // 内部属性
+        central.extend_from_slice(&0_u32.to_le_bytes()); // 外部属性
+        central.extend_from_slice(&offset.to_le_bytes());
+        central.extend_from_slice(name);
+    }
+
+    let central_offset = output.len() as u32;
+    let central_size = central.len() as u32;
+    output.extend_from_slice(&central);
+
+    // 中央目录结束记录
+    output.extend_from_slice(&0x0605_4b50_u32.to_le_bytes());
+    output.extend_from_slice(&0_u16.to_le_bytes());
+    output.extend_from_slice(&0_u16.to_le_bytes());
+    output.extend_from_slice(&(files.len() as u16).to_le_bytes());
+    output.extend_from_slice(&(files.len() as u16).to_le_bytes());
+    output.extend_from_slice(&central_size.to_le_bytes());
+    output.extend_from_slice(&central_offset.to_le_bytes());
+    output.extend_from_slice(&0_u16.to_le_bytes());
+    output
+}
+
+/// 在浏览器中以 Blob 的形式下载文件，字节直接复制到 `Uint8Array`，不经过脚本文本
+pub fn download_file(bytes: &[u8], file_name: &str, mime: &str) {
+    let Some(document) = web_sys::window().and_then(|window| window.document()) else {
+        return;
+    };
+    let parts = js_sys::Array::of1(&js_sys::Uint8Array::from(bytes));
+    let options = web_sys::BlobPropertyBag::new();
+    options.set_type(mime);
+    let Ok(blob) = web_sys::Blob::new_with_u8_array_sequence_and_options(&parts, &options) else {
+        return;
+    };
+    let Ok(url) = web_sys::Url::create_object_url_with_blob(&blob) else {
+        return;
+    };
+    let link = document
+        .create_element("a")
+        .ok()
+        .and_then(|element| element.dyn_into::<web_sys::HtmlAnchorElement>().ok());
+    if let (Some(link), Some(body)) = (link, document.body()) {
+        link.set_href(&url);
+        link.set_download(file_name);
+        if body.append_child(&link).is_ok() {
+            link.click();
+            link.remove();
+        }
+    }
+    // 下载开始后再释放 URL
+    gloo_timers::callback::Timeout::new(0, move || {
+        let _ = web_sys::Url::revoke_object_url(&url);
+    })
+    .forget();
+}
+
+/// 表格导出时的数据快照
+struct ExportSnapshot<T> {
+    columns: Vec<TableColumn<T>>,
+    rows: Vec<T>,
+}
+
+/// 表格导出器
+///
+/// 通过 [`use_table_exporter`] 创建并传给 `Table` 的 `exporter` 属性，表格每次渲染时
+/// 记录当前显示的列以及筛选、排序后的所有行（不受分页影响，树形数据包含子行）。
+pub struct TableExporter<T> {
+    snapshot: Rc<RefCell<Option<ExportSnapshot<T>>>>,
+}
+
+impl<T> Clone for TableExporter<T> {
+    fn clone(&self) -> Self {
+        Self {
+            snapshot: self.snapshot.clone(),
+        }
+    }
+}
+
+impl<T> PartialEq for TableExporter<T> {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.snapshot, &other.snapshot)
+    }
+}
+
+impl<T: TableRow> Default for TableExporter<T> {
+    fn default() -> Self {
+        Self {
+            snapshot: Rc::new(RefCell::new(None)),
+        }
+    }
+}
+
+impl<T: TableRow> TableExporter<T> {
+    /// 记录表格当前的列与行
+    pub(crate) fn update(&self, columns: &[TableColumn<T>], rows: &[T]) {
+        *self.snapshot.borrow_mut() = Some(ExportSnapshot {
+            columns: columns.to_vec(),
+            rows: flatten_tree(rows),
+        });
+    }
+
+    /// 导出为 CSV 文本
+    pub fn to_csv(&self, options: &CsvOptions) -> String {
+        match &*self.snapshot.borrow() {
+            Some(snapshot) => to_csv(&snapshot.columns, &snapshot.rows, options),
+            None => String::new(),
+        }
+    }
+
+    /// 导出为 XLSX 文件内容
+    pub fn to_xlsx(&self, sheet_name: &str) -> Vec<u8> {
+        match &*self.snapshot.borrow() {
+            Some(snapshot) => to_xlsx(&snapshot.columns, &snapshot.rows, sheet_name),
+            None => to_xlsx::<T>(&[], &[], sheet_name),
+        }
+    }
+
+    /// 下载 CSV 文件
+    pub fn download_csv(&self, file_name: &str, options: &CsvOptions) {
+        download_file(self.to_csv(options).as_bytes(), file_name, CSV_MIME);
+    }
+
+    /// 下载 XLSX 文件
+    pub fn download_xlsx(&self, file_name: &str, sheet_name: &str) {
+        download_file(&self.to_xlsx(sheet_name), file_name, XLSX_MIME);
+    }
+}
+
+/// 创建表格导出器
+pub fn use_table_exporter<T: TableRow>() -> TableExporter<T> {
+    use_hook(TableExporter::default)
+}
@@ -0,0 +1,208 @@
+//! Table 筛选
+//!
+//! 列筛选项、筛选逻辑以及表头中的筛选下拉菜单。
+
+use super::TableColumn;
+use crate::locale::LocaleConfig;
+use dioxus::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::rc::Rc;
+
+/// 列筛选函数，参数为筛选值与行数据
+pub type FilterFn<T> = Rc<dyn Fn(&str, &T) -> bool>;
+
+/// 各列当前的筛选值，key 为列的 key
+pub type TableFilters = HashMap<String, Vec<String>>;
+
+/// 筛选项
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TableFilter {
+    /// 显示文本
+    pub text: String,
+    /// 筛选值
+    pub value: String,
+}
+
+impl TableFilter {
+    pub fn new(text: &str, value: &str) -> Self {
+        Self {
+            text: text.to_string(),
+            value: value.to_string(),
+        }
+    }
+}
+
+/// 根据列配置收集默认筛选值
+pub fn default_filters<T>(columns: &[TableColumn<T>]) -> TableFilters {
+    columns
+        .iter()
+        .filter_map(|column| {
+            column
+                .default_filtered_value
+                .clone()
+                .map(|values| (column.key.clone(), values))
+        })
+        .collect()
+}
+
+/// 合并内部筛选状态与列上受控的 `filtered_value`
+pub fn merge_filters<T>(columns: &[TableColumn<T>], inner: &TableFilters) -> TableFilters {
+    let mut filters = inner.clone();
+    for column in columns {
+        if let Some(values) = &column.filtered_value {
+            filters.insert(column.key.clone(), values.clone());
+        }
+    }
+    filters.retain(|_, values| !values.is_empty());
+    filters
+}
+
+/// 按筛选值过滤行数据
+///
+/// 同一列的多个筛选值之间为“或”关系，不同列之间为“且”关系。
+/// 未设置 `on_filter` 的列按单元格文本完全匹配。
+pub fn filter_rows<T: Clone>(
+    rows: &[T],
+    columns: &[TableColumn<T>],
+    filters: &TableFilters,
+) -> Vec<T> {
+    let active: Vec<(&TableColumn<T>, &Vec<String>)> = columns
+        .iter()
+        .filter_map(|column| {
+            filters
+                .get(&column.key)
+                .filter(|values| !values.is_empty())
+                .map(|values| (column, values))
+        })
+        .collect();
+
+    rows.iter()
+        .filter(|row| {
+            active.iter().all(|(column, values)| {
+                values.iter().any(|value| match &column.on_filter {
+                    Some(on_filter) => on_filter(value, row),
+                    None => column.cell_text(row) == *value,
+                })
+            })
+        })
+        .cloned()
+        .collect()
+}
+
+/// 筛选下拉菜单属性
+#[derive(Props, Clone, PartialEq)]
+pub(crate) struct FilterDropdownProps {
+    /// 筛选项
+    filters: Vec<TableFilter>,
+    /// 当前生效的筛选值
+    selected: Vec<String>,
+    /// 是否多选
+    multiple: bool,
+    /// 是否显示搜索框
+    search: bool,
+    /// 确认筛选
+    on_confirm: EventHandler<Vec<String>>,
+}
+
+/// 表头中的筛选下拉菜单
+#[component]
+pub(crate) fn FilterDropdown(props: FilterDropdownProps) -> Element {
+    let mut draft = use_signal(|| props.selected.clone());
+    let mut keyword = use_signal(String::new);
+    let locale = try_use_context::<Signal<LocaleConfig>>()
+        .map(|locale| locale.read().clone())
+        .unwrap_or_default();
+
+    let keyword_lower = keyword().to_lowercase();
+    let visible_filters: Vec<TableFilter> = props
+        .filters
+        .iter()
+        .filter(|filter| {
+            keyword_lower.is_empty() || filter.text.to_lowercase().contains(&keyword_lower)
+        })
+        .cloned()
+        .collect();
+
+    rsx! {
+        div {
+            class: "ant-table-filter-dropdown",
+            onclick: move |evt| evt.stop_propagation(),
+
+            if props.search {
+                div {
+                    class: "ant-table-filter-dropdown-search",
+                    input {
+                        class: "ant-input ant-table-filter-dropdown-search-input",
+                        placeholder: locale.translate("table_filter_search"),
+                        value: keyword(),
+                        oninput: move |evt| keyword.set(evt.value()),
+                    }
+                }
+            }
+
+            ul {
+                class: "ant-table-filter-dropdown-menu",
+                for filter in visible_filters {
+                    li {
+                        key: "{filter.value}",
+                        class: if draft.read().contains(&filter.value) {
+                            "ant-table-filter-dropdown-menu-item ant-table-filter-dropdown-menu-item-selected"
+                        } else {
+                            "ant-table-filter-dropdown-menu-item"
+                        },
+                        onclick: {
+                            let value = filter.value.clone();
+                            let multiple = props.multiple;
+                            move |_| {
+                                let mut values = draft();
+                                if values.contains(&value) {
+                                    values.retain(|v| v != &value);
+                                } else if multiple {
+                                    values.push(value.clone());
+                                } else {
+                                    values = vec![value.clone()];
+                                }
+                                draft.set(values);
+                            }
+                        },
+                        input {
+                            r#type: if props.multiple { "checkbox" } else { "radio" },
+                            class: if props.multiple { "ant-checkbox-input" } else { "ant-radio-input" },
+                            checked: draft.read().contains(&filter.value),
+                            readonly: true,
+                        }
+                        span { {filter.text.clone()} }
+                    }
+                }
+                if props.filters.is_empty() {
+                    li {
+                        class: "ant-table-filter-dropdown-empty",
+                        {locale.translate("table_filter_empty")}
+                    }
+                }
+            }
+
+            div {
+                class: "ant-table-filter-dropdown-btns",
+                button {
+                    r#type: "button",
+                    class: "ant-btn ant-btn-link ant-btn-sm",
+                    disabled: draft.read().is_empty(),
+                    onclick: move |_| {
+                        draft.set(Vec::new());
+                        keyword.set(String::new());
+                        props.on_confirm.call(Vec::new());
+                    },
+                    {locale.translate("filter_reset")}
+                }
+                button {
+                    r#type: "button",
+                    class: "ant-btn ant-btn-primary ant-btn-sm",
+                    onclick: move |_| props.on_confirm.call(draft()),
+                    {locale.translate("filter_confirm")}
+                }
+            }
+        }
+    }
+}
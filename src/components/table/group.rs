@@ -0,0 +1,112 @@
+//! Table 表头分组
+//!
+//! 列分组（多级表头）的展平以及各级表头行的计算。
+
+use super::TableColumn;
+use std::collections::HashMap;
+
+/// 叶子列所属的分组路径，由外到内依次为各级分组的 key 与标题
+pub type ColumnGroupPath = Vec<(String, String)>;
+
+/// 表头单元格
+#[derive(Debug, Clone, PartialEq)]
+pub enum HeaderCell {
+    /// 分组标题，覆盖从 `first` 开始的 `col_span` 个叶子列
+    Group {
+        key: String,
+        title: String,
+        first: usize,
+        col_span: usize,
+    },
+    /// 叶子列，`index` 为列在展示顺序中的位置
+    Leaf { index: usize, row_span: usize },
+}
+
+/// 将分组列展平为叶子列
+pub fn flatten_columns<T>(columns: &[TableColumn<T>]) -> Vec<TableColumn<T>> {
+    columns
+        .iter()
+        .flat_map(|column| {
+            if column.children.is_empty() {
+                vec![column.clone()]
+            } else {
+                flatten_columns(&column.children)
+            }
+        })
+        .collect()
+}
+
+/// 收集每个叶子列所属的分组路径
+pub fn column_group_paths<T>(columns: &[TableColumn<T>]) -> HashMap<String, ColumnGroupPath> {
+    fn walk<T>(
+        columns: &[TableColumn<T>],
+        path: &ColumnGroupPath,
+        output: &mut HashMap<String, ColumnGroupPath>,
+    ) {
+        for column in columns {
+            if column.children.is_empty() {
+                output.insert(column.key.clone(), path.clone());
+            } else {
+                let mut path = path.clone();
+                path.push((column.key.clone(), column.title.clone()));
+                walk(&column.children, &path, output);
+            }
+        }
+    }
+
+    let mut output = HashMap::new();
+    walk(columns, &Vec::new(), &mut output);
+    output
+}
+
+/// 按叶子列的展示顺序计算各级表头行
+///
+/// 相邻且属于同一分组的叶子列合并到一个分组单元格下；列被重新排序或隐藏后，
+/// 不再相邻的同组列会各自显示分组标题。
+pub fn header_rows(
+    leaf_keys: &[String],
+    paths: &HashMap<String, ColumnGroupPath>,
+) -> Vec<Vec<HeaderCell>> {
+    let empty = Vec::new();
+    let leaf_paths: Vec<&ColumnGroupPath> = leaf_keys
+        .iter()
+        .map(|key| paths.get(key).unwrap_or(&empty))
+        .collect();
+    let depth = leaf_paths.iter().map(|path| path.len()).max().unwrap_or(0) + 1;
+
+    (0..depth)
+        .map(|level| {
+            let mut row = Vec::new();
+            let mut index = 0;
+            while index < leaf_paths.len() {
+                let path = leaf_paths[index];
+                if path.len() > level {
+                    let group = &path[..=level];
+                    let mut end = index + 1;
+                    while end < leaf_paths.len()
+                        && leaf_paths[end].len() > level
+                        && &leaf_paths[end][..=level] == group
+                    {
+                        end += 1;
+                    }
+                    row.push(HeaderCell::Group {
+                        key: path[level].0.clone(),
+                        title: path[level].1.clone(),
+                        first: index,
+                        col_span: end - index,
+                    });
+                    index = end;
+                } else {
+                    if path.len() == level {
+                        row.push(HeaderCell::Leaf {
+                            index,
+                            row_span: depth - level,
+                        });
+                    }
+                    index += 1;
+                }
+            }
+            row
+        })
+        .collect()
+}
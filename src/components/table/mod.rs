@@ -11,27 +11,30 @@
 //!
 //! ```rust
 //! use dioxus::prelude::*;
-//! use ant_design_dioxus::Table;
+//! use ant_design_dioxus::prelude::*;
+//!
+//! #[derive(Clone, PartialEq)]
+//! struct User {
+//!     id: u32,
+//!     name: String,
+//!     age: u32,
+//! }
+//!
+//! impl TableRow for User {
+//!     fn row_key(&self) -> String {
+//!         self.id.to_string()
+//!     }
+//! }
 //!
 //! #[component]
 //! fn App() -> Element {
 //!     let columns = vec![
-//!         TableColumn {
-//!             title: "姓名".to_string(),
-//!             dataindex: "name".to_string(),
-//!             key: "name".to_string(),
-//!             ..Default::default()
-//!         },
-//!         TableColumn {
-//!             title: "年龄".to_string(),
-//!             dataindex: "age".to_string(),
-//!             key: "age".to_string(),
-//!             ..Default::default()
-//!         },
+//!         TableColumn::new("name", "姓名").value(|user: &User| user.name.clone()),
+//!         TableColumn::new("age", "年龄").value(|user: &User| user.age.to_string()),
 //!     ];
 //!
 //!     rsx! {
-//!         Table {
+//!         Table::<User> {
 //!             columns: columns,
 //!             data_source: vec![],
 //!         }
@@ -39,61 +42,61 @@
 //! }
 //! ```
 
+mod column;
+mod column_state;
+mod editable;
+mod expandable;
+mod export;
+mod filter;
+mod group;
+mod remote;
+mod scroll;
+mod sorter;
+mod summary;
+
+pub use column::*;
+pub use column_state::*;
+pub use editable::*;
+pub use expandable::*;
+pub use export::*;
+pub use filter::*;
+pub use group::*;
+pub use remote::*;
+pub use scroll::*;
+pub use sorter::*;
+pub use summary::*;
+
+use crate::components::pagination::{PageSizeOption, Pagination};
+use crate::components::spin::Spin;
+use crate::locale::LocaleConfig;
 use dioxus::prelude::*;
-use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::rc::Rc;
+use std::sync::atomic::{AtomicUsize, Ordering as AtomicOrdering};
 
 const TABLE_STYLES: &str = include_str!("./style.css");
 
-/// 表格列配置
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct TableColumn {
-    /// 列标题
-    pub title: String,
-    /// 列数据在数据项中对应的路径
-    pub dataindex: String,
-    /// React 需要的 key，如果已经设置了唯一的 dataIndex，可以忽略这个属性
-    pub key: String,
-    /// 列宽度
-    pub width: Option<String>,
-    /// 设置列的对齐方式
-    pub align: Option<TableAlign>,
-    /// 是否支持排序
-    pub sortable: bool,
-    /// 是否固定列
-    pub fixed: Option<TableFixed>,
-    /// 表头是否显示下一次排序的 tooltip 提示
-    pub show_sorter_tooltip: bool,
-}
+/// 表格实例计数，用于生成表头容器的唯一 id
+static TABLE_ID: AtomicUsize = AtomicUsize::new(0);
 
-impl Default for TableColumn {
-    fn default() -> Self {
-        Self {
-            title: String::new(),
-            dataindex: String::new(),
-            key: String::new(),
-            width: None,
-            align: None,
-            sortable: false,
-            fixed: None,
-            show_sorter_tooltip: true,
-        }
-    }
-}
+/// 表格行数据
+///
+/// 表格的每一行都是一个类型化的 `T`，行 key 由数据本身派生。
+pub trait TableRow: Clone + PartialEq + 'static {
+    /// 行的唯一标识
+    fn row_key(&self) -> String;
 
-/// 表格对齐方式
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub enum TableAlign {
-    Left,
-    Center,
-    Right,
+    /// 树形数据的子行，默认没有子行
+    fn children(&self) -> &[Self] {
+        &[]
+    }
 }
 
-/// 表格固定列
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub enum TableFixed {
-    Left,
-    Right,
+/// 以 `key` 字段作为行 key 的字符串映射行
+impl TableRow for HashMap<String, String> {
+    fn row_key(&self) -> String {
+        self.get("key").cloned().unwrap_or_default()
+    }
 }
 
 /// 表格尺寸
@@ -105,8 +108,7 @@ pub enum TableSize {
 }
 
 /// 表格行选择配置
-#[derive(Debug, Clone, PartialEq)]
-pub struct TableRowSelection {
+pub struct TableRowSelection<T> {
     /// 选择框的默认属性配置
     pub checkbox_props: Option<HashMap<String, String>>,
     /// 自定义选择项参考
@@ -115,10 +117,72 @@ pub struct TableRowSelection {
     pub column_title: Option<String>,
     /// 固定选择列
     pub fixed: bool,
-    /// 指定选中项的 key 数组
-    pub selected_row_keys: Vec<String>,
+    /// 指定选中项的 key 数组，设置后为受控模式
+    pub selected_row_keys: Option<Vec<String>>,
+    /// 默认选中项的 key 数组
+    pub default_selected_row_keys: Vec<String>,
     /// 选择框的类型
     pub selection_type: SelectionType,
+    /// 树形数据中父子行的选中状态是否相互独立，为 false 时父子行联动
+    pub check_strictly: bool,
+    /// 选中项发生变化时的回调，参数为选中的 key 与对应的行
+    pub on_change: Option<EventHandler<(Vec<String>, Vec<T>)>>,
+    /// 用户手动选择/取消选择某行的回调
+    pub on_select: Option<EventHandler<(T, bool)>>,
+    /// 用户手动选择/取消选择所有行的回调
+    pub on_select_all: Option<EventHandler<(bool, Vec<T>)>>,
+}
+
+impl<T> Default for TableRowSelection<T> {
+    fn default() -> Self {
+        Self {
+            checkbox_props: None,
+            column_width: None,
+            column_title: None,
+            fixed: false,
+            selected_row_keys: None,
+            default_selected_row_keys: Vec::new(),
+            selection_type: SelectionType::Checkbox,
+            check_strictly: false,
+            on_change: None,
+            on_select: None,
+            on_select_all: None,
+        }
+    }
+}
+
+impl<T> Clone for TableRowSelection<T> {
+    fn clone(&self) -> Self {
+        Self {
+            checkbox_props: self.checkbox_props.clone(),
+            column_width: self.column_width.clone(),
+            column_title: self.column_title.clone(),
+            fixed: self.fixed,
+            selected_row_keys: self.selected_row_keys.clone(),
+            default_selected_row_keys: self.default_selected_row_keys.clone(),
+            selection_type: self.selection_type.clone(),
+            check_strictly: self.check_strictly,
+            on_change: self.on_change,
+            on_select: self.on_select,
+            on_select_all: self.on_select_all,
+        }
+    }
+}
+
+impl<T: 'static> PartialEq for TableRowSelection<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.checkbox_props == other.checkbox_props
+            && self.column_width == other.column_width
+            && self.column_title == other.column_title
+            && self.fixed == other.fixed
+            && self.selected_row_keys == other.selected_row_keys
+            && self.default_selected_row_keys == other.default_selected_row_keys
+            && self.selection_type == other.selection_type
+            && self.check_strictly == other.check_strictly
+            && self.on_change == other.on_change
+            && self.on_select == other.on_select
+            && self.on_select_all == other.on_select_all
+    }
 }
 
 /// 选择框类型
@@ -145,19 +209,56 @@ pub struct TablePagination {
     pub page_size_options: Vec<String>,
 }
 
+/// 触发表格变化的操作
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TableAction {
+    /// 翻页或修改每页条数
+    Paginate,
+    /// 排序
+    Sort,
+    /// 筛选
+    Filter,
+}
+
+/// 表格分页、筛选、排序变化时的完整状态
+#[derive(Debug, Clone, PartialEq)]
+pub struct TableChange {
+    /// 分页状态，未开启分页时为 None
+    pub pagination: Option<TablePagination>,
+    /// 各列的筛选值
+    pub filters: TableFilters,
+    /// 排序状态
+    pub sorter: Vec<SorterState>,
+    /// 触发本次变化的操作
+    pub action: TableAction,
+}
+
 /// Table 组件属性
 #[derive(Props, PartialEq, Clone)]
-pub struct TableProps {
+pub struct TableProps<T: TableRow> {
     /// 表格列的配置描述
-    pub columns: Vec<TableColumn>,
+    pub columns: Vec<TableColumn<T>>,
     /// 数据数组
-    pub data_source: Vec<HashMap<String, String>>,
+    pub data_source: Vec<T>,
     /// 表格是否可滚动
     #[props(default = false)]
     pub scroll_x: bool,
-    /// 表格是否可滚动
+    /// 表格是否可纵向滚动，开启后固定表头
     #[props(default = false)]
     pub scroll_y: bool,
+    /// 表体的最大高度（像素），设置后固定表头
+    pub scroll_height: Option<u32>,
+    /// 是否开启虚拟滚动，只渲染可视区域内的行
+    #[props(default = false)]
+    pub virtual_scroll: bool,
+    /// 虚拟滚动时的预估行高（像素），未设置时按表格尺寸估算，并在首行渲染后以实际高度为准
+    pub row_height: Option<u32>,
+    /// 虚拟滚动时可视区域上下额外渲染的行数
+    #[props(default = DEFAULT_OVERSCAN)]
+    pub overscan: usize,
+    /// 表头是否吸顶
+    #[props(default = false)]
+    pub sticky: bool,
     /// 表格大小
     #[props(default = TableSize::Default)]
     pub size: TableSize,
@@ -165,19 +266,41 @@ pub struct TableProps {
     pub title: Option<String>,
     /// 表格尾部
     pub footer: Option<String>,
+    /// 总结栏，参数为当前页的数据，返回的行与数据列对齐
+    pub summary: Option<Callback<Vec<T>, Vec<TableSummaryRow>>>,
     /// 是否显示表头
     #[props(default = true)]
     pub show_header: bool,
     /// 表格行是否可选择
-    pub row_selection: Option<TableRowSelection>,
+    pub row_selection: Option<TableRowSelection<T>>,
+    /// 展开行配置
+    pub expandable: Option<TableExpandable<T>>,
+    /// 可编辑配置
+    pub editable: Option<TableEditable<T>>,
+    /// 是否可拖拽调整列宽
+    #[props(default = false)]
+    pub resizable: bool,
+    /// 是否可拖拽表头调整列顺序，固定列不参与拖拽
+    #[props(default = false)]
+    pub reorderable: bool,
+    /// 是否显示列设置，用于切换列的显隐
+    #[props(default = false)]
+    pub show_column_picker: bool,
+    /// 列状态，设置后为受控模式
+    pub column_state: Option<ColumnState>,
+    /// 默认列状态
+    pub default_column_state: Option<ColumnState>,
+    /// 列状态变化时的回调
+    pub on_column_state_change: Option<EventHandler<ColumnState>>,
     /// 分页器，参考配置项或 pagination 文档
     pub pagination: Option<TablePagination>,
     /// 页面是否加载中
     #[props(default = false)]
     pub loading: bool,
-    /// 表格行 key 的取值
-    #[props(default = "key".to_string())]
-    pub row_key: String,
+    /// 远程数据模式，设置后分页、排序与筛选由服务端完成，忽略 `data_source`
+    pub remote: Option<TableRemote<T>>,
+    /// 表格导出器，通过 [`use_table_exporter`] 创建
+    pub exporter: Option<TableExporter<T>>,
     /// 设置表格的总体样式
     #[props(default = String::new())]
     pub class: String,
@@ -185,16 +308,296 @@ pub struct TableProps {
     #[props(default = String::new())]
     pub style: String,
     /// 行点击事件回调
-    pub on_row_click: Option<EventHandler<String>>,
-    /// 列排序事件回调
-    pub on_sort_change: Option<EventHandler<(String, String)>>,
+    pub on_row_click: Option<EventHandler<T>>,
+    /// 排序状态，设置后为受控模式
+    pub sort_state: Option<Vec<SorterState>>,
+    /// 排序状态变化的回调
+    pub on_sort_change: Option<EventHandler<Vec<SorterState>>>,
+    /// 分页、排序、筛选变化时的回调
+    pub on_change: Option<EventHandler<TableChange>>,
+}
+
+/// 切换某一行的选中状态，返回新的选中 key 列表
+fn toggle_selected_key(
+    selected: &[String],
+    key: &str,
+    selection_type: &SelectionType,
+) -> Vec<String> {
+    match selection_type {
+        SelectionType::Radio => vec![key.to_string()],
+        SelectionType::Checkbox => {
+            if selected.iter().any(|k| k == key) {
+                selected.iter().filter(|k| *k != key).cloned().collect()
+            } else {
+                let mut keys = selected.to_vec();
+                keys.push(key.to_string());
+                keys
+            }
+        }
+    }
+}
+
+/// 正在拖拽调整的列宽
+#[derive(Debug, Clone, PartialEq)]
+struct ColumnResize {
+    /// 列的 key
+    key: String,
+    /// 开始拖拽时鼠标的横坐标
+    start_x: f64,
+    /// 开始拖拽时的列宽
+    start_width: f64,
+    /// 当前列宽
+    width: f64,
+}
+
+/// 根据选中的 key 收集对应的行数据，包含树形数据中的子行
+fn collect_selected_rows<T: TableRow>(data: &[T], keys: &[String]) -> Vec<T> {
+    let keys: HashSet<&str> = keys.iter().map(String::as_str).collect();
+    flatten_tree(data)
+        .into_iter()
+        .filter(|row| keys.contains(row.row_key().as_str()))
+        .collect()
+}
+
+/// 最近一次传入的行数据
+///
+/// 属性不变时重新渲染（如滚动）拿到的是同一个 `Vec`，按地址与长度即可判断未变化，
+/// 只有属性更新时才逐行比较。
+struct SourceData<T> {
+    /// `data_source` 的地址与长度
+    identity: (usize, usize),
+    /// 内容变化的次数，作为行数据计算的依赖
+    version: usize,
+    rows: Rc<Vec<T>>,
+}
+
+impl<T: TableRow> SourceData<T> {
+    fn new(rows: &[T]) -> Self {
+        Self {
+            identity: (rows.as_ptr() as usize, rows.len()),
+            version: 0,
+            rows: Rc::new(rows.to_vec()),
+        }
+    }
+
+    /// 同步最新的行数据，返回内容的版本
+    fn sync(&mut self, rows: &[T]) -> usize {
+        let identity = (rows.as_ptr() as usize, rows.len());
+        if self.identity != identity {
+            self.identity = identity;
+            if *self.rows != rows {
+                self.rows = Rc::new(rows.to_vec());
+                self.version += 1;
+            }
+        }
+        self.version
+    }
+}
+
+/// 行数据计算所依赖的数据、列、筛选与排序状态
+#[derive(Clone)]
+struct SourceInput<T: TableRow> {
+    data: Rc<Vec<T>>,
+    data_version: usize,
+    columns: Vec<TableColumn<T>>,
+    filters: TableFilters,
+    sorters: Vec<SorterState>,
+    remote: bool,
+}
+
+impl<T: TableRow> PartialEq for SourceInput<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.data_version == other.data_version
+            && self.columns == other.columns
+            && self.filters == other.filters
+            && self.sorters == other.sorters
+            && self.remote == other.remote
+    }
+}
+
+/// 分页与展平所依赖的展开状态与分页配置
+#[derive(Clone, PartialEq)]
+struct PageInput {
+    expanded_keys: Vec<String>,
+    pagination: Option<TablePagination>,
+}
+
+/// 筛选、排序后的行数据
+#[derive(Clone, PartialEq)]
+struct SourceRows<T: TableRow> {
+    /// 原始数据，远程模式下为当前页的数据
+    data: Rc<Vec<T>>,
+    /// 筛选、排序后的所有行
+    sorted: Rc<Vec<T>>,
+    /// 数据总数
+    total: usize,
+    /// 是否包含树形数据
+    tree_mode: bool,
+    /// 所有行（包含子行）的 key
+    row_keys: Rc<Vec<String>>,
+}
+
+/// 当前页的行数据与展平后用于渲染的行
+#[derive(Clone, PartialEq)]
+struct PageRows<T: TableRow> {
+    page: Rc<Vec<T>>,
+    flat: Rc<Vec<FlatRow<T>>>,
+}
+
+/// 截取分页后当前页的行数据
+fn paginate_rows<T: Clone>(rows: &[T], pagination: Option<&TablePagination>) -> Vec<T> {
+    match pagination {
+        Some(pagination) if pagination.page_size > 0 => rows
+            .iter()
+            .skip(pagination.current.saturating_sub(1) * pagination.page_size)
+            .take(pagination.page_size)
+            .cloned()
+            .collect(),
+        _ => rows.to_vec(),
+    }
+}
+
+/// 将形如 `"10条"` 的每页条数选项转换为分页器选项
+fn page_size_options(options: &[String]) -> Option<Vec<PageSizeOption>> {
+    let options: Vec<PageSizeOption> = options
+        .iter()
+        .filter_map(|label| {
+            let digits: String = label.chars().take_while(|c| c.is_ascii_digit()).collect();
+            digits
+                .parse::<usize>()
+                .ok()
+                .map(|size| PageSizeOption::with_label(size, label))
+        })
+        .collect();
+    if options.is_empty() {
+        None
+    } else {
+        Some(options)
+    }
+}
+
+/// 表头排序提示文本
+fn sorter_tooltip(locale: &LocaleConfig, order: Option<SortOrder>) -> String {
+    let key = match next_sort_order(order) {
+        Some(SortOrder::Ascend) => "table_sort_ascend",
+        Some(SortOrder::Descend) => "table_sort_descend",
+        None => "table_sort_cancel",
+    };
+    locale.translate(key)
 }
 
 /// Table 表格组件
 ///
 /// 展示行列数据的表格组件。
 #[component]
-pub fn Table(props: TableProps) -> Element {
+pub fn Table<T: TableRow>(props: TableProps<T>) -> Element {
+    // 分组列展平后的叶子列参与排序、筛选与渲染
+    let leaf_columns = flatten_columns(&props.columns);
+    let group_paths = column_group_paths(&props.columns);
+    let locale = try_use_context::<Signal<LocaleConfig>>()
+        .map(|locale| locale.read().clone())
+        .unwrap_or_default();
+
+    let inner_selected_keys = use_signal(|| {
+        props
+            .row_selection
+            .as_ref()
+            .map(|selection| selection.default_selected_row_keys.clone())
+            .unwrap_or_default()
+    });
+
+    // 受控模式下以外部传入的 key 为准
+    let selected_keys: Vec<String> = props
+        .row_selection
+        .as_ref()
+        .and_then(|selection| selection.selected_row_keys.clone())
+        .unwrap_or_else(|| inner_selected_keys.cloned());
+
+    let inner_expanded_keys = use_signal(|| {
+        props
+            .expandable
+            .as_ref()
+            .map(|expandable| expandable.initial_expanded_keys(&props.data_source))
+            .unwrap_or_default()
+    });
+
+    // 受控模式下以外部传入的展开行为准
+    let expanded_keys: Vec<String> = props
+        .expandable
+        .as_ref()
+        .and_then(|expandable| expandable.expanded_row_keys.clone())
+        .unwrap_or_else(|| inner_expanded_keys.cloned());
+
+    let editing = use_signal(|| None::<EditingState>);
+    let editing_state = editing.cloned();
+    let edit_mode = props
+        .editable
+        .as_ref()
+        .map(|editable| editable.mode.clone())
+        .unwrap_or_default();
+    let action_column = props
+        .editable
+        .as_ref()
+        .is_some_and(|editable| editable.mode == TableEditMode::Row && editable.show_action_column);
+
+    let mut inner_sorters = use_signal(|| default_sorters(&leaf_columns));
+
+    // 受控模式下以外部传入的排序状态为准
+    let sorters: Vec<SorterState> = props
+        .sort_state
+        .clone()
+        .unwrap_or_else(|| inner_sorters.cloned());
+
+    let mut inner_filters = use_signal(|| default_filters(&leaf_columns));
+    let mut open_filter = use_signal(|| None::<String>);
+    let filters = merge_filters(&leaf_columns, &inner_filters.read());
+
+    let mut current_page = use_signal(|| {
+        props
+            .pagination
+            .as_ref()
+            .map(|pagination| pagination.current.max(1))
+            .unwrap_or(1)
+    });
+    let mut page_size = use_signal(|| {
+        props
+            .pagination
+            .as_ref()
+            .map(|pagination| pagination.page_size)
+            .unwrap_or(10)
+    });
+
+    // 固定表头与虚拟滚动的状态
+    let table_id = use_hook(|| {
+        format!(
+            "ant-table-{}",
+            TABLE_ID.fetch_add(1, AtomicOrdering::Relaxed)
+        )
+    });
+    let mut body_ref = use_signal(|| None::<Rc<MountedData>>);
+    let mut scroll_top = use_signal(|| 0.0_f64);
+    let mut measured_row_height = use_signal(|| None::<f64>);
+
+    // 列宽、列顺序与列显隐的状态
+    let inner_column_state = use_signal(|| props.default_column_state.clone().unwrap_or_default());
+    let column_state = props
+        .column_state
+        .clone()
+        .unwrap_or_else(|| inner_column_state.cloned());
+    let mut resizing = use_signal(|| None::<ColumnResize>);
+    let mut dragging_column = use_signal(|| None::<String>);
+    let mut column_picker_open = use_signal(|| false);
+    let update_column_state = {
+        let on_column_state_change = props.on_column_state_change;
+        move |state: ColumnState| {
+            let mut inner_column_state = inner_column_state;
+            inner_column_state.set(state.clone());
+            if let Some(on_column_state_change) = on_column_state_change {
+                on_column_state_change.call(state);
+            }
+        }
+    };
+
     let table_class = format!(
         "ant-table ant-table-{} {}",
         match props.size {
@@ -210,174 +613,793 @@ pub fn Table(props: TableProps) -> Element {
     } else {
         ""
     };
+    let sticky_class = if props.sticky {
+        " ant-table-sticky"
+    } else {
+        ""
+    };
 
-    rsx! {
-        style { {TABLE_STYLES} }
+    // 远程数据模式的状态
+    let remote_state = RemoteState {
+        rows: use_signal(Vec::new),
+        total: use_signal(|| 0),
+        loading: use_signal(|| false),
+        task: use_signal(|| None),
+        request_id: use_signal(|| 0),
+    };
+    let is_remote = props.remote.is_some();
+    let loading = props.loading || (remote_state.loading)();
+    // 分页、排序或筛选变化时重新加载远程数据，筛选变化时防抖
+    let load_remote = use_callback({
+        let remote = props.remote.clone();
+        move |change: TableChange| {
+            let Some(remote) = &remote else {
+                return;
+            };
+            let query = TableQuery {
+                page: change
+                    .pagination
+                    .as_ref()
+                    .map(|pagination| pagination.current)
+                    .unwrap_or_else(|| *current_page.peek()),
+                page_size: change
+                    .pagination
+                    .as_ref()
+                    .map(|pagination| pagination.page_size)
+                    .unwrap_or_else(|| *page_size.peek()),
+                sorters: change.sorter,
+                filters: change.filters,
+            };
+            let delay = if change.action == TableAction::Filter {
+                remote.filter_debounce
+            } else {
+                0
+            };
+            remote_state.request(remote, query, delay);
+        }
+    });
+    // 首次渲染后加载第一页
+    use_hook(|| {
+        if let Some(remote) = props.remote.clone() {
+            let query = TableQuery {
+                page: *current_page.peek(),
+                page_size: *page_size.peek(),
+                sorters: sorters.clone(),
+                filters: filters.clone(),
+            };
+            spawn(async move { remote_state.request(&remote, query, 0) });
+        }
+    });
 
-        div {
-            class: "ant-table-wrapper",
-            style: props.style.clone(),
+    // 行数据的计算只依赖数据、列、筛选、排序、分页与展开状态，滚动时复用上次的结果
+    let mut source_data = use_hook(|| CopyValue::new(SourceData::new(&props.data_source)));
+    let data_version = source_data.with_mut(|data| data.sync(&props.data_source));
+    let source_input = use_memo(use_reactive(
+        (&data_version, &leaf_columns, &filters, &sorters, &is_remote),
+        move |(data_version, columns, filters, sorters, remote)| SourceInput {
+            data: source_data.peek().rows.clone(),
+            data_version,
+            columns,
+            filters,
+            sorters,
+            remote,
+        },
+    ));
+    let pagination_config = props.pagination.clone();
+    let page_input = use_memo(use_reactive!(|expanded_keys, pagination_config| {
+        PageInput {
+            expanded_keys,
+            pagination: pagination_config,
+        }
+    }));
 
-            // 表格标题
-            if let Some(title) = &props.title {
-                div {
-                    class: "ant-table-title",
-                    {title.clone()}
+    // 依次筛选、排序，远程模式下由服务端完成
+    let table_source = use_memo(move || {
+        let input = source_input.read();
+        let (data, sorted, total) = if input.remote {
+            let rows = Rc::new(remote_state.rows.cloned());
+            (rows.clone(), rows, (remote_state.total)())
+        } else {
+            let filtered_rows = filter_rows(&input.data, &input.columns, &input.filters);
+            let sorted_rows = sort_rows(&filtered_rows, &input.columns, &input.sorters);
+            // 客户端分页时总数为筛选后的数据条数
+            let total = sorted_rows.len();
+            (input.data.clone(), Rc::new(sorted_rows), total)
+        };
+        SourceRows {
+            tree_mode: data.iter().any(|row| !row.children().is_empty()),
+            row_keys: Rc::new(flatten_tree(&data).iter().map(TableRow::row_key).collect()),
+            data,
+            sorted,
+            total,
+        }
+    });
+    // 分页并按展开状态展平树形数据
+    let table_page = use_memo(move || {
+        let source = table_source.read();
+        let input = page_input.read();
+        let remote = source_input.read().remote;
+        let page = if remote {
+            source.sorted.clone()
+        } else {
+            let pagination = input.pagination.clone().map(|pagination| TablePagination {
+                current: current_page(),
+                page_size: page_size(),
+                ..pagination
+            });
+            Rc::new(paginate_rows(&source.sorted, pagination.as_ref()))
+        };
+        let sorters = if remote {
+            Vec::new()
+        } else {
+            source_input.read().sorters.clone()
+        };
+        let flat = flatten_rows(
+            &page,
+            &input.expanded_keys,
+            &source_input.read().columns,
+            &sorters,
+        );
+        PageRows {
+            page,
+            flat: Rc::new(flat),
+        }
+    });
+    let SourceRows {
+        data: data_source,
+        sorted: sorted_rows,
+        total,
+        tree_mode,
+        row_keys: all_row_keys,
+    } = table_source();
+    let PageRows {
+        page: page_rows,
+        flat: flat_rows,
+    } = table_page();
+
+    let pagination = props.pagination.as_ref().map(|pagination| TablePagination {
+        current: current_page(),
+        page_size: page_size(),
+        total,
+        ..pagination.clone()
+    });
+    // 导出当前显示的列以及筛选、排序后的所有行，只在列或行变化时更新
+    let mut exported = use_hook(|| CopyValue::new(None::<(ColumnState, Rc<Vec<T>>)>));
+    if let Some(exporter) = props.exporter.as_ref() {
+        let stale = exported
+            .peek()
+            .as_ref()
+            .is_none_or(|(state, rows)| *state != column_state || !Rc::ptr_eq(rows, &sorted_rows));
+        if stale {
+            exporter.update(&column_state.apply(&leaf_columns), &sorted_rows);
+            exported.set(Some((column_state.clone(), sorted_rows.clone())));
+        }
+    }
+    let expand_column = props
+        .expandable
+        .as_ref()
+        .is_some_and(|expandable| expandable.expanded_row_render.is_some());
+    let indent_size = props
+        .expandable
+        .as_ref()
+        .map(|expandable| expandable.indent_size)
+        .unwrap_or(15.0);
+
+    // 固定表头、虚拟滚动与固定列
+    let fixed_header = props.virtual_scroll || props.scroll_y || props.scroll_height.is_some();
+    let scroll_height = f64::from(props.scroll_height.unwrap_or(DEFAULT_SCROLL_HEIGHT));
+    let row_height = measured_row_height().unwrap_or_else(|| {
+        props
+            .row_height
+            .map(f64::from)
+            .unwrap_or_else(|| default_row_height(&props.size))
+    });
+    let range = if props.virtual_scroll {
+        virtual_range(
+            flat_rows.len(),
+            scroll_top(),
+            scroll_height,
+            row_height,
+            props.overscan,
+        )
+    } else {
+        VirtualRange {
+            start: 0,
+            end: flat_rows.len(),
+            offset_top: 0.0,
+            offset_bottom: 0.0,
+        }
+    };
+
+    // 拖拽调整列宽的过程中实时预览新的列宽
+    let visible_column_state = match resizing.read().as_ref() {
+        Some(resize) => column_state.resize(&resize.key, resize.width),
+        None => column_state.clone(),
+    };
+    let columns = arrange_fixed_columns(&visible_column_state.apply(&leaf_columns));
+    let header_rows = header_rows(
+        &columns
+            .iter()
+            .map(|column| column.key.clone())
+            .collect::<Vec<_>>(),
+        &group_paths,
+    );
+    let header_depth = header_rows.len();
+    let selection_width = props.row_selection.as_ref().map(|selection| {
+        selection
+            .column_width
+            .as_deref()
+            .and_then(parse_px)
+            .unwrap_or(SELECTION_COLUMN_WIDTH)
+    });
+    let selection_fixed_width = props
+        .row_selection
+        .as_ref()
+        .filter(|selection| selection.fixed)
+        .and(selection_width);
+    // 选择列固定时，紧随其后的展开列一同固定
+    let (mut selection_fixed, fixed) = fixed_cells(
+        &columns,
+        selection_fixed_width.map(|width| {
+            width
+                + if expand_column {
+                    EXPAND_COLUMN_WIDTH
+                } else {
+                    0.0
+                }
+        }),
+    );
+    let expand_fixed = match selection_fixed_width {
+        Some(width) if expand_column => {
+            let cell = FixedCell {
+                offset: width,
+                ..selection_fixed.clone()
+            };
+            selection_fixed.is_edge = false;
+            cell
+        }
+        _ => FixedCell::default(),
+    };
+    let column_count = columns.len()
+        + usize::from(props.row_selection.is_some())
+        + usize::from(expand_column)
+        + usize::from(action_column);
+
+    let content_width: Option<f64> = if props.scroll_x {
+        columns
+            .iter()
+            .map(|column| column.width.as_deref().and_then(parse_px))
+            .sum::<Option<f64>>()
+            .map(|width| {
+                width
+                    + selection_width.unwrap_or_default()
+                    + if expand_column {
+                        EXPAND_COLUMN_WIDTH
+                    } else {
+                        0.0
+                    }
+            })
+    } else {
+        None
+    };
+    let table_style = format!(
+        "{}{}",
+        if fixed_header
+            || fixed.iter().any(|cell| cell.side.is_some())
+            || !visible_column_state.widths.is_empty()
+        {
+            "table-layout: fixed;"
+        } else {
+            ""
+        },
+        content_width
+            .map(|width| format!(" width: {width}px; min-width: 100%;"))
+            .unwrap_or_default()
+    );
+
+    // 同步表体与表头的滚动位置
+    let handle_scroll = {
+        let table_id = table_id.clone();
+        let virtual_scroll = props.virtual_scroll;
+        move |_| {
+            let Some(body) = body_ref.cloned() else {
+                return;
+            };
+            let table_id = table_id.clone();
+            spawn(async move {
+                if let Ok(offset) = body.get_scroll_offset().await {
+                    if virtual_scroll {
+                        scroll_top.set(offset.y);
+                    }
+                    document::eval(&format!(
+                        "var header = document.getElementById('{table_id}-header'); if (header) {{ header.scrollLeft = {}; }}",
+                        offset.x
+                    ));
                 }
+            });
+        }
+    };
+
+    let change = TableChange {
+        pagination: pagination.clone(),
+        filters: filters.clone(),
+        sorter: sorters.clone(),
+        action: TableAction::Paginate,
+    };
+    let emit_change = {
+        let on_change = props.on_change;
+        move |change: TableChange| {
+            load_remote.call(change.clone());
+            if let Some(on_change) = on_change {
+                on_change.call(change);
             }
+        }
+    };
 
-            // 表格容器
-            div {
-                class: "ant-table-container",
+    let update_selection = {
+        let selection = props.row_selection.clone();
+        let data_source = data_source.clone();
+        Rc::new(move |keys: Vec<String>| {
+            let mut inner_selected_keys = inner_selected_keys;
+            inner_selected_keys.set(keys.clone());
+            if let Some(on_change) = selection.as_ref().and_then(|s| s.on_change) {
+                let rows = collect_selected_rows(&data_source, &keys);
+                on_change.call((keys, rows));
+            }
+        })
+    };
 
-                // 表格内容
-                div {
-                    class: format!("{table_class}{scroll_class}"),
+    let toggle_expand = {
+        let expandable = props.expandable.clone();
+        let expanded_keys = expanded_keys.clone();
+        Rc::new(move |row: T| {
+            let key = row.row_key();
+            let keys = toggle_expanded_key(&expanded_keys, &key);
+            let expanded = keys.contains(&key);
+            let mut inner_expanded_keys = inner_expanded_keys;
+            inner_expanded_keys.set(keys.clone());
+            if let Some(expandable) = &expandable {
+                if let Some(on_expand) = expandable.on_expand {
+                    on_expand.call((expanded, row));
+                }
+                if let Some(on_expanded_rows_change) = expandable.on_expanded_rows_change {
+                    on_expanded_rows_change.call(keys);
+                }
+            }
+        })
+    };
 
-                    // 表格主体
-                    table {
-                        class: "ant-table-table",
+    // 校验并保存当前的编辑，校验失败时保留编辑状态并返回 false
+    let save_editing = {
+        let columns = leaf_columns.clone();
+        let data_source = data_source.clone();
+        let on_save = props
+            .editable
+            .as_ref()
+            .and_then(|editable| editable.on_save);
+        Rc::new(move || -> bool {
+            let mut editing = editing;
+            let Some(state) = editing.cloned() else {
+                return true;
+            };
+            let errors = validate_cell_edits(&columns, &state.draft);
+            if !errors.is_empty() {
+                editing.set(Some(EditingState { errors, ..state }));
+                return false;
+            }
+            editing.set(None);
+            let row = flatten_tree(&data_source)
+                .into_iter()
+                .find(|row| row.row_key() == state.row_key);
+            if let (Some(row), Some(on_save)) = (row, on_save) {
+                on_save.call(apply_cell_edits(&row, &columns, &state.draft));
+            }
+            true
+        })
+    };
 
-                        // 表头
-                        if props.show_header {
-                            thead {
-                                class: "ant-table-thead",
-                                tr {
-                                    class: "ant-table-row",
-                                    key: index.to_string(),
-
-                                    // 行选择列
-                                    if props.row_selection.is_some() {
-                                        th {
-                                            class: "ant-table-cell ant-table-selection-column",
-                                            input {
-                                                r#type: "checkbox",
-                                                class: "ant-checkbox-input",
-                                            }
-                                        }
-                                    }
+    let cancel_editing = {
+        let data_source = data_source.clone();
+        let on_cancel = props
+            .editable
+            .as_ref()
+            .and_then(|editable| editable.on_cancel);
+        Rc::new(move || {
+            let mut editing = editing;
+            let Some(state) = editing.cloned() else {
+                return;
+            };
+            editing.set(None);
+            let row = flatten_tree(&data_source)
+                .into_iter()
+                .find(|row| row.row_key() == state.row_key);
+            if let (Some(row), Some(on_cancel)) = (row, on_cancel) {
+                on_cancel.call(row);
+            }
+        })
+    };
 
-                                    // 数据列
-                                    for column in &props.columns {
-                                        th {
-                                            class: format!(
-                                                "ant-table-cell {}",
-                                                if let Some(align) = &column.align {
-                                                    match align {
-                                                        TableAlign::Left => "ant-table-cell-left",
-                                                        TableAlign::Center => "ant-table-cell-center",
-                                                        TableAlign::Right => "ant-table-cell-right",
-                                                    }
-                                                } else {
-                                                    ""
-                                                }
-                                            ),
-                                            key: column.key.clone(),
-                                            style: if let Some(width) = &column.width {
-                                                format!("width: {}", width)
-                                            } else {
-                                                String::new()
-                                            },
+    // 开始编辑某一行或某个单元格，正在编辑其他单元格时先保存
+    let start_editing = {
+        let columns = leaf_columns.clone();
+        let save_editing = save_editing.clone();
+        Rc::new(move |row: T, column_key: Option<String>| {
+            let row_key = row.row_key();
+            let same = editing
+                .peek()
+                .as_ref()
+                .is_some_and(|state| state.row_key == row_key && state.column_key == column_key);
+            if same || !save_editing() {
+                return;
+            }
+            let mut editing = editing;
+            editing.set(Some(EditingState::start(
+                row_key,
+                &row,
+                &columns,
+                column_key.as_deref(),
+            )));
+        })
+    };
 
-                                            div {
-                                                class: "ant-table-column-title",
-                                                {column.title.clone()}
-                                            }
+    let is_checkbox = props
+        .row_selection
+        .as_ref()
+        .is_some_and(|selection| selection.selection_type == SelectionType::Checkbox);
+    // 选中与展开状态按 key 查找，避免逐行在列表中线性查找
+    let selected_set: HashSet<&str> = selected_keys.iter().map(String::as_str).collect();
+    let expanded_set: HashSet<&str> = expanded_keys.iter().map(String::as_str).collect();
+    let all_selected = !all_row_keys.is_empty()
+        && all_row_keys
+            .iter()
+            .all(|key| selected_set.contains(key.as_str()));
 
-                                            // 排序图标
-                                            if column.sortable {
-                                                div {
-                                                    class: "ant-table-column-sorters",
-                                                    span {
-                                                        class: "ant-table-column-sorter",
-                                                        span {
-                                                            class: "ant-table-column-sorter-up",
-                                                            "▲"
-                                                        }
-                                                        span {
-                                                            class: "ant-table-column-sorter-down",
-                                                            "▼"
-                                                        }
-                                                    }
-                                                }
-                                            }
+    let colgroup = rsx! {
+        colgroup {
+            if let Some(width) = selection_width {
+                col { style: "width: {width}px;" }
+            }
+            if expand_column {
+                col { style: "width: {EXPAND_COLUMN_WIDTH}px;" }
+            }
+            for column in columns.iter() {
+                col {
+                    key: "{column.key}",
+                    style: column.width.as_ref().map(|width| format!("width: {width};")),
+                }
+            }
+            if action_column {
+                col {}
+            }
+        }
+    };
+
+    let summary = props.summary;
+    let summary_rows = use_memo(move || {
+        summary
+            .map(|summary| summary.call((*table_page.read().page).clone()))
+            .unwrap_or_default()
+    })();
+    let tfoot = rsx! {
+        if !summary_rows.is_empty() {
+            tfoot {
+                class: "ant-table-summary",
+                for (row_index, summary_row) in summary_rows.iter().enumerate() {
+                    tr {
+                        key: "{row_index}",
+                        class: "ant-table-summary-row",
+
+                        if props.row_selection.is_some() {
+                            td {
+                                class: "ant-table-cell ant-table-selection-column{selection_fixed.class()}",
+                                style: selection_fixed.style(),
+                            }
+                        }
+                        if expand_column {
+                            td {
+                                class: "ant-table-cell{expand_fixed.class()}",
+                                style: expand_fixed.style(),
+                            }
+                        }
+
+                        // 与数据列对齐的汇总单元格
+                        for layout in layout_summary_row(&columns, summary_row) {
+                            {
+                                let fixed_cell = group_fixed_cell(&fixed, layout.index, layout.col_span);
+                                rsx! {
+                                    td {
+                                        key: "{layout.index}",
+                                        class: "ant-table-cell {columns[layout.index].align_class()}{fixed_cell.class()}",
+                                        style: fixed_cell.style(),
+                                        colspan: (layout.col_span > 1).then(|| layout.col_span.to_string()),
+                                        if let Some(cell) = layout.cell {
+                                            {summary_row.cells[cell].content.clone()}
                                         }
                                     }
                                 }
                             }
                         }
 
-                        // 表格主体
-                        tbody {
-                            class: "ant-table-tbody",
-
-                            // 数据行
-                            for (_index, row) in props.data_source.iter().enumerate() {
-                                tr {
-                                    class: "ant-table-row",
-                                    key: index.to_string(),
-                                    onclick: {
-                                         let handler = props.on_row_click.clone();
-                                         let key_value = row.get(&props.row_key).cloned();
-                                         move |_| {
-                                             if let Some(handler) = &handler {
-                                                 if let Some(key) = &key_value {
-                                                     handler.call(key.clone());
-                                                 }
-                                             }
-                                         }
-                                     },
-
-                                    // 行选择列
-                                    if props.row_selection.is_some() {
-                                        td {
-                                            class: "ant-table-cell ant-table-selection-column",
-                                            input {
-                                                r#type: "checkbox",
-                                                class: "ant-checkbox-input",
+                        if action_column {
+                            td { class: "ant-table-cell" }
+                        }
+                    }
+                }
+            }
+        }
+    };
+
+    let thead = rsx! {
+        thead {
+            class: "ant-table-thead",
+            for (row_index, header_row) in header_rows.iter().enumerate() {
+                tr {
+                    key: "{row_index}",
+                    class: "ant-table-row",
+
+                    // 行选择列
+                    if let Some(selection) = props.row_selection.as_ref().filter(|_| row_index == 0) {
+                        th {
+                            class: "ant-table-cell ant-table-selection-column{selection_fixed.class()}",
+                            style: selection_fixed.style(),
+                            rowspan: "{header_depth}",
+                            if let Some(column_title) = &selection.column_title {
+                                {column_title.clone()}
+                            } else if is_checkbox {
+                                input {
+                                    r#type: "checkbox",
+                                    class: "ant-checkbox-input",
+                                    checked: all_selected,
+                                    onchange: {
+                                        let update_selection = update_selection.clone();
+                                        let data_source = data_source.clone();
+                                        let all_row_keys = all_row_keys.clone();
+                                        let on_select_all = selection.on_select_all;
+                                        move |_| {
+                                            let keys = if all_selected {
+                                                Vec::new()
+                                            } else {
+                                                (*all_row_keys).clone()
+                                            };
+                                            if let Some(on_select_all) = on_select_all {
+                                                on_select_all.call((!all_selected, collect_selected_rows(&data_source, &keys)));
                                             }
+                                            update_selection(keys);
                                         }
-                                    }
+                                    },
+                                }
+                            }
+                        }
+                    }
 
-                                    // 数据列
-                                    for column in &props.columns {
-                                        td {
-                                            class: format!(
-                                                "ant-table-cell {}",
-                                                if let Some(align) = &column.align {
-                                                    match align {
-                                                        TableAlign::Left => "ant-table-cell-left",
-                                                        TableAlign::Center => "ant-table-cell-center",
-                                                        TableAlign::Right => "ant-table-cell-right",
-                                                    }
-                                                } else {
-                                                    ""
-                                                }
-                                            ),
-                                            key: column.key.clone(),
+                    // 展开列
+                    if expand_column && row_index == 0 {
+                        th {
+                            class: "ant-table-cell ant-table-row-expand-icon-cell{expand_fixed.class()}",
+                            style: expand_fixed.style(),
+                            rowspan: "{header_depth}",
+                        }
+                    }
 
-                                            {row.get(&column.dataindex).cloned().unwrap_or_default()}
-                                        }
+                    // 数据列
+                    for cell in header_row.iter() {
+                        match cell {
+                            HeaderCell::Group { key, title, first, col_span } => {
+                                let fixed_cell = group_fixed_cell(&fixed, *first, *col_span);
+                                rsx! {
+                                    th {
+                                        key: "{key}-{first}",
+                                        class: "ant-table-cell ant-table-column-group{fixed_cell.class()}",
+                                        style: fixed_cell.style(),
+                                        colspan: "{col_span}",
+                                        {title.clone()}
                                     }
                                 }
                             }
+                            HeaderCell::Leaf { index, row_span } => {
+                                let column_index = *index;
+                                let column = &columns[column_index];
+                                let sort_order = column_sort_order(&sorters, &column.key);
+                                let sortable = column.is_sortable();
+                                let filtered = filters.contains_key(&column.key);
+                                let filter_open = open_filter.read().as_deref() == Some(column.key.as_str());
+                                let fixed_cell = &fixed[column_index];
+                                let draggable = props.reorderable && column.fixed.is_none();
+                                rsx! {
+                                    th {
+                                        class: format!(
+                                            "ant-table-cell {}{}{}{}{}",
+                                            column.align_class(),
+                                            if sortable { " ant-table-column-has-sorters" } else { "" },
+                                            if sort_order.is_some() { " ant-table-column-sort" } else { "" },
+                                            if dragging_column.read().as_deref() == Some(column.key.as_str()) { " ant-table-column-dragging" } else { "" },
+                                            fixed_cell.class()
+                                        ),
+                                        key: "{column.key}",
+                                        id: "{table_id}-th-{column.key}",
+                                        rowspan: "{row_span}",
+                                        style: fixed_cell.style(),
+                                        draggable: draggable,
+                                        ondragstart: {
+                                            let column_key = column.key.clone();
+                                            move |_| {
+                                                if draggable {
+                                                    dragging_column.set(Some(column_key.clone()));
+                                                }
+                                            }
+                                        },
+                                        ondragover: move |evt: DragEvent| {
+                                            if draggable {
+                                                evt.prevent_default();
+                                            }
+                                        },
+                                        ondragend: move |_| dragging_column.set(None),
+                                        ondrop: {
+                                            let column_key = column.key.clone();
+                                            let columns = leaf_columns.clone();
+                                            let column_state = column_state.clone();
+                                            move |evt: DragEvent| {
+                                                evt.prevent_default();
+                                                let Some(from) = dragging_column.take() else {
+                                                    return;
+                                                };
+                                                if draggable && from != column_key {
+                                                    update_column_state(column_state.move_column(&columns, &from, &column_key));
+                                                }
+                                            }
+                                        },
+                                        title: if sortable && column.show_sorter_tooltip {
+                                            Some(sorter_tooltip(&locale, sort_order))
+                                        } else {
+                                            None
+                                        },
+                                        onclick: {
+                                            let column_key = column.key.clone();
+                                            let columns = leaf_columns.clone();
+                                            let sorters = sorters.clone();
+                                            let on_sort_change = props.on_sort_change;
+                                            let change = change.clone();
+                                            move |_| {
+                                                if !sortable {
+                                                    return;
+                                                }
+                                                let next = toggle_sorter(&sorters, &columns, &column_key);
+                                                inner_sorters.set(next.clone());
+                                                if let Some(on_sort_change) = on_sort_change {
+                                                    on_sort_change.call(next.clone());
+                                                }
+                                                emit_change(TableChange {
+                                                    sorter: next,
+                                                    action: TableAction::Sort,
+                                                    ..change.clone()
+                                                });
+                                            }
+                                        },
+
+                                        // 调整列宽的拖拽手柄
+                                        if props.resizable {
+                                            span {
+                                                class: "ant-table-column-resize-handle",
+                                                onclick: move |evt| evt.stop_propagation(),
+                                                onmousedown: {
+                                                    let column_key = column.key.clone();
+                                                    let width = column.width.as_deref().and_then(parse_px);
+                                                    let th_id = format!("{table_id}-th-{}", column.key);
+                                                    move |evt: MouseEvent| {
+                                                        evt.prevent_default();
+                                                        evt.stop_propagation();
+                                                        let start_x = evt.client_coordinates().x;
+                                                        let column_key = column_key.clone();
+                                                        let th_id = th_id.clone();
+                                                        spawn(async move {
+                                                            // 未设置像素宽度时读取表头单元格的实际宽度
+                                                            let start_width = match width {
+                                                                Some(width) => width,
+                                                                None => document::eval(&format!(
+                                                                    "var th = document.getElementById('{th_id}'); return th ? th.getBoundingClientRect().width : 0;"
+                                                                ))
+                                                                .await
+                                                                .ok()
+                                                                .and_then(|value| value.as_f64())
+                                                                .unwrap_or(MIN_COLUMN_WIDTH),
+                                                            };
+                                                            resizing.set(Some(ColumnResize {
+                                                                key: column_key,
+                                                                start_x,
+                                                                start_width,
+                                                                width: start_width,
+                                                            }));
+                                                        });
+                                                    }
+                                                },
+                                            }
+                                        }
 
-                            // 空数据提示
-                            if props.data_source.is_empty() {
-                                tr {
-                                    class: "ant-table-placeholder",
-                                    td {
-                                        class: "ant-table-cell",
-                                        colspan: format!("{}", props.columns.len() + if props.row_selection.is_some() { 1 } else { 0 }),
                                         div {
-                                            class: "ant-empty ant-empty-normal",
-                                            div {
-                                                class: "ant-empty-image",
-                                                "📄"
+                                            class: if column.is_editable() && is_required(&column.rules) {
+                                                "ant-table-column-title ant-table-column-title-required"
+                                            } else {
+                                                "ant-table-column-title"
+                                            },
+                                            {column.title.clone()}
+                                        }
+
+                                        // 筛选菜单
+                                        if column.is_filterable() {
+                                            span {
+                                                class: if filtered {
+                                                    "ant-table-filter-trigger active"
+                                                } else {
+                                                    "ant-table-filter-trigger"
+                                                },
+                                                onclick: {
+                                                    let column_key = column.key.clone();
+                                                    move |evt: MouseEvent| {
+                                                        evt.stop_propagation();
+                                                        if filter_open {
+                                                            open_filter.set(None);
+                                                        } else {
+                                                            open_filter.set(Some(column_key.clone()));
+                                                        }
+                                                    }
+                                                },
+                                                "⏷"
+                                            }
+                                            if filter_open {
+                                                FilterDropdown {
+                                                    key: "{column.key}",
+                                                    filters: column.filters.clone(),
+                                                    selected: filters.get(&column.key).cloned().unwrap_or_default(),
+                                                    multiple: column.filter_multiple,
+                                                    search: column.filter_search,
+                                                    on_confirm: {
+                                                        let column_key = column.key.clone();
+                                                        let columns = leaf_columns.clone();
+                                                        let change = change.clone();
+                                                        move |values: Vec<String>| {
+                                                            inner_filters.with_mut(|filters| {
+                                                                filters.insert(column_key.clone(), values);
+                                                            });
+                                                            open_filter.set(None);
+                                                            current_page.set(1);
+                                                            emit_change(TableChange {
+                                                                pagination: change.pagination.clone().map(|pagination| TablePagination {
+                                                                    current: 1,
+                                                                    ..pagination
+                                                                }),
+                                                                filters: merge_filters(&columns, &inner_filters.read()),
+                                                                action: TableAction::Filter,
+                                                                ..change.clone()
+                                                            });
+                                                        }
+                                                    },
+                                                }
                                             }
+                                        }
+
+                                        // 排序图标
+                                        if sortable {
                                             div {
-                                                class: "ant-empty-description",
-                                                "暂无数据"
+                                                class: "ant-table-column-sorters",
+                                                span {
+                                                    class: "ant-table-column-sorter",
+                                                    span {
+                                                        class: if sort_order == Some(SortOrder::Ascend) {
+                                                            "ant-table-column-sorter-up active"
+                                                        } else {
+                                                            "ant-table-column-sorter-up"
+                                                        },
+                                                        "▲"
+                                                    }
+                                                    span {
+                                                        class: if sort_order == Some(SortOrder::Descend) {
+                                                            "ant-table-column-sorter-down active"
+                                                        } else {
+                                                            "ant-table-column-sorter-down"
+                                                        },
+                                                        "▼"
+                                                    }
+                                                }
                                             }
                                         }
                                     }
@@ -385,42 +1407,1474 @@ pub fn Table(props: TableProps) -> Element {
                             }
                         }
                     }
+
+                    // 编辑操作列
+                    if action_column && row_index == 0 {
+                        th {
+                            class: "ant-table-cell ant-table-edit-action-column",
+                            rowspan: "{header_depth}",
+                            {props.editable.as_ref().map(|editable| {
+                                editable
+                                    .action_title
+                                    .clone()
+                                    .unwrap_or_else(|| locale.translate("table_action"))
+                            })}
+                        }
+                    }
                 }
             }
+        }
+    };
 
-            // 表格尾部
-            if let Some(footer) = &props.footer {
+    let tbody = rsx! {
+        tbody {
+            class: "ant-table-tbody",
+
+            // 虚拟滚动时可视区间之前的占位
+            if range.offset_top > 0.0 {
+                tr {
+                    class: "ant-table-virtual-placeholder",
+                    style: "height: {range.offset_top}px;",
+                    td { colspan: "{column_count}" }
+                }
+            }
+
+            // 数据行
+            for (index, flat) in flat_rows.iter().enumerate().skip(range.start).take(range.end - range.start) {
+                {
+                    let row = &flat.row;
+                    let row_key = row.row_key();
+                    let row_expandable = props
+                        .expandable
+                        .as_ref()
+                        .is_some_and(|expandable| expandable.is_row_expandable(row));
+                    let row_expanded = expanded_set.contains(row_key.as_str());
+                    let row_selected = selected_set.contains(row_key.as_str());
+                    let expand_by_click = props
+                        .expandable
+                        .as_ref()
+                        .is_some_and(|expandable| expandable.expand_row_by_click)
+                        && (row_expandable || flat.has_children);
+                    rsx! {
+                        Fragment {
+                            key: "{row_key}",
+                            tr {
+                                class: format!(
+                                    "ant-table-row ant-table-row-level-{}{}",
+                                    flat.depth,
+                                    if row_selected { " ant-table-row-selected" } else { "" }
+                                ),
+                                style: if props.virtual_scroll {
+                                    Some(format!("height: {row_height}px;"))
+                                } else {
+                                    None
+                                },
+                                onmounted: {
+                                    let measure = props.virtual_scroll && index == range.start;
+                                    move |evt: MountedEvent| {
+                                        if !measure || measured_row_height.peek().is_some() {
+                                            return;
+                                        }
+                                        spawn(async move {
+                                            if let Ok(rect) = evt.data().get_client_rect().await {
+                                                if rect.height() > 0.0 {
+                                                    measured_row_height.set(Some(rect.height()));
+                                                }
+                                            }
+                                        });
+                                    }
+                                },
+                                onclick: {
+                                    let handler = props.on_row_click;
+                                    let toggle_expand = toggle_expand.clone();
+                                    let row = row.clone();
+                                    move |_| {
+                                        if expand_by_click {
+                                            toggle_expand(row.clone());
+                                        }
+                                        if let Some(handler) = &handler {
+                                            handler.call(row.clone());
+                                        }
+                                    }
+                                },
+
+                                // 行选择列
+                                if let Some(selection) = &props.row_selection {
+                                    td {
+                                        class: "ant-table-cell ant-table-selection-column{selection_fixed.class()}",
+                                        style: selection_fixed.style(),
+                                        input {
+                                            r#type: if is_checkbox { "checkbox" } else { "radio" },
+                                            class: match (is_checkbox, !selection.check_strictly && is_row_indeterminate(row, &selected_set)) {
+                                                (true, true) => "ant-checkbox-input ant-checkbox-indeterminate",
+                                                (true, false) => "ant-checkbox-input",
+                                                _ => "ant-radio-input",
+                                            },
+                                            checked: row_selected,
+                                            onclick: move |evt| evt.stop_propagation(),
+                                            onchange: {
+                                                let update_selection = update_selection.clone();
+                                                let selected_keys = selected_keys.clone();
+                                                let selection_type = selection.selection_type.clone();
+                                                let cascade = is_checkbox && !selection.check_strictly;
+                                                let on_select = selection.on_select;
+                                                let data_source = data_source.clone();
+                                                let row = row.clone();
+                                                move |_| {
+                                                    let key = row.row_key();
+                                                    let keys = if cascade {
+                                                        toggle_tree_selection(&data_source, &selected_keys, &row, !row_selected)
+                                                    } else {
+                                                        toggle_selected_key(&selected_keys, &key, &selection_type)
+                                                    };
+                                                    if let Some(on_select) = on_select {
+                                                        on_select.call((row.clone(), keys.contains(&key)));
+                                                    }
+                                                    update_selection(keys);
+                                                }
+                                            },
+                                        }
+                                    }
+                                }
+
+                                // 展开列
+                                if expand_column {
+                                    td {
+                                        class: "ant-table-cell ant-table-row-expand-icon-cell{expand_fixed.class()}",
+                                        style: expand_fixed.style(),
+                                        if row_expandable {
+                                            button {
+                                                r#type: "button",
+                                                class: if row_expanded {
+                                                    "ant-table-row-expand-icon ant-table-row-expand-icon-expanded"
+                                                } else {
+                                                    "ant-table-row-expand-icon ant-table-row-expand-icon-collapsed"
+                                                },
+                                                "aria-label": if row_expanded { "关闭行" } else { "展开行" },
+                                                onclick: {
+                                                    let toggle_expand = toggle_expand.clone();
+                                                    let row = row.clone();
+                                                    move |evt: MouseEvent| {
+                                                        evt.stop_propagation();
+                                                        toggle_expand(row.clone());
+                                                    }
+                                                },
+                                            }
+                                        }
+                                    }
+                                }
+
+                                // 数据列
+                                for (column_index, column) in columns.iter().enumerate() {
+                                    if let Some(cell_props) = Some(column.cell_props(row, index)).filter(|cell_props| !cell_props.is_hidden()) {
+                                        td {
+                                            colspan: cell_props.col_span.map(|col_span| col_span.to_string()),
+                                            rowspan: cell_props.row_span.map(|row_span| row_span.to_string()),
+                                            class: format!(
+                                                "ant-table-cell {}{}{}{}",
+                                                column.align_class(),
+                                                if column_sort_order(&sorters, &column.key).is_some() { " ant-table-column-sort" } else { "" },
+                                                if column.is_editable() && edit_mode == TableEditMode::Cell { " ant-table-cell-editable" } else { "" },
+                                                fixed[column_index].class()
+                                            ),
+                                            key: "{column.key}",
+                                            style: fixed[column_index].style(),
+                                            onclick: {
+                                                let start_editing = start_editing.clone();
+                                                let editable = props.editable.is_some()
+                                                    && edit_mode == TableEditMode::Cell
+                                                    && column.is_editable();
+                                                let column_key = column.key.clone();
+                                                let row = row.clone();
+                                                move |evt: MouseEvent| {
+                                                    if editable {
+                                                        evt.stop_propagation();
+                                                        start_editing(row.clone(), Some(column_key.clone()));
+                                                    }
+                                                }
+                                            },
+
+                                            // 树形数据的缩进与展开图标
+                                            if tree_mode && column_index == 0 {
+                                                span {
+                                                    class: "ant-table-row-indent indent-level-{flat.depth}",
+                                                    style: "padding-left: {flat.depth as f64 * indent_size}px;",
+                                                }
+                                                if flat.has_children {
+                                                    button {
+                                                        r#type: "button",
+                                                        class: if row_expanded {
+                                                            "ant-table-row-expand-icon ant-table-row-expand-icon-expanded"
+                                                        } else {
+                                                            "ant-table-row-expand-icon ant-table-row-expand-icon-collapsed"
+                                                        },
+                                                        "aria-label": if row_expanded { "关闭行" } else { "展开行" },
+                                                        onclick: {
+                                                            let toggle_expand = toggle_expand.clone();
+                                                            let row = row.clone();
+                                                            move |evt: MouseEvent| {
+                                                                evt.stop_propagation();
+                                                                toggle_expand(row.clone());
+                                                            }
+                                                        },
+                                                    }
+                                                } else {
+                                                    span { class: "ant-table-row-expand-icon ant-table-row-expand-icon-spaced" }
+                                                }
+                                            }
+
+                                            match editing_state.as_ref().filter(|state| state.is_editing(&row_key, &column.key)) {
+                                                Some(state) => {
+                                                    let column_key = column.key.clone();
+                                                    let save_editing = save_editing.clone();
+                                                    let cancel_editing = cancel_editing.clone();
+                                                    let editor = column.editor.clone().unwrap_or_else(TableCellEditor::input);
+                                                    let error = state.errors.get(&column.key).cloned();
+                                                    let context = CellEditContext {
+                                                        value: state.draft.get(&column.key).cloned().unwrap_or_default(),
+                                                        error: error.clone(),
+                                                        save_on_blur: edit_mode == TableEditMode::Cell,
+                                                        on_change: EventHandler::new(move |value: String| {
+                                                            let mut editing = editing;
+                                                            editing.with_mut(|state| {
+                                                                if let Some(state) = state {
+                                                                    state.errors.remove(&column_key);
+                                                                    state.draft.insert(column_key.clone(), value);
+                                                                }
+                                                            });
+                                                        }),
+                                                        on_save: EventHandler::new(move |_| {
+                                                            save_editing();
+                                                        }),
+                                                        on_cancel: EventHandler::new({
+                                                            let cancel_editing = cancel_editing.clone();
+                                                            move |_| cancel_editing()
+                                                        }),
+                                                    };
+                                                    rsx! {
+                                                        div {
+                                                            class: "ant-table-cell-editor",
+                                                            onclick: move |evt| evt.stop_propagation(),
+                                                            onkeydown: move |evt: KeyboardEvent| {
+                                                                if evt.key() == Key::Escape {
+                                                                    cancel_editing();
+                                                                }
+                                                            },
+                                                            {editor(context)}
+                                                            if let Some(error) = error {
+                                                                div { class: "ant-form-item-explain-error", "{error}" }
+                                                            }
+                                                        }
+                                                    }
+                                                }
+                                                None => column.render_cell(row, index),
+                                            }
+                                        }
+                                    }
+                                }
+
+                                // 编辑操作列
+                                if action_column {
+                                    td {
+                                        class: "ant-table-cell ant-table-edit-action-column",
+                                        onclick: move |evt| evt.stop_propagation(),
+                                        if editing_state.as_ref().is_some_and(|state| state.row_key == row_key) {
+                                            a {
+                                                class: "ant-table-edit-action",
+                                                onclick: {
+                                                    let save_editing = save_editing.clone();
+                                                    move |_| {
+                                                        save_editing();
+                                                    }
+                                                },
+                                                {locale.translate("save")}
+                                            }
+                                            a {
+                                                class: "ant-table-edit-action",
+                                                onclick: {
+                                                    let cancel_editing = cancel_editing.clone();
+                                                    move |_| cancel_editing()
+                                                },
+                                                {locale.translate("cancel")}
+                                            }
+                                        } else {
+                                            a {
+                                                class: if editing_state.is_some() {
+                                                    "ant-table-edit-action ant-table-edit-action-disabled"
+                                                } else {
+                                                    "ant-table-edit-action"
+                                                },
+                                                onclick: {
+                                                    let start_editing = start_editing.clone();
+                                                    let busy = editing_state.is_some();
+                                                    let row = row.clone();
+                                                    move |_| {
+                                                        if !busy {
+                                                            start_editing(row.clone(), None);
+                                                        }
+                                                    }
+                                                },
+                                                {locale.translate("edit")}
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+
+                            // 展开行内容
+                            if row_expandable && row_expanded {
+                                if let Some(render) = props.expandable.as_ref().and_then(|expandable| expandable.expanded_row_render.clone()) {
+                                    tr {
+                                        class: "ant-table-expanded-row ant-table-expanded-row-level-{flat.depth + 1}",
+                                        td {
+                                            class: "ant-table-cell",
+                                            colspan: "{column_count}",
+                                            {render(row, index)}
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            // 虚拟滚动时可视区间之后的占位
+            if range.offset_bottom > 0.0 {
+                tr {
+                    class: "ant-table-virtual-placeholder",
+                    style: "height: {range.offset_bottom}px;",
+                    td { colspan: "{column_count}" }
+                }
+            }
+
+            // 空数据提示
+            if page_rows.is_empty() {
+                tr {
+                    class: "ant-table-placeholder",
+                    td {
+                        class: "ant-table-cell",
+                        colspan: "{column_count}",
+                        div {
+                            class: "ant-empty ant-empty-normal",
+                            div {
+                                class: "ant-empty-image",
+                                "📄"
+                            }
+                            div {
+                                class: "ant-empty-description",
+                                "暂无数据"
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    };
+
+    rsx! {
+        style { {TABLE_STYLES} }
+
+        div {
+            class: "ant-table-wrapper",
+            style: props.style.clone(),
+
+            // 表格标题
+            if let Some(title) = &props.title {
                 div {
-                    class: "ant-table-footer",
-                    {footer.clone()}
+                    class: "ant-table-title",
+                    {title.clone()}
                 }
             }
 
-            // 分页器
-            if let Some(pagination) = &props.pagination {
+            // 列设置
+            if props.show_column_picker {
+                div {
+                    class: "ant-table-column-picker",
+                    button {
+                        r#type: "button",
+                        class: "ant-btn ant-btn-sm ant-table-column-picker-trigger",
+                        onclick: move |_| column_picker_open.set(!column_picker_open()),
+                        {locale.translate("table_column_settings")}
+                    }
+                    if column_picker_open() {
+                        div {
+                            class: "ant-table-column-picker-dropdown",
+                            for column in column_state.ordered(&leaf_columns) {
+                                label {
+                                    key: "{column.key}",
+                                    class: "ant-table-column-picker-item",
+                                    input {
+                                        r#type: "checkbox",
+                                        class: "ant-checkbox-input",
+                                        checked: !column_state.is_hidden(&column.key),
+                                        onchange: {
+                                            let column_state = column_state.clone();
+                                            let column_key = column.key.clone();
+                                            move |_| update_column_state(column_state.toggle_hidden(&column_key))
+                                        },
+                                    }
+                                    span { {column.title.clone()} }
+                                }
+                            }
+                            div {
+                                class: "ant-table-column-picker-footer",
+                                button {
+                                    r#type: "button",
+                                    class: "ant-btn ant-btn-link ant-btn-sm",
+                                    onclick: move |_| update_column_state(ColumnState::default()),
+                                    {locale.translate("reset")}
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            // 拖拽调整列宽时覆盖整个页面以持续捕获鼠标移动
+            if resizing.read().is_some() {
                 div {
-                    class: "ant-table-pagination ant-pagination",
+                    class: "ant-table-column-resize-mask",
+                    onmousemove: move |evt: MouseEvent| {
+                        let x = evt.client_coordinates().x;
+                        resizing.with_mut(|resize| {
+                            if let Some(resize) = resize {
+                                resize.width = (resize.start_width + x - resize.start_x).max(MIN_COLUMN_WIDTH);
+                            }
+                        });
+                    },
+                    onmouseup: {
+                        let column_state = column_state.clone();
+                        move |_| {
+                            if let Some(resize) = resizing.take() {
+                                update_column_state(column_state.resize(&resize.key, resize.width));
+                            }
+                        }
+                    },
+                }
+            }
+
+            // 表格容器
+            div {
+                class: if loading { "ant-table-container ant-table-loading" } else { "ant-table-container" },
+
+                // 加载中遮罩
+                if loading {
                     div {
-                        class: "ant-pagination-total-text",
-                        {format!("共 {} 条", pagination.total)}
+                        class: "ant-table-loading-mask",
+                        Spin {}
                     }
-                    ul {
-                        class: "ant-pagination-list",
-                        li {
-                            class: "ant-pagination-prev",
-                            "‹"
+                }
+
+                // 表格内容
+                div {
+                    class: format!("{table_class}{scroll_class}{sticky_class}"),
+
+                    if fixed_header {
+                        // 固定表头：表头与表体分开渲染，表体滚动时同步表头的横向滚动位置
+                        if props.show_header {
+                            div {
+                                class: "ant-table-header",
+                                id: "{table_id}-header",
+                                table {
+                                    class: "ant-table-table",
+                                    style: table_style.clone(),
+                                    {colgroup.clone()}
+                                    {thead}
+                                }
+                            }
                         }
-                        li {
-                            class: "ant-pagination-item ant-pagination-item-active",
-                            {pagination.current.to_string()}
+                        div {
+                            class: "ant-table-body",
+                            style: "max-height: {scroll_height}px;",
+                            onmounted: move |evt: MountedEvent| body_ref.set(Some(evt.data())),
+                            onscroll: handle_scroll,
+                            table {
+                                class: "ant-table-table",
+                                style: table_style.clone(),
+                                {colgroup}
+                                {tbody}
+                                {tfoot.clone()}
+                            }
                         }
-                        li {
-                            class: "ant-pagination-next",
-                            "›"
+                    } else {
+                        div {
+                            class: "ant-table-content",
+                            table {
+                                class: "ant-table-table",
+                                style: table_style.clone(),
+                                {colgroup}
+                                if props.show_header {
+                                    {thead}
+                                }
+                                {tbody}
+                                {tfoot}
+                            }
                         }
                     }
                 }
             }
+
+            // 表格尾部
+            if let Some(footer) = &props.footer {
+                div {
+                    class: "ant-table-footer",
+                    {footer.clone()}
+                }
+            }
+
+            // 分页器
+            if let Some(pagination) = &pagination {
+                div {
+                    class: "ant-table-pagination",
+                    Pagination {
+                        key: "{pagination.current}-{pagination.page_size}-{pagination.total}",
+                        current: pagination.current,
+                        total: pagination.total,
+                        page_size: pagination.page_size,
+                        page_size_options: page_size_options(&pagination.page_size_options),
+                        show_size_changer: pagination.show_size_changer,
+                        show_quick_jumper: pagination.show_quick_jumper,
+                        show_total: true,
+                        on_change: {
+                            let change = change.clone();
+                            move |page: usize| {
+                                current_page.set(page);
+                                emit_change(TableChange {
+                                    pagination: change.pagination.clone().map(|pagination| TablePagination {
+                                        current: page,
+                                        ..pagination
+                                    }),
+                                    ..change.clone()
+                                });
+                            }
+                        },
+                        on_show_size_change: {
+                            let change = change.clone();
+                            move |(page, size): (usize, usize)| {
+                                current_page.set(page);
+                                page_size.set(size);
+                                emit_change(TableChange {
+                                    pagination: change.pagination.clone().map(|pagination| TablePagination {
+                                        current: page,
+                                        page_size: size,
+                                        ..pagination
+                                    }),
+                                    ..change.clone()
+                                });
+                            }
+                        },
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::form::FormRule;
+    use crate::locale::Locale;
+
+    #[derive(Clone, PartialEq)]
+    struct User {
+        id: u32,
+        name: String,
+    }
+
+    fn keys(rows: &[User]) -> Vec<u32> {
+        rows.iter().map(|row| row.id).collect()
+    }
+
+    impl TableRow for User {
+        fn row_key(&self) -> String {
+            self.id.to_string()
+        }
+    }
+
+    fn users() -> Vec<User> {
+        vec![
+            User {
+                id: 1,
+                name: "John".to_string(),
+            },
+            User {
+                id: 2,
+                name: "Jim".to_string(),
+            },
+        ]
+    }
+
+    #[test]
+    fn test_column_cell_text() {
+        let column = TableColumn::new("name", "Name").value(|user: &User| user.name.clone());
+        assert_eq!(column.cell_text(&users()[0]), "John");
+
+        let empty: TableColumn<User> = TableColumn::new("empty", "Empty");
+        assert_eq!(empty.cell_text(&users()[0]), "");
+    }
+
+    #[test]
+    fn test_hashmap_row_key() {
+        let mut row = HashMap::new();
+        row.insert("key".to_string(), "42".to_string());
+        assert_eq!(row.row_key(), "42");
+    }
+
+    #[test]
+    fn test_toggle_selected_key() {
+        let keys = toggle_selected_key(&[], "1", &SelectionType::Checkbox);
+        assert_eq!(keys, vec!["1".to_string()]);
+
+        let keys = toggle_selected_key(&keys, "2", &SelectionType::Checkbox);
+        assert_eq!(keys, vec!["1".to_string(), "2".to_string()]);
+
+        let keys = toggle_selected_key(&keys, "1", &SelectionType::Checkbox);
+        assert_eq!(keys, vec!["2".to_string()]);
+
+        let keys = toggle_selected_key(&keys, "1", &SelectionType::Radio);
+        assert_eq!(keys, vec!["1".to_string()]);
+    }
+
+    #[test]
+    fn test_collect_selected_rows() {
+        let rows = collect_selected_rows(&users(), &["2".to_string()]);
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].name, "Jim");
+    }
+
+    #[test]
+    fn test_next_sort_order() {
+        assert_eq!(next_sort_order(None), Some(SortOrder::Ascend));
+        assert_eq!(
+            next_sort_order(Some(SortOrder::Ascend)),
+            Some(SortOrder::Descend)
+        );
+        assert_eq!(next_sort_order(Some(SortOrder::Descend)), None);
+    }
+
+    #[test]
+    fn test_sorter_tooltip_locale() {
+        let zh = LocaleConfig::new(Locale::ZhCN);
+        assert_eq!(sorter_tooltip(&zh, None), "点击升序");
+        assert_eq!(sorter_tooltip(&zh, Some(SortOrder::Descend)), "取消排序");
+
+        let en = LocaleConfig::new(Locale::En);
+        assert_eq!(sorter_tooltip(&en, None), "Click to sort ascending");
+        assert_eq!(
+            sorter_tooltip(&en, Some(SortOrder::Ascend)),
+            "Click to sort descending"
+        );
+    }
+
+    #[test]
+    fn test_sort_rows_with_sorter() {
+        let columns =
+            vec![TableColumn::new("id", "Id").sorter(|a: &User, b: &User| a.id.cmp(&b.id))];
+        let mut rows = users();
+        rows.reverse();
+
+        let sorted = sort_rows(
+            &rows,
+            &columns,
+            &[SorterState::new("id", SortOrder::Ascend)],
+        );
+        assert_eq!(keys(&sorted), vec![1, 2]);
+
+        let sorted = sort_rows(
+            &rows,
+            &columns,
+            &[SorterState::new("id", SortOrder::Descend)],
+        );
+        assert_eq!(keys(&sorted), vec![2, 1]);
+
+        let unsorted = sort_rows(&rows, &columns, &[]);
+        assert_eq!(keys(&unsorted), vec![2, 1]);
+    }
+
+    #[test]
+    fn test_sort_rows_by_cell_text() {
+        let columns = vec![TableColumn::new("id", "Id")
+            .value(|user: &User| user.id.to_string())
+            .sortable()];
+        let rows: Vec<User> = [10, 9, 100]
+            .into_iter()
+            .map(|id| User {
+                id,
+                name: String::new(),
+            })
+            .collect();
+
+        // 数字文本按数值排序
+        let sorted = sort_rows(
+            &rows,
+            &columns,
+            &[SorterState::new("id", SortOrder::Ascend)],
+        );
+        assert_eq!(keys(&sorted), vec![9, 10, 100]);
+    }
+
+    #[test]
+    fn test_multiple_sort_priority() {
+        let columns = vec![
+            TableColumn::new("name", "Name")
+                .sorter(|a: &User, b: &User| a.name.cmp(&b.name))
+                .sort_multiple(2),
+            TableColumn::new("id", "Id")
+                .sorter(|a: &User, b: &User| a.id.cmp(&b.id))
+                .sort_multiple(1),
+        ];
+        let rows = vec![
+            User {
+                id: 2,
+                name: "b".to_string(),
+            },
+            User {
+                id: 1,
+                name: "b".to_string(),
+            },
+            User {
+                id: 3,
+                name: "a".to_string(),
+            },
+        ];
+        let sorters = vec![
+            SorterState::new("id", SortOrder::Descend),
+            SorterState::new("name", SortOrder::Ascend),
+        ];
+
+        let sorted = sort_rows(&rows, &columns, &sorters);
+        assert_eq!(keys(&sorted), vec![3, 2, 1]);
+    }
+
+    #[test]
+    fn test_toggle_sorter() {
+        let columns = vec![
+            TableColumn::<User>::new("a", "A")
+                .sortable()
+                .sort_multiple(1),
+            TableColumn::<User>::new("b", "B")
+                .sortable()
+                .sort_multiple(2),
+            TableColumn::<User>::new("c", "C").sortable(),
+        ];
+
+        let state = toggle_sorter(&[], &columns, "a");
+        assert_eq!(state, vec![SorterState::new("a", SortOrder::Ascend)]);
+
+        // 多列排序的列可以共存
+        let state = toggle_sorter(&state, &columns, "b");
+        assert_eq!(state.len(), 2);
+
+        let state = toggle_sorter(&state, &columns, "a");
+        assert_eq!(column_sort_order(&state, "a"), Some(SortOrder::Descend));
+
+        // 普通列会清除其他排序
+        let state = toggle_sorter(&state, &columns, "c");
+        assert_eq!(state, vec![SorterState::new("c", SortOrder::Ascend)]);
+    }
+
+    #[test]
+    fn test_paginate_rows() {
+        let rows: Vec<usize> = (1..=25).collect();
+        let pagination = TablePagination {
+            current: 3,
+            page_size: 10,
+            total: 25,
+            show_quick_jumper: false,
+            show_size_changer: false,
+            page_size_options: Vec::new(),
+        };
+        assert_eq!(
+            paginate_rows(&rows, Some(&pagination)),
+            vec![21, 22, 23, 24, 25]
+        );
+        assert_eq!(paginate_rows(&rows, None).len(), 25);
+    }
+
+    #[test]
+    fn test_filter_rows() {
+        let columns = vec![
+            TableColumn::new("name", "Name").value(|user: &User| user.name.clone()),
+            TableColumn::new("id", "Id")
+                .on_filter(|value: &str, user: &User| user.id.to_string() == value),
+        ];
+
+        let mut filters = TableFilters::new();
+        filters.insert(
+            "name".to_string(),
+            vec!["John".to_string(), "Jim".to_string()],
+        );
+        assert_eq!(keys(&filter_rows(&users(), &columns, &filters)), vec![1, 2]);
+
+        filters.insert("id".to_string(), vec!["2".to_string()]);
+        assert_eq!(keys(&filter_rows(&users(), &columns, &filters)), vec![2]);
+
+        filters.insert("name".to_string(), vec!["John".to_string()]);
+        assert!(filter_rows(&users(), &columns, &filters).is_empty());
+    }
+
+    #[test]
+    fn test_merge_filters() {
+        let columns = vec![
+            TableColumn::<User>::new("a", "A").filtered_value(vec!["x".to_string()]),
+            TableColumn::<User>::new("b", "B").default_filtered_value(vec!["y".to_string()]),
+        ];
+        let mut inner = default_filters(&columns);
+        assert_eq!(inner.get("b"), Some(&vec!["y".to_string()]));
+
+        inner.insert("a".to_string(), vec!["z".to_string()]);
+        inner.insert("b".to_string(), Vec::new());
+        let merged = merge_filters(&columns, &inner);
+        // 受控值优先，空筛选值会被移除
+        assert_eq!(merged.get("a"), Some(&vec!["x".to_string()]));
+        assert!(!merged.contains_key("b"));
+    }
+
+    #[test]
+    fn test_page_size_options() {
+        let options = page_size_options(&["10条".to_string(), "20条".to_string()]).unwrap();
+        assert_eq!(options[0].size, 10);
+        assert_eq!(options[1].label, "20条");
+        assert!(page_size_options(&["abc".to_string()]).is_none());
+    }
+
+    #[test]
+    fn test_virtual_range() {
+        let range = virtual_range(50_000, 0.0, 400.0, 40.0, 5);
+        assert_eq!(range.start, 0);
+        assert_eq!(range.end, 16);
+        assert_eq!(range.offset_top, 0.0);
+        assert_eq!(range.offset_bottom, (50_000 - 16) as f64 * 40.0);
+
+        let range = virtual_range(50_000, 4000.0, 400.0, 40.0, 5);
+        assert_eq!(range.start, 95);
+        assert_eq!(range.end, 116);
+        assert_eq!(range.offset_top, 95.0 * 40.0);
+
+        // 滚动到底部时不会越界
+        let range = virtual_range(100, 1_000_000.0, 400.0, 40.0, 5);
+        assert_eq!(range.end, 100);
+        assert!(range.start <= range.end);
+        assert_eq!(range.offset_bottom, 0.0);
+    }
+
+    #[test]
+    fn test_parse_px() {
+        assert_eq!(parse_px("150px"), Some(150.0));
+        assert_eq!(parse_px(" 80 "), Some(80.0));
+        assert_eq!(parse_px("20%"), None);
+    }
+
+    #[test]
+    fn test_fixed_cells() {
+        let columns = arrange_fixed_columns(&[
+            TableColumn::<User>::new("a", "A"),
+            TableColumn::<User>::new("b", "B")
+                .width("100px")
+                .fixed(TableFixed::Left),
+            TableColumn::<User>::new("c", "C")
+                .width("80px")
+                .fixed(TableFixed::Right),
+            TableColumn::<User>::new("d", "D")
+                .width("120px")
+                .fixed(TableFixed::Left),
+            TableColumn::<User>::new("e", "E")
+                .width("60px")
+                .fixed(TableFixed::Right),
+        ]);
+        let order: Vec<&str> = columns.iter().map(|column| column.key.as_str()).collect();
+        assert_eq!(order, vec!["b", "d", "a", "c", "e"]);
+
+        let (selection, cells) = fixed_cells(&columns, Some(48.0));
+        assert_eq!(selection.side, Some(TableFixed::Left));
+        assert!(!selection.is_edge);
+
+        assert_eq!(cells[0].offset, 48.0);
+        assert_eq!(cells[1].offset, 148.0);
+        assert!(cells[1].is_edge);
+        assert_eq!(cells[2].side, None);
+        assert_eq!(cells[3].offset, 60.0);
+        assert!(cells[3].is_edge);
+        assert_eq!(cells[4].offset, 0.0);
+        assert_eq!(cells[4].style(), "position: sticky; right: 0px;");
+    }
+
+    #[derive(Clone, PartialEq)]
+    struct Node {
+        key: &'static str,
+        children: Vec<Node>,
+    }
+
+    impl TableRow for Node {
+        fn row_key(&self) -> String {
+            self.key.to_string()
+        }
+
+        fn children(&self) -> &[Self] {
+            &self.children
+        }
+    }
+
+    fn node(key: &'static str, children: Vec<Node>) -> Node {
+        Node { key, children }
+    }
+
+    fn tree() -> Vec<Node> {
+        vec![
+            node(
+                "1",
+                vec![
+                    node("1-1", vec![]),
+                    node("1-2", vec![node("1-2-1", vec![]), node("1-2-2", vec![])]),
+                ],
+            ),
+            node("2", vec![]),
+        ]
+    }
+
+    fn strings(keys: &[&str]) -> Vec<String> {
+        keys.iter().map(|key| key.to_string()).collect()
+    }
+
+    #[test]
+    fn test_flatten_rows() {
+        let rows = tree();
+        let flat = flatten_rows(&rows, &[], &[], &[]);
+        let order: Vec<String> = flat.iter().map(|flat| flat.row.row_key()).collect();
+        assert_eq!(order, strings(&["1", "2"]));
+        assert!(flat[0].has_children);
+        assert!(!flat[0].expanded);
+
+        let flat = flatten_rows(&rows, &strings(&["1", "1-2"]), &[], &[]);
+        let order: Vec<(String, usize)> = flat
+            .iter()
+            .map(|flat| (flat.row.row_key(), flat.depth))
+            .collect();
+        assert_eq!(
+            order,
+            vec![
+                ("1".to_string(), 0),
+                ("1-1".to_string(), 1),
+                ("1-2".to_string(), 1),
+                ("1-2-1".to_string(), 2),
+                ("1-2-2".to_string(), 2),
+                ("2".to_string(), 0),
+            ]
+        );
+
+        // 子行使用相同的排序状态
+        let columns = vec![TableColumn::new("key", "Key")
+            .value(|row: &Node| row.key.to_string())
+            .sortable()];
+        let sorters = vec![SorterState::new("key", SortOrder::Descend)];
+        let flat = flatten_rows(&rows, &strings(&["1"]), &columns, &sorters);
+        let order: Vec<String> = flat.iter().map(|flat| flat.row.row_key()).collect();
+        assert_eq!(order, strings(&["1", "1-2", "1-1", "2"]));
+    }
+
+    #[test]
+    fn test_initial_expanded_keys() {
+        let expandable: TableExpandable<Node> = TableExpandable {
+            default_expanded_row_keys: strings(&["2"]),
+            ..Default::default()
+        };
+        assert_eq!(expandable.initial_expanded_keys(&tree()), strings(&["2"]));
+
+        let expandable: TableExpandable<Node> = TableExpandable {
+            default_expand_all_rows: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            expandable.initial_expanded_keys(&tree()),
+            strings(&["1", "1-2"])
+        );
+        assert_eq!(
+            toggle_expanded_key(&strings(&["1", "1-2"]), "1"),
+            strings(&["1-2"])
+        );
+    }
+
+    #[test]
+    fn test_tree_selection() {
+        let rows = tree();
+
+        // 选中父行会选中所有子孙行
+        let keys = toggle_tree_selection(&rows, &[], &rows[0].children[1], true);
+        let mut sorted = keys.clone();
+        sorted.sort();
+        assert_eq!(sorted, strings(&["1-2", "1-2-1", "1-2-2"]));
+        assert!(is_row_indeterminate(
+            &rows[0],
+            &keys.iter().map(String::as_str).collect()
+        ));
+
+        // 所有子行选中后父行自动选中
+        let keys = toggle_tree_selection(&rows, &keys, &rows[0].children[0], true);
+        assert!(keys.contains(&"1".to_string()));
+        assert!(!is_row_indeterminate(
+            &rows[0],
+            &keys.iter().map(String::as_str).collect()
+        ));
+
+        // 取消选中某个子行会取消其祖先行
+        let keys = toggle_tree_selection(&rows, &keys, &rows[0].children[1].children[0], false);
+        let mut sorted = keys.clone();
+        sorted.sort();
+        assert_eq!(sorted, strings(&["1-1", "1-2-2"]));
+
+        let keys = normalize_tree_selection(&rows, strings(&["1-2-1", "1-2-2"]));
+        assert!(keys.contains(&"1-2".to_string()));
+        assert!(!keys.contains(&"1".to_string()));
+    }
+
+    fn editable_columns() -> Vec<TableColumn<User>> {
+        vec![
+            TableColumn::new("id", "Id").value(|user: &User| user.id.to_string()),
+            TableColumn::new("name", "Name")
+                .value(|user: &User| user.name.clone())
+                .editable(|user: &mut User, value| user.name = value.to_string())
+                .rules(vec![FormRule::required("请输入姓名")]),
+        ]
+    }
+
+    #[test]
+    fn test_editing_state() {
+        let columns = editable_columns();
+        let user = &users()[0];
+
+        let state = EditingState::start(user.row_key(), user, &columns, None);
+        assert_eq!(state.draft.len(), 1);
+        assert_eq!(state.draft["name"], "John");
+        assert!(state.is_editing("1", "name"));
+        assert!(!state.is_editing("1", "id"));
+        assert!(!state.is_editing("2", "name"));
+
+        let state = EditingState::start(user.row_key(), user, &columns, Some("id"));
+        assert!(state.draft.is_empty());
+    }
+
+    #[test]
+    fn test_validate_and_apply_cell_edits() {
+        let columns = editable_columns();
+        let user = &users()[0];
+
+        let mut draft = HashMap::new();
+        draft.insert("name".to_string(), " ".to_string());
+        let errors = validate_cell_edits(&columns, &draft);
+        assert_eq!(errors.get("name").map(String::as_str), Some("请输入姓名"));
+
+        draft.insert("name".to_string(), "Jane".to_string());
+        assert!(validate_cell_edits(&columns, &draft).is_empty());
+
+        let edited = apply_cell_edits(user, &columns, &draft);
+        assert_eq!(edited.name, "Jane");
+        assert_eq!(edited.id, 1);
+        assert_eq!(user.name, "John");
+    }
+
+    fn state_columns() -> Vec<TableColumn<User>> {
+        vec![
+            TableColumn::new("a", "A").width("100px"),
+            TableColumn::new("b", "B"),
+            TableColumn::new("c", "C"),
+        ]
+    }
+
+    fn column_keys(columns: &[TableColumn<User>]) -> Vec<String> {
+        columns.iter().map(|column| column.key.clone()).collect()
+    }
+
+    #[test]
+    fn test_column_state_apply() {
+        let columns = state_columns();
+        assert_eq!(
+            column_keys(&ColumnState::default().apply(&columns)),
+            strings(&["a", "b", "c"])
+        );
+
+        let state = ColumnState {
+            order: strings(&["c", "a"]),
+            widths: HashMap::from([("a".to_string(), 180.0)]),
+            hidden: strings(&["b"]),
+        };
+        let applied = state.apply(&columns);
+        assert_eq!(column_keys(&applied), strings(&["c", "a"]));
+        assert_eq!(applied[1].width.as_deref(), Some("180px"));
+        assert_eq!(
+            column_keys(&state.ordered(&columns)),
+            strings(&["c", "a", "b"])
+        );
+    }
+
+    #[test]
+    fn test_column_state_updates() {
+        let columns = state_columns();
+        let state = ColumnState::default().move_column(&columns, "c", "a");
+        assert_eq!(state.order, strings(&["c", "a", "b"]));
+        let state = state.move_column(&columns, "c", "b");
+        assert_eq!(state.order, strings(&["a", "b", "c"]));
+
+        let state = state.resize("b", 10.0);
+        assert_eq!(state.widths["b"], MIN_COLUMN_WIDTH);
+
+        let state = state.toggle_hidden("a");
+        assert!(state.is_hidden("a"));
+        assert!(!state.toggle_hidden("a").is_hidden("a"));
+    }
+
+    #[test]
+    fn test_column_state_serde() {
+        let state = ColumnState {
+            order: strings(&["b", "a"]),
+            widths: HashMap::from([("a".to_string(), 120.0)]),
+            hidden: strings(&["c"]),
+        };
+        let json = serde_json::to_string(&state).unwrap();
+        assert_eq!(serde_json::from_str::<ColumnState>(&json).unwrap(), state);
+
+        // 缺失的字段使用默认值，便于兼容旧版本保存的布局
+        let partial: ColumnState = serde_json::from_str(r#"{"hidden":["a"]}"#).unwrap();
+        assert!(partial.order.is_empty());
+        assert_eq!(partial.hidden, strings(&["a"]));
+    }
+
+    fn grouped_columns() -> Vec<TableColumn<User>> {
+        vec![
+            TableColumn::new("name", "Name"),
+            TableColumn::group(
+                "other",
+                "Other",
+                vec![
+                    TableColumn::new("age", "Age"),
+                    TableColumn::group(
+                        "address",
+                        "Address",
+                        vec![
+                            TableColumn::new("street", "Street"),
+                            TableColumn::new("block", "Block"),
+                        ],
+                    ),
+                ],
+            ),
+            TableColumn::new("company", "Company"),
+        ]
+    }
+
+    #[test]
+    fn test_flatten_columns() {
+        let columns = grouped_columns();
+        assert_eq!(
+            column_keys(&flatten_columns(&columns)),
+            strings(&["name", "age", "street", "block", "company"])
+        );
+
+        let paths = column_group_paths(&columns);
+        assert!(paths["name"].is_empty());
+        assert_eq!(
+            paths["block"],
+            vec![
+                ("other".to_string(), "Other".to_string()),
+                ("address".to_string(), "Address".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_header_rows() {
+        let columns = grouped_columns();
+        let paths = column_group_paths(&columns);
+        let rows = header_rows(&column_keys(&flatten_columns(&columns)), &paths);
+        assert_eq!(rows.len(), 3);
+        assert_eq!(
+            rows[0],
+            vec![
+                HeaderCell::Leaf {
+                    index: 0,
+                    row_span: 3
+                },
+                HeaderCell::Group {
+                    key: "other".to_string(),
+                    title: "Other".to_string(),
+                    first: 1,
+                    col_span: 3
+                },
+                HeaderCell::Leaf {
+                    index: 4,
+                    row_span: 3
+                },
+            ]
+        );
+        assert_eq!(rows[1].len(), 2);
+        assert_eq!(
+            rows[2],
+            vec![
+                HeaderCell::Leaf {
+                    index: 2,
+                    row_span: 1
+                },
+                HeaderCell::Leaf {
+                    index: 3,
+                    row_span: 1
+                },
+            ]
+        );
+
+        // 同组的列被拆开后各自显示分组标题
+        let keys = strings(&["street", "name", "block"]);
+        let rows = header_rows(&keys, &paths);
+        let groups = rows[0]
+            .iter()
+            .filter(|cell| matches!(cell, HeaderCell::Group { .. }))
+            .count();
+        assert_eq!(groups, 2);
+
+        // 没有分组时只有一行表头
+        let rows = header_rows(&strings(&["a", "b"]), &HashMap::new());
+        assert_eq!(rows.len(), 1);
+    }
+
+    #[test]
+    fn test_cell_props() {
+        let column = TableColumn::new("name", "Name").on_cell(|_: &User, index| TableCellProps {
+            row_span: Some(if index == 0 { 2 } else { 0 }),
+            ..Default::default()
+        });
+        assert_eq!(column.cell_props(&users()[0], 0).row_span, Some(2));
+        assert!(column.cell_props(&users()[1], 1).is_hidden());
+        assert!(!TableColumn::<User>::new("id", "Id")
+            .cell_props(&users()[0], 0)
+            .is_hidden());
+    }
+
+    #[test]
+    fn test_layout_summary_row() {
+        let columns = state_columns();
+        let row = TableSummaryRow::new(vec![
+            TableSummaryCell::new("a", rsx! { "Total" }).col_span(2),
+            TableSummaryCell::new("c", rsx! { "42" }).col_span(5),
+        ]);
+        let layout = layout_summary_row(&columns, &row);
+        assert_eq!(
+            layout,
+            vec![
+                SummaryCellLayout {
+                    index: 0,
+                    col_span: 2,
+                    cell: Some(0)
+                },
+                SummaryCellLayout {
+                    index: 2,
+                    col_span: 1,
+                    cell: Some(1)
+                },
+            ]
+        );
+
+        let layout = layout_summary_row(&columns, &TableSummaryRow::default());
+        assert_eq!(layout.len(), 3);
+        assert!(layout.iter().all(|cell| cell.cell.is_none()));
+    }
+
+    #[test]
+    fn test_group_fixed_cell() {
+        let left = |offset, is_edge| FixedCell {
+            side: Some(TableFixed::Left),
+            offset,
+            is_edge,
+        };
+        let fixed = vec![left(0.0, false), left(100.0, true), FixedCell::default()];
+        let cell = group_fixed_cell(&fixed, 0, 2);
+        assert_eq!(cell.offset, 0.0);
+        assert!(cell.is_edge);
+        assert_eq!(group_fixed_cell(&fixed, 1, 2), FixedCell::default());
+    }
+
+    #[test]
+    fn test_table_remote_load() {
+        use std::task::{Context, Poll, Waker};
+
+        let remote = TableRemote::new(|query: TableQuery| async move {
+            let rows: Vec<User> = users()
+                .into_iter()
+                .skip((query.page - 1) * query.page_size)
+                .take(query.page_size)
+                .collect();
+            Ok(TableResult { rows, total: 2 })
+        })
+        .filter_debounce(500);
+        assert_eq!(remote.filter_debounce, 500);
+        assert!(remote == remote.clone());
+
+        let query = TableQuery {
+            page: 2,
+            page_size: 1,
+            sorters: vec![SorterState::new("name", SortOrder::Ascend)],
+            filters: TableFilters::new(),
+        };
+        let mut future = (remote.load)(query);
+        let mut context = Context::from_waker(Waker::noop());
+        match future.as_mut().poll(&mut context) {
+            Poll::Ready(Ok(result)) => {
+                assert_eq!(keys(&result.rows), vec![2]);
+                assert_eq!(result.total, 2);
+            }
+            _ => panic!("load should resolve immediately"),
+        }
+    }
+
+    #[test]
+    fn test_table_query_serde() {
+        let query = TableQuery {
+            page: 3,
+            page_size: 20,
+            sorters: vec![SorterState::new("age", SortOrder::Descend)],
+            filters: TableFilters::from([("name".to_string(), strings(&["Jim"]))]),
+        };
+        let json = serde_json::to_value(&query).unwrap();
+        assert_eq!(json["page"], 3);
+        assert_eq!(json["sorters"][0]["order"], "Descend");
+        assert_eq!(serde_json::from_value::<TableQuery>(json).unwrap(), query);
+    }
+
+    fn export_columns() -> Vec<TableColumn<User>> {
+        vec![
+            TableColumn::new("id", "Id").value(|user: &User| user.id.to_string()),
+            TableColumn::new("name", "Name, \"full\"").value(|user: &User| user.name.clone()),
+            TableColumn::new("action", "Action").render(|_: &User, _| rsx! { "edit" }),
+        ]
+    }
+
+    #[test]
+    fn test_to_csv() {
+        let mut rows = users();
+        rows[1].name = "Jim \"Green\"\nLondon".to_string();
+        let csv = to_csv(&export_columns(), &rows, &CsvOptions::default());
+        assert_eq!(
+            csv,
+            "Id,\"Name, \"\"full\"\"\"\r\n1,John\r\n2,\"Jim \"\"Green\"\"\nLondon\"\r\n"
+        );
+
+        let options = CsvOptions {
+            bom: true,
+            delimiter: ';',
+            include_header: false,
+        };
+        assert_eq!(
+            to_csv(&export_columns(), &users(), &options),
+            "\u{feff}1;John\r\n2;Jim\r\n"
+        );
+        assert_eq!(escape_csv_field("a;b", ';'), "\"a;b\"");
+        assert_eq!(escape_csv_field("a;b", ','), "a;b");
+    }
+
+    #[test]
+    fn test_to_xlsx() {
+        let mut rows = users();
+        rows[0].name = "<John & Co>".to_string();
+        let bytes = to_xlsx(&export_columns(), &rows, "Users");
+        // ZIP 本地文件头与中央目录结束记录
+        assert_eq!(&bytes[..4], b"PK\x03\x04");
+        assert_eq!(&bytes[bytes.len() - 22..bytes.len() - 18], b"PK\x05\x06");
+
+        let content = String::from_utf8_lossy(&bytes);
+        assert!(content.contains("[Content_Types].xml"));
+        assert!(content.contains("<sheet name=\"Users\""));
+        assert!(content.contains("<c r=\"A2\"><v>1</v></c>"));
+        assert!(content.contains("&lt;John &amp; Co&gt;"));
+        assert!(content.contains("Name, &quot;full&quot;"));
+        assert!(!content.contains("Action"));
+    }
+
+    #[test]
+    fn test_source_data_sync() {
+        let rows = users();
+        let mut data = SourceData::new(&rows);
+        assert_eq!(data.sync(&rows), 0);
+        // 内容相同的新数据不视为变化
+        let copy = users();
+        assert_eq!(data.sync(&copy), 0);
+        let mut changed = users();
+        changed[0].name = "Tom".to_string();
+        assert_eq!(data.sync(&changed), 1);
+        assert_eq!(data.rows[0].name, "Tom");
+        assert_eq!(data.sync(&changed), 1);
+    }
+
+    #[test]
+    fn test_table_rows_reused_between_renders() {
+        use std::cell::{Cell, RefCell};
+
+        thread_local! {
+            static COMPARES: Cell<usize> = const { Cell::new(0) };
+            static RENDERS: Cell<usize> = const { Cell::new(0) };
+            static EXPORTER: RefCell<Option<TableExporter<User>>> = const { RefCell::new(None) };
+        }
+
+        #[component]
+        fn App() -> Element {
+            let columns = use_hook(|| {
+                vec![TableColumn::new("name", "Name")
+                    .value(|user: &User| user.name.clone())
+                    .sorter(|a: &User, b: &User| {
+                        COMPARES.with(|compares| compares.set(compares.get() + 1));
+                        a.name.cmp(&b.name)
+                    })
+                    .default_sort_order(SortOrder::Ascend)]
+            });
+            let data = use_hook(users);
+            let exporter = use_table_exporter::<User>();
+            EXPORTER.with(|cell| *cell.borrow_mut() = Some(exporter.clone()));
+            // 每次渲染传入不同的类名，表格重新渲染但数据与列不变
+            let renders = RENDERS.with(|renders| {
+                renders.set(renders.get() + 1);
+                renders.get()
+            });
+            rsx! {
+                Table::<User> {
+                    columns,
+                    data_source: data,
+                    exporter,
+                    class: "render-{renders}",
+                }
+            }
+        }
+
+        let mut dom = VirtualDom::new(App);
+        dom.rebuild_in_place();
+        let compares = COMPARES.with(Cell::get);
+        assert!(compares > 0);
+        let exporter = EXPORTER.with(|cell| cell.borrow().clone()).unwrap();
+        assert_eq!(
+            exporter.to_csv(&CsvOptions::default()),
+            "Name\r\nJim\r\nJohn\r\n"
+        );
+
+        dom.mark_dirty(ScopeId::APP);
+        dom.render_immediate(&mut dioxus_core::NoOpMutations);
+        assert_eq!(RENDERS.with(Cell::get), 2);
+        assert_eq!(COMPARES.with(Cell::get), compares);
+    }
+
+    #[test]
+    fn test_table_exporter() {
+        let exporter = TableExporter::<User>::default();
+        assert_eq!(exporter.to_csv(&CsvOptions::default()), "");
+        assert!(exporter == exporter.clone());
+        assert!(exporter != TableExporter::default());
+
+        let mut rows = users();
+        rows.reverse();
+        let columns = ColumnState {
+            hidden: strings(&["id"]),
+            ..Default::default()
         }
+        .apply(&export_columns());
+        exporter.update(&columns, &rows);
+        assert_eq!(
+            exporter.to_csv(&CsvOptions::default()),
+            "\"Name, \"\"full\"\"\"\r\nJim\r\nJohn\r\n"
+        );
     }
 }
@@ -0,0 +1,157 @@
+//! Table 远程数据
+//!
+//! 服务端分页、排序与筛选：表格状态变化时调用异步加载函数获取当前页的数据。
+
+use super::{SorterState, TableFilters};
+use dioxus::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+
+/// 筛选变化后默认的请求防抖时间（毫秒）
+pub const DEFAULT_FILTER_DEBOUNCE: u32 = 300;
+
+/// 远程加载的查询参数
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TableQuery {
+    /// 当前页码，从 1 开始
+    pub page: usize,
+    /// 每页条数
+    pub page_size: usize,
+    /// 排序状态
+    pub sorters: Vec<SorterState>,
+    /// 各列的筛选值
+    pub filters: TableFilters,
+}
+
+/// 远程加载的结果
+#[derive(Debug, Clone, PartialEq)]
+pub struct TableResult<T> {
+    /// 当前页的数据
+    pub rows: Vec<T>,
+    /// 数据总条数
+    pub total: usize,
+}
+
+/// 远程加载返回的 Future
+pub type TableLoadFuture<T> = Pin<Box<dyn Future<Output = Result<TableResult<T>, String>>>>;
+
+/// 远程加载函数
+pub type TableLoadFn<T> = Rc<dyn Fn(TableQuery) -> TableLoadFuture<T>>;
+
+/// 远程数据配置
+pub struct TableRemote<T> {
+    /// 异步加载函数
+    pub load: TableLoadFn<T>,
+    /// 筛选变化后的请求防抖时间（毫秒）
+    pub filter_debounce: u32,
+    /// 加载失败时的回调
+    pub on_error: Option<EventHandler<String>>,
+}
+
+impl<T: 'static> TableRemote<T> {
+    /// 通过异步加载函数创建远程数据配置
+    pub fn new<F, Fut>(load: F) -> Self
+    where
+        F: Fn(TableQuery) -> Fut + 'static,
+        Fut: Future<Output = Result<TableResult<T>, String>> + 'static,
+    {
+        Self {
+            load: Rc::new(move |query| Box::pin(load(query))),
+            filter_debounce: DEFAULT_FILTER_DEBOUNCE,
+            on_error: None,
+        }
+    }
+
+    /// 设置筛选变化后的请求防抖时间
+    pub fn filter_debounce(mut self, debounce: u32) -> Self {
+        self.filter_debounce = debounce;
+        self
+    }
+
+    /// 设置加载失败时的回调
+    pub fn on_error(mut self, on_error: EventHandler<String>) -> Self {
+        self.on_error = Some(on_error);
+        self
+    }
+}
+
+impl<T> Clone for TableRemote<T> {
+    fn clone(&self) -> Self {
+        Self {
+            load: self.load.clone(),
+            filter_debounce: self.filter_debounce,
+            on_error: self.on_error,
+        }
+    }
+}
+
+impl<T: 'static> PartialEq for TableRemote<T> {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.load, &other.load)
+            && self.filter_debounce == other.filter_debounce
+            && self.on_error == other.on_error
+    }
+}
+
+/// 远程加载的状态
+pub(crate) struct RemoteState<T: 'static> {
+    /// 当前页的数据
+    pub rows: Signal<Vec<T>>,
+    /// 数据总条数
+    pub total: Signal<usize>,
+    /// 是否正在加载
+    pub loading: Signal<bool>,
+    /// 进行中的请求，发起新请求时取消
+    pub task: Signal<Option<Task>>,
+    /// 最近一次请求的序号，用于丢弃过期的响应
+    pub request_id: Signal<u64>,
+}
+
+impl<T: 'static> Clone for RemoteState<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: 'static> Copy for RemoteState<T> {}
+
+impl<T: Clone + 'static> RemoteState<T> {
+    /// 发起请求：取消进行中的请求，等待 `delay` 毫秒后调用加载函数
+    pub fn request(mut self, remote: &TableRemote<T>, query: TableQuery, delay: u32) {
+        if let Some(task) = self.task.take() {
+            task.cancel();
+        }
+        let request_id = *self.request_id.peek() + 1;
+        self.request_id.set(request_id);
+
+        let load = remote.load.clone();
+        let on_error = remote.on_error;
+        let task = spawn(async move {
+            if delay > 0 {
+                gloo_timers::future::TimeoutFuture::new(delay).await;
+            }
+            self.loading.set(true);
+            let result = load(query).await;
+            // 只接受最近一次请求的响应
+            if *self.request_id.peek() != request_id {
+                return;
+            }
+            match result {
+                Ok(result) => {
+                    self.rows.set(result.rows);
+                    self.total.set(result.total);
+                }
+                Err(error) => {
+                    if let Some(on_error) = on_error {
+                        on_error.call(error);
+                    }
+                }
+            }
+            self.loading.set(false);
+            self.task.set(None);
+        });
+        self.task.set(Some(task));
+    }
+}
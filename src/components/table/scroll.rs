@@ -0,0 +1,212 @@
+//! Table 滚动
+//!
+//! 虚拟滚动的可视区间计算以及固定列的定位。
+
+use super::{TableColumn, TableFixed};
+
+/// 虚拟滚动默认的额外渲染行数
+pub const DEFAULT_OVERSCAN: usize = 5;
+
+/// 虚拟滚动默认的可视区域高度
+pub const DEFAULT_SCROLL_HEIGHT: u32 = 400;
+
+/// 选择列的默认宽度
+pub(crate) const SELECTION_COLUMN_WIDTH: f64 = 48.0;
+
+/// 展开列的宽度
+pub(crate) const EXPAND_COLUMN_WIDTH: f64 = 48.0;
+
+/// 虚拟滚动时实际渲染的行区间
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct VirtualRange {
+    /// 起始行（包含）
+    pub start: usize,
+    /// 结束行（不包含）
+    pub end: usize,
+    /// 起始行之前的占位高度
+    pub offset_top: f64,
+    /// 结束行之后的占位高度
+    pub offset_bottom: f64,
+}
+
+/// 根据滚动位置计算需要渲染的行区间
+///
+/// 可视区域上下各额外渲染 `overscan` 行，避免快速滚动时出现空白。
+pub fn virtual_range(
+    total: usize,
+    scroll_top: f64,
+    viewport_height: f64,
+    row_height: f64,
+    overscan: usize,
+) -> VirtualRange {
+    if total == 0 || row_height <= 0.0 {
+        return VirtualRange {
+            start: 0,
+            end: total,
+            offset_top: 0.0,
+            offset_bottom: 0.0,
+        };
+    }
+
+    let first_visible = (scroll_top.max(0.0) / row_height).floor() as usize;
+    let visible_count = (viewport_height / row_height).ceil() as usize + 1;
+
+    let start = first_visible.saturating_sub(overscan).min(total);
+    let end = (first_visible + visible_count + overscan).min(total);
+
+    VirtualRange {
+        start,
+        end,
+        offset_top: start as f64 * row_height,
+        offset_bottom: (total - end) as f64 * row_height,
+    }
+}
+
+/// 不同尺寸表格的默认行高
+pub(crate) fn default_row_height(size: &super::TableSize) -> f64 {
+    match size {
+        super::TableSize::Default => 55.0,
+        super::TableSize::Middle => 47.0,
+        super::TableSize::Small => 39.0,
+    }
+}
+
+/// 解析像素宽度，如 `"150px"` 或 `"150"`，其他单位返回 None
+pub fn parse_px(width: &str) -> Option<f64> {
+    let width = width.trim();
+    width
+        .strip_suffix("px")
+        .unwrap_or(width)
+        .trim()
+        .parse::<f64>()
+        .ok()
+}
+
+/// 固定列单元格的定位信息
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct FixedCell {
+    /// 固定方向
+    pub side: Option<TableFixed>,
+    /// 距离固定边的偏移量
+    pub offset: f64,
+    /// 是否为该侧最靠内的固定列，用于绘制阴影
+    pub is_edge: bool,
+}
+
+impl FixedCell {
+    /// 固定列单元格的CSS类名
+    pub fn class(&self) -> String {
+        match &self.side {
+            Some(TableFixed::Left) if self.is_edge => {
+                " ant-table-cell-fix-left ant-table-cell-fix-left-last".to_string()
+            }
+            Some(TableFixed::Left) => " ant-table-cell-fix-left".to_string(),
+            Some(TableFixed::Right) if self.is_edge => {
+                " ant-table-cell-fix-right ant-table-cell-fix-right-first".to_string()
+            }
+            Some(TableFixed::Right) => " ant-table-cell-fix-right".to_string(),
+            None => String::new(),
+        }
+    }
+
+    /// 固定列单元格的内联样式
+    pub fn style(&self) -> String {
+        match &self.side {
+            Some(TableFixed::Left) => format!("position: sticky; left: {}px;", self.offset),
+            Some(TableFixed::Right) => format!("position: sticky; right: {}px;", self.offset),
+            None => String::new(),
+        }
+    }
+}
+
+/// 将左侧固定列排在最前、右侧固定列排在最后，其余列保持原有顺序
+pub fn arrange_fixed_columns<T>(columns: &[TableColumn<T>]) -> Vec<TableColumn<T>> {
+    let side = |column: &TableColumn<T>| match column.fixed {
+        Some(TableFixed::Left) => 0,
+        None => 1,
+        Some(TableFixed::Right) => 2,
+    };
+    let mut arranged = columns.to_vec();
+    arranged.sort_by_key(|column| side(column));
+    arranged
+}
+
+/// 计算选择列与各数据列的固定定位
+///
+/// 偏移量由前面（或后面）同侧固定列的像素宽度累加得到，因此固定列需要设置像素宽度。
+/// `selection_width` 为固定在左侧的选择列宽度，未固定时传入 None。
+pub fn fixed_cells<T>(
+    columns: &[TableColumn<T>],
+    selection_width: Option<f64>,
+) -> (FixedCell, Vec<FixedCell>) {
+    let width_of = |column: &TableColumn<T>| {
+        column
+            .width
+            .as_deref()
+            .and_then(parse_px)
+            .unwrap_or_default()
+    };
+
+    let mut cells = vec![FixedCell::default(); columns.len()];
+    let has_left_columns = columns
+        .iter()
+        .any(|column| column.fixed == Some(TableFixed::Left));
+
+    let selection = match selection_width {
+        Some(_) => FixedCell {
+            side: Some(TableFixed::Left),
+            offset: 0.0,
+            is_edge: !has_left_columns,
+        },
+        None => FixedCell::default(),
+    };
+
+    let mut left = selection_width.unwrap_or_default();
+    let last_left = columns
+        .iter()
+        .rposition(|column| column.fixed == Some(TableFixed::Left));
+    for (index, column) in columns.iter().enumerate() {
+        if column.fixed == Some(TableFixed::Left) {
+            cells[index] = FixedCell {
+                side: Some(TableFixed::Left),
+                offset: left,
+                is_edge: Some(index) == last_left,
+            };
+            left += width_of(column);
+        }
+    }
+
+    let mut right = 0.0;
+    let first_right = columns
+        .iter()
+        .position(|column| column.fixed == Some(TableFixed::Right));
+    for (index, column) in columns.iter().enumerate().rev() {
+        if column.fixed == Some(TableFixed::Right) {
+            cells[index] = FixedCell {
+                side: Some(TableFixed::Right),
+                offset: right,
+                is_edge: Some(index) == first_right,
+            };
+            right += width_of(column);
+        }
+    }
+
+    (selection, cells)
+}
+
+/// 分组表头单元格的固定定位：覆盖的叶子列均固定在同一侧时随之固定
+pub(crate) fn group_fixed_cell(fixed: &[FixedCell], first: usize, col_span: usize) -> FixedCell {
+    let cells = &fixed[first..(first + col_span).min(fixed.len())];
+    let side = cells.first().and_then(|cell| cell.side.clone());
+    match side {
+        Some(side) if cells.iter().all(|cell| cell.side.as_ref() == Some(&side)) => FixedCell {
+            offset: match side {
+                TableFixed::Left => cells[0].offset,
+                TableFixed::Right => cells[cells.len() - 1].offset,
+            },
+            is_edge: cells.iter().any(|cell| cell.is_edge),
+            side: Some(side),
+        },
+        _ => FixedCell::default(),
+    }
+}
@@ -0,0 +1,194 @@
+//! Table 排序
+//!
+//! 客户端排序逻辑：排序方向、多列排序优先级以及行数据的重排。
+
+use super::TableColumn;
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::rc::Rc;
+
+/// 列比较函数
+pub type SorterFn<T> = Rc<dyn Fn(&T, &T) -> Ordering>;
+
+/// 排序方向
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SortOrder {
+    /// 升序
+    Ascend,
+    /// 降序
+    Descend,
+}
+
+impl SortOrder {
+    /// 获取排序方向对应的字符串
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SortOrder::Ascend => "ascend",
+            SortOrder::Descend => "descend",
+        }
+    }
+}
+
+/// 点击表头后的下一个排序方向：升序 → 降序 → 取消排序
+pub fn next_sort_order(current: Option<SortOrder>) -> Option<SortOrder> {
+    match current {
+        None => Some(SortOrder::Ascend),
+        Some(SortOrder::Ascend) => Some(SortOrder::Descend),
+        Some(SortOrder::Descend) => None,
+    }
+}
+
+/// 单列排序状态
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SorterState {
+    /// 排序列的 key
+    pub column_key: String,
+    /// 排序方向
+    pub order: SortOrder,
+}
+
+impl SorterState {
+    pub fn new(column_key: &str, order: SortOrder) -> Self {
+        Self {
+            column_key: column_key.to_string(),
+            order,
+        }
+    }
+}
+
+/// 获取列当前的排序方向
+pub fn column_sort_order(sorters: &[SorterState], column_key: &str) -> Option<SortOrder> {
+    sorters
+        .iter()
+        .find(|state| state.column_key == column_key)
+        .map(|state| state.order)
+}
+
+/// 根据列配置收集默认排序状态
+pub fn default_sorters<T>(columns: &[TableColumn<T>]) -> Vec<SorterState> {
+    columns
+        .iter()
+        .filter_map(|column| {
+            column
+                .default_sort_order
+                .map(|order| SorterState::new(&column.key, order))
+        })
+        .collect()
+}
+
+/// 切换某一列的排序状态
+///
+/// 设置了 `sort_multiple` 的列可以与其他多列排序的列共存，否则点击后只保留当前列的排序。
+pub fn toggle_sorter<T>(
+    sorters: &[SorterState],
+    columns: &[TableColumn<T>],
+    column_key: &str,
+) -> Vec<SorterState> {
+    let is_multiple = |key: &str| {
+        columns
+            .iter()
+            .any(|column| column.key == key && column.sort_multiple.is_some())
+    };
+
+    let next = next_sort_order(column_sort_order(sorters, column_key));
+    let mut states: Vec<SorterState> = if is_multiple(column_key) {
+        sorters
+            .iter()
+            .filter(|state| state.column_key != column_key && is_multiple(&state.column_key))
+            .cloned()
+            .collect()
+    } else {
+        Vec::new()
+    };
+
+    if let Some(order) = next {
+        states.push(SorterState::new(column_key, order));
+    }
+    states
+}
+
+/// 单元格文本的排序键，文本为数字时同时保存解析后的数值
+struct CellSortKey {
+    text: String,
+    number: Option<f64>,
+}
+
+impl CellSortKey {
+    fn new(text: String) -> Self {
+        let number = text.trim().parse::<f64>().ok();
+        Self { text, number }
+    }
+
+    /// 比较两段单元格文本，均为数字时按数值比较
+    fn compare(&self, other: &Self) -> Ordering {
+        match (self.number, other.number) {
+            (Some(a), Some(b)) => a.partial_cmp(&b).unwrap_or(Ordering::Equal),
+            _ => self.text.cmp(&other.text),
+        }
+    }
+}
+
+/// 按排序状态对行数据进行排序
+///
+/// 多列排序时按 `sort_multiple` 的值从大到小决定优先级，排序是稳定的。
+pub fn sort_rows<T: Clone>(
+    rows: &[T],
+    columns: &[TableColumn<T>],
+    sorters: &[SorterState],
+) -> Vec<T> {
+    let mut active: Vec<(&TableColumn<T>, SortOrder)> = sorters
+        .iter()
+        .filter_map(|state| {
+            columns
+                .iter()
+                .find(|column| column.key == state.column_key && column.is_sortable())
+                .map(|column| (column, state.order))
+        })
+        .collect();
+    active.sort_by_key(|(column, _)| std::cmp::Reverse(column.sort_multiple.unwrap_or(0)));
+
+    if active.is_empty() {
+        return rows.to_vec();
+    }
+
+    // 没有自定义比较函数的列预先计算每行的排序键，避免每次比较时重新生成文本
+    let keys: Vec<Vec<Option<CellSortKey>>> = rows
+        .iter()
+        .map(|row| {
+            active
+                .iter()
+                .map(|(column, _)| {
+                    column
+                        .sorter
+                        .is_none()
+                        .then(|| CellSortKey::new(column.cell_text(row)))
+                })
+                .collect()
+        })
+        .collect();
+
+    let mut indices: Vec<usize> = (0..rows.len()).collect();
+    indices.sort_by(|&a, &b| {
+        for (index, (column, order)) in active.iter().enumerate() {
+            let ordering = match &column.sorter {
+                Some(sorter) => sorter(&rows[a], &rows[b]),
+                None => match (&keys[a][index], &keys[b][index]) {
+                    (Some(key_a), Some(key_b)) => key_a.compare(key_b),
+                    _ => Ordering::Equal,
+                },
+            };
+            let ordering = match order {
+                SortOrder::Ascend => ordering,
+                SortOrder::Descend => ordering.reverse(),
+            };
+            if ordering != Ordering::Equal {
+                return ordering;
+            }
+        }
+        Ordering::Equal
+    });
+    indices
+        .into_iter()
+        .map(|index| rows[index].clone())
+        .collect()
+}
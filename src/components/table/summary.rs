@@ -0,0 +1,82 @@
+//! Table 总结栏
+//!
+//! 表格底部的汇总行，单元格按列的 key 与数据列对齐。
+
+use super::TableColumn;
+use dioxus::prelude::*;
+
+/// 总结栏单元格
+#[derive(Clone, PartialEq)]
+pub struct TableSummaryCell {
+    /// 对齐的列的 key
+    pub column_key: String,
+    /// 向右合并的列数
+    pub col_span: usize,
+    /// 单元格内容
+    pub content: Element,
+}
+
+impl TableSummaryCell {
+    pub fn new(column_key: &str, content: Element) -> Self {
+        Self {
+            column_key: column_key.to_string(),
+            col_span: 1,
+            content,
+        }
+    }
+
+    /// 设置向右合并的列数
+    pub fn col_span(mut self, col_span: usize) -> Self {
+        self.col_span = col_span.max(1);
+        self
+    }
+}
+
+/// 总结栏中的一行
+#[derive(Clone, PartialEq, Default)]
+pub struct TableSummaryRow {
+    /// 行内的单元格，未覆盖的列渲染为空单元格
+    pub cells: Vec<TableSummaryCell>,
+}
+
+impl TableSummaryRow {
+    pub fn new(cells: Vec<TableSummaryCell>) -> Self {
+        Self { cells }
+    }
+}
+
+/// 总结栏单元格在数据列上的布局
+#[derive(Debug, Clone, PartialEq)]
+pub struct SummaryCellLayout {
+    /// 起始列在展示顺序中的位置
+    pub index: usize,
+    /// 合并的列数
+    pub col_span: usize,
+    /// 对应的单元格在 `TableSummaryRow::cells` 中的位置，空单元格为 None
+    pub cell: Option<usize>,
+}
+
+/// 计算总结栏一行在数据列上的布局，合并的列数不会超出表格的列数
+pub fn layout_summary_row<T>(
+    columns: &[TableColumn<T>],
+    row: &TableSummaryRow,
+) -> Vec<SummaryCellLayout> {
+    let mut layout = Vec::new();
+    let mut index = 0;
+    while index < columns.len() {
+        let cell = row
+            .cells
+            .iter()
+            .position(|cell| cell.column_key == columns[index].key);
+        let col_span = cell
+            .map(|cell| row.cells[cell].col_span.min(columns.len() - index))
+            .unwrap_or(1);
+        layout.push(SummaryCellLayout {
+            index,
+            col_span,
+            cell,
+        });
+        index += col_span;
+    }
+    layout
+}
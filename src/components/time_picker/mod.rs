@@ -27,6 +27,7 @@
 //! }
 //! ```
 
+use crate::components::form::{use_form_item_control, value_to_text};
 use dioxus::prelude::*;
 use serde::{Deserialize, Serialize};
 
@@ -270,30 +271,33 @@ pub struct TimePickerProps {
 /// - `id`: 自定义 id
 #[component]
 pub fn TimePicker(props: TimePickerProps) -> Element {
+    let form_item = use_form_item_control();
+
     // 内部状态管理
     let mut internal_value = use_signal(|| props.default_value.clone());
     let mut is_open = use_signal(|| false);
-    let mut input_value = use_signal(|| String::new());
 
-    // 使用受控模式还是非受控模式
+    // 使用受控模式还是非受控模式，位于 FormItem 内时使用字段值（按 `format` 格式化的字符串）
     let is_controlled = props.onchange.is_some();
-    let current_value = if is_controlled {
-        props.value.clone()
-    } else {
-        internal_value()
-    };
+    let current_value =
+        if let Some(value) = form_item.and_then(|item| item.value_for("value", "value")) {
+            TimeValue::from_string(&value_to_text(&value), &props.format)
+        } else if is_controlled {
+            props.value.clone()
+        } else {
+            internal_value()
+        };
+    let disabled = props.disabled || form_item.is_some_and(|item| item.is_disabled());
 
-    // 初始化输入框显示值
-    let format_clone = props.format.clone();
-    let current_value_clone = current_value.clone().unwrap();
-    use_effect(move || {
-        let value = current_value_clone.to_string(&format_clone);
-        input_value.set(value);
-    });
+    // 输入框显示值
+    let input_value = current_value
+        .as_ref()
+        .map(|value| value.to_string(&props.format))
+        .unwrap_or_default();
 
     // 处理输入框点击事件
     let handle_input_click = move |_: MouseEvent| {
-        if !props.disabled {
+        if !disabled {
             is_open.set(!is_open());
             if let Some(on_open_change) = &props.on_open_change {
                 on_open_change.call(!is_open());
@@ -302,11 +306,17 @@ pub fn TimePicker(props: TimePickerProps) -> Element {
     };
 
     // 处理时间选择
-    let mut handle_time_select = move |new_value: Option<TimeValue>| {
+    let format = props.format.clone();
+    let handle_time_select = Callback::new(move |new_value: Option<TimeValue>| {
         if !is_controlled {
             internal_value.set(new_value.clone());
         }
 
+        if let Some(item) = form_item {
+            let text = new_value.as_ref().map(|value| value.to_string(&format));
+            item.trigger("on_change", text);
+        }
+
         if let Some(onchange) = &props.onchange {
             onchange.call(new_value);
         }
@@ -315,18 +325,18 @@ pub fn TimePicker(props: TimePickerProps) -> Element {
         if let Some(on_open_change) = &props.on_open_change {
             on_open_change.call(false);
         }
-    };
+    });
 
     // 处理清除按钮
     let handle_clear = move |evt: MouseEvent| {
         evt.stop_propagation();
-        handle_time_select(None);
+        handle_time_select.call(None);
     };
 
     // 构建类名
     let mut class_names = vec!["ant-picker", "ant-picker-time"];
 
-    if props.disabled {
+    if disabled {
         class_names.push("ant-picker-disabled");
     }
 
@@ -364,8 +374,8 @@ pub fn TimePicker(props: TimePickerProps) -> Element {
                     input {
                         r#type: "text",
                         placeholder: "{placeholder}",
-                        value: "{input_value()}",
-                        disabled: props.disabled,
+                        value: "{input_value}",
+                        disabled: disabled,
                         autofocus: props.auto_focus,
                         readonly: true,
                     }
@@ -373,7 +383,7 @@ pub fn TimePicker(props: TimePickerProps) -> Element {
 
                 div {
                     class: "ant-picker-suffix",
-                    if props.allow_clear && current_value.is_some() && !props.disabled {
+                    if props.allow_clear && current_value.is_some() && !disabled {
                         span {
                             class: "ant-picker-clear",
                             onclick: handle_clear,
@@ -387,7 +397,7 @@ pub fn TimePicker(props: TimePickerProps) -> Element {
                 }
             }
 
-            if is_open() && !props.disabled {
+            if is_open() && !disabled {
                 TimePickerPanel {
                     value: current_value.clone(),
                     format: props.format.clone(),
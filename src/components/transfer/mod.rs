@@ -35,6 +35,7 @@
 //! }
 //! ```
 
+use crate::utils::{fuzzy_match, highlight_segments};
 use dioxus::prelude::*;
 use serde_json::Value;
 use std::collections::HashSet;
@@ -286,21 +287,16 @@ pub fn Transfer(props: TransferProps) -> Element {
             return data.to_vec();
         }
 
-        data.iter()
-            .filter(|item| {
-                if let Some(filter_fn) = &props.filter_option {
+        if let Some(filter_fn) = &props.filter_option {
+            return data
+                .iter()
+                .filter(|item| {
                     filter_fn.call((search_value.to_string(), (*item).clone(), direction))
-                } else {
-                    item.title
-                        .to_lowercase()
-                        .contains(&search_value.to_lowercase())
-                        || item.description.as_ref().map_or(false, |desc| {
-                            desc.to_lowercase().contains(&search_value.to_lowercase())
-                        })
-                }
-            })
-            .cloned()
-            .collect()
+                })
+                .cloned()
+                .collect();
+        }
+        filter_items(data, search_value)
     };
 
     let filtered_left_data = filter_data(
@@ -697,7 +693,7 @@ fn TransferList(props: TransferListProps) -> Element {
                         r#type: "text",
                         class: "ant-input ant-transfer-list-search-input",
                         placeholder: props.search_placeholder,
-                        value: props.search_value,
+                        value: props.search_value.clone(),
                         disabled: props.disabled,
                         oninput: handle_search_input,
                     }
@@ -725,6 +721,7 @@ fn TransferList(props: TransferListProps) -> Element {
                                 item: item.clone(),
                                 selected: selected_keys_set.contains(&item.key),
                                 disabled: props.disabled || item.disabled,
+                                search: props.search_value.clone(),
                                 on_select: handle_item_select,
                             }
                         }
@@ -751,6 +748,8 @@ struct TransferListItemProps {
     item: TransferItem,
     selected: bool,
     disabled: bool,
+    /// 搜索内容，用于高亮标题中匹配的字符
+    search: String,
     on_select: Callback<(String, bool)>,
 }
 
@@ -777,6 +776,10 @@ fn TransferListItem(props: TransferListItemProps) -> Element {
         }
     };
 
+    let title_ranges = fuzzy_match(&props.item.title, &props.search)
+        .map(|result| result.ranges)
+        .unwrap_or_default();
+
     let item_class = format!(
         "ant-transfer-list-content-item{}{}",
         if props.selected {
@@ -809,7 +812,15 @@ fn TransferListItem(props: TransferListItemProps) -> Element {
 
             // 内容
             div { class: "ant-transfer-list-content-item-text",
-                div { class: "ant-transfer-list-content-item-title", {props.item.title} }
+                div { class: "ant-transfer-list-content-item-title",
+                    for (segment, matched) in highlight_segments(&props.item.title, &title_ranges) {
+                        if matched {
+                            span { class: "ant-transfer-list-content-item-highlight", "{segment}" }
+                        } else {
+                            "{segment}"
+                        }
+                    }
+                }
                 if let Some(description) = &props.item.description {
                     div { class: "ant-transfer-list-content-item-description", "{description}" }
                 }
@@ -818,6 +829,23 @@ fn TransferListItem(props: TransferListItemProps) -> Element {
     }
 }
 
+/// 按搜索内容模糊筛选数据项，匹配标题、描述及汉字拼音首字母，并按匹配得分排序
+fn filter_items(data: &[TransferItem], search: &str) -> Vec<TransferItem> {
+    let mut matched: Vec<(i32, &TransferItem)> = data
+        .iter()
+        .filter_map(|item| {
+            let score = std::iter::once(&item.title)
+                .chain(item.description.as_ref())
+                .filter_map(|text| fuzzy_match(text, search))
+                .map(|result| result.score)
+                .max()?;
+            Some((score, item))
+        })
+        .collect();
+    matched.sort_by_key(|(score, _)| std::cmp::Reverse(*score));
+    matched.into_iter().map(|(_, item)| item.clone()).collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -838,6 +866,25 @@ mod tests {
         assert_eq!(item.description, Some("这是一个测试项目".to_string()));
     }
 
+    #[test]
+    fn test_filter_items() {
+        let data = vec![
+            TransferItem::new("1", "Content 1").with_description("北京"),
+            TransferItem::new("2", "Beijing"),
+            TransferItem::new("3", "Shanghai"),
+        ];
+        let keys = |search: &str| {
+            filter_items(&data, search)
+                .into_iter()
+                .map(|item| item.key)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(keys(""), vec!["1", "2", "3"]);
+        assert_eq!(keys("bj"), vec!["1", "2"]);
+        assert_eq!(keys("shai"), vec!["3"]);
+        assert!(keys("xyz").is_empty());
+    }
+
     #[test]
     fn test_transfer_item_with_disabled() {
         let item = TransferItem::new("1", "测试项目").with_disabled(true);
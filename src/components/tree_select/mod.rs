@@ -1,4 +1,6 @@
+use crate::components::form::{use_form_item_control, value_to_text, ValidateStatus};
 use dioxus::prelude::*;
+use serde_json::Value;
 
 const TREE_SELECT_STYLE: &str = include_str!("./style.css");
 
@@ -250,6 +252,32 @@ pub enum TreeSelectValue {
     MultipleLabelInValue(Vec<TreeSelectLabelValue>),
 }
 
+impl TreeSelectValue {
+    /// Value stored in a form field: a string, or an array of strings for multiple selection
+    fn to_form_value(&self) -> Value {
+        match self {
+            TreeSelectValue::Single(value) => Value::from(value.as_str()),
+            TreeSelectValue::Multiple(values) => Value::from(values.clone()),
+            TreeSelectValue::LabelInValue(item) => Value::from(item.value.as_str()),
+            TreeSelectValue::MultipleLabelInValue(items) => {
+                Value::from_iter(items.iter().map(|item| item.value.as_str()))
+            }
+        }
+    }
+
+    /// Build from a form field value, `None` when the field is empty
+    fn from_form_value(value: &Value, multiple: bool) -> Option<Self> {
+        match value {
+            Value::Null => None,
+            Value::Array(items) => Some(TreeSelectValue::Multiple(
+                items.iter().map(value_to_text).collect(),
+            )),
+            value if multiple => Some(TreeSelectValue::Multiple(vec![value_to_text(value)])),
+            value => Some(TreeSelectValue::Single(value_to_text(value))),
+        }
+    }
+}
+
 /// Label in value structure
 #[derive(Clone, Debug, PartialEq)]
 pub struct TreeSelectLabelValue {
@@ -471,11 +499,39 @@ pub fn TreeSelect(props: TreeSelectProps) -> Element {
     });
     let mut filtered_tree_data = use_signal(|| props.tree_data.clone());
 
+    // Bind to the surrounding FormItem: field value, disabled and validation status
+    let form_item = use_form_item_control();
+    let multiple = props.multiple;
+    use_effect(move || {
+        if let Some(value) = form_item.and_then(|item| item.value_for("value", "value")) {
+            current_value.set(TreeSelectValue::from_form_value(&value, multiple));
+        }
+    });
+    let disabled = props.disabled || form_item.is_some_and(|item| item.is_disabled());
+    let status = props
+        .status
+        .clone()
+        .or_else(|| match form_item.and_then(|item| item.status()) {
+            Some(ValidateStatus::Error) => Some(TreeSelectStatus::Error),
+            Some(ValidateStatus::Warning) => Some(TreeSelectStatus::Warning),
+            _ => None,
+        });
+    let trigger_form = move |value: Option<&TreeSelectValue>| {
+        if let Some(item) = form_item {
+            item.trigger(
+                "on_change",
+                value
+                    .map(TreeSelectValue::to_form_value)
+                    .unwrap_or(Value::Null),
+            );
+        }
+    };
+
     let class_name = format!(
         "ant-tree-select ant-tree-select-{} ant-tree-select-{} {} {} {} {}",
         props.size.as_str(),
         props.variant.as_str(),
-        if props.disabled {
+        if disabled {
             "ant-tree-select-disabled"
         } else {
             ""
@@ -485,7 +541,7 @@ pub fn TreeSelect(props: TreeSelectProps) -> Element {
         } else {
             "ant-tree-select-single"
         },
-        if let Some(status) = &props.status {
+        if let Some(status) = &status {
             format!("ant-tree-select-status-{}", status.as_str())
         } else {
             String::new()
@@ -496,7 +552,7 @@ pub fn TreeSelect(props: TreeSelectProps) -> Element {
     .to_string();
 
     let handle_selector_click = move |_evt: MouseEvent| {
-        if !props.disabled {
+        if !disabled {
             let new_open = !*is_open.read();
             is_open.set(new_open);
             if let Some(on_dropdown_visible_change) = &props.on_dropdown_visible_change {
@@ -542,6 +598,7 @@ pub fn TreeSelect(props: TreeSelectProps) -> Element {
 
             let new_value = TreeSelectValue::Multiple(new_values);
             current_value.set(Some(new_value.clone()));
+            trigger_form(Some(&new_value));
 
             if let Some(on_change) = &props.on_change {
                 on_change.call(new_value);
@@ -550,6 +607,7 @@ pub fn TreeSelect(props: TreeSelectProps) -> Element {
             // Handle single selection
             let new_value = TreeSelectValue::Single(node_value.clone());
             current_value.set(Some(new_value.clone()));
+            trigger_form(Some(&new_value));
             is_open.set(false);
 
             if let Some(on_change) = &props.on_change {
@@ -569,6 +627,7 @@ pub fn TreeSelect(props: TreeSelectProps) -> Element {
     let handle_clear = move |evt: MouseEvent| {
         evt.stop_propagation();
         current_value.set(None);
+        trigger_form(None);
         if let Some(on_change) = &props.on_change {
             if props.multiple {
                 on_change.call(TreeSelectValue::Multiple(Vec::new()));
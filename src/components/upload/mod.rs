@@ -33,6 +33,7 @@
 //! }
 //! ```
 
+use crate::components::form::use_form_item_control;
 use dioxus::events::FormEvent;
 use dioxus::prelude::*;
 use serde::{Deserialize, Serialize};
@@ -274,10 +275,27 @@ pub fn Upload(props: UploadProps) -> Element {
     let mut internal_file_list = use_signal(|| props.default_file_list.clone());
     let mut drag_over = use_signal(|| false);
 
-    // 使用受控模式还是非受控模式
+    // 所在表单项的绑定，字段值为文件列表
+    let form_item = use_form_item_control();
+    let disabled = props.disabled || form_item.is_some_and(|item| item.is_disabled());
+    let form_file_list = form_item
+        .and_then(|item| item.value_for("file_list", "file_list"))
+        .map(|value| serde_json::from_value::<Vec<UploadFile>>(value).unwrap_or_default());
+    let trigger_form = move |file_list: &Vec<UploadFile>| {
+        if let Some(item) = form_item {
+            item.trigger(
+                "on_change",
+                serde_json::to_value(file_list).unwrap_or_default(),
+            );
+        }
+    };
+
+    // 使用受控模式还是非受控模式，绑定表单时以字段值为准
     let is_controlled = props.file_list.is_some();
     let current_file_list = if is_controlled {
         props.file_list.clone().unwrap_or_default()
+    } else if let Some(file_list) = form_file_list {
+        file_list
     } else {
         internal_file_list()
     };
@@ -335,6 +353,7 @@ pub fn Upload(props: UploadProps) -> Element {
                 if !is_controlled {
                     internal_file_list.set(updated_list.clone());
                 }
+                trigger_form(&updated_list);
 
                 // 触发变更回调
                 if let Some(onchange) = &props.onchange {
@@ -360,6 +379,7 @@ pub fn Upload(props: UploadProps) -> Element {
             if !is_controlled {
                 internal_file_list.set(updated_list.clone());
             }
+            trigger_form(&updated_list);
 
             if let Some(on_remove) = &props.on_remove {
                 on_remove.call(file.clone());
@@ -388,7 +408,7 @@ pub fn Upload(props: UploadProps) -> Element {
     // 处理拖拽事件
     let handle_drag_over = move |evt: DragEvent| {
         evt.prevent_default();
-        if props.drag && !props.disabled {
+        if props.drag && !disabled {
             drag_over.set(true);
         }
     };
@@ -401,7 +421,7 @@ pub fn Upload(props: UploadProps) -> Element {
 
     let handle_drop = move |evt: DragEvent| {
         evt.prevent_default();
-        if props.drag && !props.disabled {
+        if props.drag && !disabled {
             drag_over.set(false);
             // 处理拖拽文件
             // 注意：在实际实现中需要处理 DataTransfer 中的文件
@@ -411,7 +431,7 @@ pub fn Upload(props: UploadProps) -> Element {
     // 构建类名
     let mut class_names = vec!["ant-upload-wrapper"];
 
-    if props.disabled {
+    if disabled {
         class_names.push("ant-upload-disabled");
     }
 
@@ -446,7 +466,7 @@ pub fn Upload(props: UploadProps) -> Element {
                     class: "ant-upload-input",
                     accept: props.accept.as_deref().unwrap_or(""),
                     multiple: props.multiple,
-                    disabled: props.disabled,
+                    disabled: disabled,
                     onchange: handle_file_select,
                     style: "display: none;",
                 }
@@ -454,7 +474,7 @@ pub fn Upload(props: UploadProps) -> Element {
                 div {
                     class: "ant-upload-btn",
                     onclick: move |_| {
-                        if !props.disabled {
+                        if !disabled {
                             // 触发文件选择
                             let _ = eval("document.querySelector('.ant-upload-input').click()");
                         }
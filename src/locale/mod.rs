@@ -730,6 +730,76 @@ fn get_default_messages(locale: &Locale) -> LanguagePack {
             messages.insert("min_length", "至少输入 {min} 个字符".to_string());
             messages.insert("max_length", "最多输入 {max} 个字符".to_string());
             messages.insert("pattern_mismatch", "输入格式不正确".to_string());
+            messages.insert("form_validate_required", "请输入${label}".to_string());
+            messages.insert(
+                "form_validate_whitespace",
+                "${label}不能为空字符".to_string(),
+            );
+            messages.insert(
+                "form_validate_type",
+                "${label}不是一个有效的${type}".to_string(),
+            );
+            messages.insert(
+                "form_validate_enum",
+                "${label}必须是其中一个[${enum}]".to_string(),
+            );
+            messages.insert(
+                "form_validate_pattern",
+                "${label}不符合格式${pattern}".to_string(),
+            );
+            messages.insert(
+                "form_validate_pattern_invalid",
+                "${label}的校验格式${pattern}无效".to_string(),
+            );
+            messages.insert(
+                "form_validate_string_len",
+                "${label}须为${len}个字符".to_string(),
+            );
+            messages.insert(
+                "form_validate_string_min",
+                "${label}最少${min}个字符".to_string(),
+            );
+            messages.insert(
+                "form_validate_string_max",
+                "${label}最多${max}个字符".to_string(),
+            );
+            messages.insert(
+                "form_validate_string_range",
+                "${label}须在${min}-${max}字符之间".to_string(),
+            );
+            messages.insert(
+                "form_validate_number_len",
+                "${label}必须等于${len}".to_string(),
+            );
+            messages.insert(
+                "form_validate_number_min",
+                "${label}最小值为${min}".to_string(),
+            );
+            messages.insert(
+                "form_validate_number_max",
+                "${label}最大值为${max}".to_string(),
+            );
+            messages.insert(
+                "form_validate_number_range",
+                "${label}须在${min}-${max}之间".to_string(),
+            );
+            messages.insert(
+                "form_validate_array_len",
+                "须为${len}个${label}".to_string(),
+            );
+            messages.insert(
+                "form_validate_array_min",
+                "最少${min}个${label}".to_string(),
+            );
+            messages.insert(
+                "form_validate_array_max",
+                "最多${max}个${label}".to_string(),
+            );
+            messages.insert(
+                "form_validate_array_range",
+                "${label}数量须在${min}-${max}之间".to_string(),
+            );
+            messages.insert("form_list_add", "添加".to_string());
 
             // 日期时间
             messages.insert("today", "今天".to_string());
@@ -756,6 +826,13 @@ fn get_default_messages(locale: &Locale) -> LanguagePack {
             messages.insert("filter_reset", "重置".to_string());
             messages.insert("filter_confirm", "确定".to_string());
             messages.insert("filter_checkall", "全选".to_string());
+            messages.insert("table_action", "操作".to_string());
+            messages.insert("table_filter_search", "在筛选项中搜索".to_string());
+            messages.insert("table_filter_empty", "暂无筛选项".to_string());
+            messages.insert("table_sort_ascend", "点击升序".to_string());
+            messages.insert("table_sort_descend", "点击降序".to_string());
+            messages.insert("table_sort_cancel", "取消排序".to_string());
+            messages.insert("table_column_settings", "列设置".to_string());
 
             // 上传相关
             messages.insert("upload_drag", "点击或拖拽文件到此区域上传".to_string());
@@ -840,6 +917,76 @@ fn get_default_messages(locale: &Locale) -> LanguagePack {
                 "Please enter no more than {max} characters".to_string(),
             );
             messages.insert("pattern_mismatch", "Input format is incorrect".to_string());
+            messages.insert("form_validate_required", "${label} is required".to_string());
+            messages.insert(
+                "form_validate_whitespace",
+                "${label} cannot be empty".to_string(),
+            );
+            messages.insert(
+                "form_validate_type",
+                "${label} is not a valid ${type}".to_string(),
+            );
+            messages.insert(
+                "form_validate_enum",
+                "${label} must be one of [${enum}]".to_string(),
+            );
+            messages.insert(
+                "form_validate_pattern",
+                "${label} does not match pattern ${pattern}".to_string(),
+            );
+            messages.insert(
+                "form_validate_pattern_invalid",
+                "${label} has an invalid pattern ${pattern}".to_string(),
+            );
+            messages.insert(
+                "form_validate_string_len",
+                "${label} must be exactly ${len} characters".to_string(),
+            );
+            messages.insert(
+                "form_validate_string_min",
+                "${label} must be at least ${min} characters".to_string(),
+            );
+            messages.insert(
+                "form_validate_string_max",
+                "${label} cannot be longer than ${max} characters".to_string(),
+            );
+            messages.insert(
+                "form_validate_string_range",
+                "${label} must be between ${min} and ${max} characters".to_string(),
+            );
+            messages.insert(
+                "form_validate_number_len",
+                "${label} must equal ${len}".to_string(),
+            );
+            messages.insert(
+                "form_validate_number_min",
+                "${label} cannot be less than ${min}".to_string(),
+            );
+            messages.insert(
+                "form_validate_number_max",
+                "${label} cannot be greater than ${max}".to_string(),
+            );
+            messages.insert(
+                "form_validate_number_range",
+                "${label} must be between ${min} and ${max}".to_string(),
+            );
+            messages.insert(
+                "form_validate_array_len",
+                "${label} must be exactly ${len} in length".to_string(),
+            );
+            messages.insert(
+                "form_validate_array_min",
+                "${label} cannot be less than ${min} in length".to_string(),
+            );
+            messages.insert(
+                "form_validate_array_max",
+                "${label} cannot be greater than ${max} in length".to_string(),
+            );
+            messages.insert(
+                "form_validate_array_range",
+                "${label} must be between ${min} and ${max} in length".to_string(),
+            );
+            messages.insert("form_list_add", "Add".to_string());
 
             // Date and time
             messages.insert("today", "Today".to_string());
@@ -866,6 +1013,13 @@ fn get_default_messages(locale: &Locale) -> LanguagePack {
             messages.insert("filter_reset", "Reset".to_string());
             messages.insert("filter_confirm", "OK".to_string());
             messages.insert("filter_checkall", "Select All".to_string());
+            messages.insert("table_action", "Action".to_string());
+            messages.insert("table_filter_search", "Search in filters".to_string());
+            messages.insert("table_filter_empty", "No filters".to_string());
+            messages.insert("table_sort_ascend", "Click to sort ascending".to_string());
+            messages.insert("table_sort_descend", "Click to sort descending".to_string());
+            messages.insert("table_sort_cancel", "Click to cancel sorting".to_string());
+            messages.insert("table_column_settings", "Columns".to_string());
 
             // Upload related
             messages.insert(
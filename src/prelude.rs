@@ -40,7 +40,8 @@ pub use crate::locale::{
 
 /// 重新导出工具类型和函数
 pub use crate::utils::{
-    is_chinese_char, is_two_cn_char, to_percent, to_px, DebounceCallback, Size, SpaceSize,
+    fuzzy_match, highlight_segments, is_chinese_char, is_two_cn_char, pinyin_initial,
+    pinyin_initials, to_percent, to_px, DebounceCallback, FuzzyMatch, Size, SpaceSize,
     ThrottleCallback,
 };
 
@@ -145,7 +146,13 @@ pub use crate::components::color_picker::{ColorPicker, ColorPickerProps};
 pub use crate::components::date_picker::{
     DatePicker, DatePickerMode, DatePickerProps, DatePickerSize, DatePickerStatus,
 };
-pub use crate::components::form::{Form, FormItem, FormLayout, FormProps, FormRule, FormSize};
+pub use crate::components::form::{
+    get_value_by_path, parse_schema, set_value_by_path, use_form, use_form_item_control,
+    AsyncValidatorFn, ErrorField, FieldData, FinishFailedInfo, Form, FormContext, FormInstance,
+    FormItem, FormItemContext, FormItemControl, FormLayout, FormList, FormListField,
+    FormListOperation, FormListRenderArgs, FormPersist, FormProps, FormRule, FormSize, FormStorage,
+    PersistStorage, RuleType, SchemaField, SchemaForm, SchemaOption, SchemaWidget,
+};
 pub use crate::components::input::{Input, InputProps, InputSize, InputStatus};
 pub use crate::components::input_number::{
     InputNumber, InputNumberProps, InputNumberSize, InputNumberStatus, StepInfo,
@@ -156,7 +163,9 @@ pub use crate::components::mentions::{
 pub use crate::components::radio::{Radio, RadioGroup, RadioOption, RadioProps, RadioSize};
 pub use crate::components::rate::{Rate, RateProps, RateSize};
 pub use crate::components::select::{
-    OptionData, Select, SelectOptGroup, SelectOption, SelectProps, SelectSize, SelectStatus,
+    LabeledValue, LoadOptions, MaxTagCount, OptionData, Select, SelectFieldNames, SelectMode,
+    SelectOptGroup, SelectOption, SelectOptionData, SelectProps, SelectSize, SelectStatus,
+    SelectTagRenderArgs, SelectValue,
 };
 pub use crate::components::slider::{Slider, SliderMark, SliderProps, SliderSize, SliderStatus};
 pub use crate::components::switch::{Switch, SwitchProps, SwitchSize};
@@ -193,8 +202,13 @@ pub use crate::components::qr_code::{QRCode, QRCodeProps};
 pub use crate::components::segmented::{Segmented, SegmentedItem, SegmentedOption, SegmentedProps};
 pub use crate::components::statistic::{Countdown, Statistic, StatisticPrecision, StatisticProps};
 pub use crate::components::table::{
-    SelectionType, Table, TableAlign, TableColumn, TableFixed, TablePagination, TableProps,
-    TableRowSelection, TableSize,
+    use_table_exporter, CellEditContext, CellEditorFn, CellPropsFn, CellRenderFn, CellSetterFn,
+    CellValueFn, ColumnState, CsvOptions, ExpandedRowRenderFn, FilterFn, FlatRow, RowExpandableFn,
+    SelectionType, SortOrder, SorterFn, SorterState, Table, TableAction, TableAlign,
+    TableCellEditor, TableCellProps, TableChange, TableColumn, TableEditMode, TableEditable,
+    TableExpandable, TableExporter, TableFilter, TableFilters, TableFixed, TablePagination,
+    TableProps, TableQuery, TableRemote, TableResult, TableRow, TableRowSelection, TableSize,
+    TableSummaryCell, TableSummaryRow, VirtualRange,
 };
 pub use crate::components::tabs::{
     TabItem, Tabs, TabsEditAction, TabsPosition, TabsProps, TabsSize, TabsType,
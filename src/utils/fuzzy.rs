@@ -0,0 +1,201 @@
+//! 模糊匹配
+//!
+//! 用于选项筛选：忽略大小写，支持连续子串、按顺序出现的字符以及汉字拼音首字母匹配，
+//! 返回匹配得分与命中字符的位置，便于排序和高亮显示。
+
+use super::pinyin::pinyin_initial;
+use std::ops::Range;
+
+/// 连续匹配的基础得分
+const CONTIGUOUS_SCORE: i32 = 200;
+/// 按顺序出现匹配的基础得分
+const SUBSEQUENCE_SCORE: i32 = 100;
+/// 从文本开头匹配的加分
+const PREFIX_BONUS: i32 = 100;
+/// 从单词开头匹配的加分
+const WORD_START_BONUS: i32 = 50;
+/// 完全匹配的加分
+const EXACT_BONUS: i32 = 100;
+/// 按顺序出现匹配时相邻字符的加分
+const ADJACENT_BONUS: i32 = 10;
+/// 匹配位置靠后的最大扣分
+const MAX_POSITION_PENALTY: i32 = 50;
+
+/// 模糊匹配结果
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FuzzyMatch {
+    /// 匹配得分，越大越相关
+    pub score: i32,
+    /// 命中字符在原文本中的字节区间，已合并相邻区间
+    pub ranges: Vec<Range<usize>>,
+}
+
+/// 模糊匹配文本，不匹配时返回 None，搜索内容为空时视为匹配
+///
+/// 连续匹配得分高于按顺序出现的匹配，从开头或单词开头匹配时加分；
+/// 汉字可以用拼音首字母匹配，如 `bj` 匹配「北京」。
+pub fn fuzzy_match(text: &str, pattern: &str) -> Option<FuzzyMatch> {
+    let pattern: Vec<char> = pattern.trim().chars().map(lower_char).collect();
+    if pattern.is_empty() {
+        return Some(FuzzyMatch::default());
+    }
+    let chars: Vec<(usize, char)> = text.char_indices().collect();
+    if chars.len() < pattern.len() {
+        return None;
+    }
+
+    let (indices, score) =
+        match_contiguous(&chars, &pattern).or_else(|| match_subsequence(&chars, &pattern))?;
+    Some(FuzzyMatch {
+        score,
+        ranges: char_ranges(&chars, &indices, text.len()),
+    })
+}
+
+/// 按匹配区间拆分文本，返回 `(片段, 是否命中)`
+pub fn highlight_segments<'a>(text: &'a str, ranges: &[Range<usize>]) -> Vec<(&'a str, bool)> {
+    let mut segments = Vec::new();
+    let mut start = 0;
+    for range in ranges {
+        if range.start < start || range.end > text.len() || range.start >= range.end {
+            continue;
+        }
+        if range.start > start {
+            segments.push((&text[start..range.start], false));
+        }
+        segments.push((&text[range.clone()], true));
+        start = range.end;
+    }
+    if start < text.len() {
+        segments.push((&text[start..], false));
+    }
+    segments
+}
+
+fn lower_char(c: char) -> char {
+    c.to_lowercase().next().unwrap_or(c)
+}
+
+/// 文本字符与搜索字符是否匹配，汉字同时比较拼音首字母
+fn char_matches(c: char, pattern: char) -> bool {
+    lower_char(c) == pattern || pinyin_initial(c) == Some(pattern)
+}
+
+/// 字符是否位于单词开头：文本开头、分隔符之后、小写转大写处或汉字
+fn is_word_start(chars: &[(usize, char)], index: usize) -> bool {
+    let c = chars[index].1;
+    if index == 0 || pinyin_initial(c).is_some() {
+        return true;
+    }
+    let prev = chars[index - 1].1;
+    !prev.is_alphanumeric() || (prev.is_lowercase() && c.is_uppercase())
+}
+
+fn match_contiguous(chars: &[(usize, char)], pattern: &[char]) -> Option<(Vec<usize>, i32)> {
+    let start = (0..=chars.len() - pattern.len()).find(|&start| {
+        pattern
+            .iter()
+            .enumerate()
+            .all(|(offset, p)| char_matches(chars[start + offset].1, *p))
+    })?;
+
+    let mut score = CONTIGUOUS_SCORE - (start as i32).min(MAX_POSITION_PENALTY);
+    if start == 0 {
+        score += PREFIX_BONUS;
+        if pattern.len() == chars.len() {
+            score += EXACT_BONUS;
+        }
+    } else if is_word_start(chars, start) {
+        score += WORD_START_BONUS;
+    }
+    Some(((start..start + pattern.len()).collect(), score))
+}
+
+fn match_subsequence(chars: &[(usize, char)], pattern: &[char]) -> Option<(Vec<usize>, i32)> {
+    let mut indices = Vec::with_capacity(pattern.len());
+    let mut next = 0;
+    for p in pattern {
+        let index = (next..chars.len()).find(|&index| char_matches(chars[index].1, *p))?;
+        indices.push(index);
+        next = index + 1;
+    }
+
+    let mut score = SUBSEQUENCE_SCORE - (indices[0] as i32).min(MAX_POSITION_PENALTY);
+    for (i, index) in indices.iter().enumerate() {
+        if i > 0 && indices[i - 1] + 1 == *index {
+            score += ADJACENT_BONUS;
+        } else if is_word_start(chars, *index) {
+            score += ADJACENT_BONUS / 2;
+        }
+    }
+    let gaps = (indices[indices.len() - 1] - indices[0] + 1 - indices.len()) as i32;
+    score -= gaps.min(MAX_POSITION_PENALTY);
+    // 按顺序出现的匹配总是排在连续匹配之后
+    Some((
+        indices,
+        score.min(CONTIGUOUS_SCORE - MAX_POSITION_PENALTY - 1),
+    ))
+}
+
+/// 字符下标转换为合并后的字节区间
+fn char_ranges(chars: &[(usize, char)], indices: &[usize], text_len: usize) -> Vec<Range<usize>> {
+    let byte_end = |index: usize| chars.get(index + 1).map_or(text_len, |(byte, _)| *byte);
+    let mut ranges: Vec<Range<usize>> = Vec::new();
+    for &index in indices {
+        let start = chars[index].0;
+        let end = byte_end(index);
+        match ranges.last_mut() {
+            Some(last) if last.end == start => last.end = end,
+            _ => ranges.push(start..end),
+        }
+    }
+    ranges
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn matched(text: &str, pattern: &str) -> Vec<String> {
+        let result = fuzzy_match(text, pattern).unwrap();
+        highlight_segments(text, &result.ranges)
+            .into_iter()
+            .filter(|(_, hit)| *hit)
+            .map(|(segment, _)| segment.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_fuzzy_match() {
+        assert_eq!(fuzzy_match("Apple", ""), Some(FuzzyMatch::default()));
+        assert_eq!(matched("Pineapple", "APP"), vec!["app"]);
+        assert_eq!(matched("Select Option", "sop"), vec!["S", "Op"]);
+        assert_eq!(matched("北京市", "bj"), vec!["北京"]);
+        assert_eq!(matched("上海浦东", "shpd"), vec!["上海浦东"]);
+        assert_eq!(matched("中国 China", "国c"), vec!["国", "C"]);
+        assert!(fuzzy_match("banana", "xyz").is_none());
+        assert!(fuzzy_match("ab", "abc").is_none());
+    }
+
+    #[test]
+    fn test_fuzzy_score() {
+        let score = |text: &str| fuzzy_match(text, "app").unwrap().score;
+        assert!(score("app") > score("apple"));
+        assert!(score("apple") > score("my app"));
+        assert!(score("my app") > score("pineapple"));
+        assert!(score("pineapple") > score("a_p_p"));
+    }
+
+    #[test]
+    fn test_highlight_segments() {
+        assert_eq!(
+            highlight_segments("hello", &[1..3]),
+            vec![("h", false), ("el", true), ("lo", false)]
+        );
+        assert_eq!(highlight_segments("hi", &[]), vec![("hi", false)]);
+        assert_eq!(
+            highlight_segments("中文", &[0..3]),
+            vec![("中", true), ("文", false)]
+        );
+    }
+}
@@ -4,10 +4,14 @@
 
 pub mod class_names;
 pub mod color;
+pub mod fuzzy;
 pub mod motion;
+pub mod pinyin;
 pub mod responsive;
 pub mod size;
 
+use std::rc::Rc;
+
 // 重新导出常用工具
 pub use class_names::*;
 pub use color::presets as color_presets;
@@ -15,8 +19,10 @@ pub use color::{
     get_color_by_type, get_contrast_color, is_dark_color, ColorPalette, ColorType, HslColor,
     RgbColor,
 };
+pub use fuzzy::{fuzzy_match, highlight_segments, FuzzyMatch};
 pub use motion::presets as motion_presets;
 pub use motion::{AnimationConfig, Direction, Duration, Easing, TransitionType};
+pub use pinyin::{pinyin_initial, pinyin_initials};
 pub use responsive::*;
 pub use size::*;
 
@@ -88,6 +94,15 @@ pub fn to_percent(value: f64) -> String {
     format!("{}%", (value * 100.0).round())
 }
 
+/// 比较两个可选的闭包是否指向同一个实例
+pub(crate) fn rc_option_eq<F: ?Sized>(a: &Option<Rc<F>>, b: &Option<Rc<F>>) -> bool {
+    match (a, b) {
+        (Some(a), Some(b)) => Rc::ptr_eq(a, b),
+        (None, None) => true,
+        _ => false,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;